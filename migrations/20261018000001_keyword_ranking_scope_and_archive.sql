@@ -0,0 +1,50 @@
+-- 순위표를 (날짜, 지역, 산정 방식) 단위로 구분
+ALTER TABLE youtube_keyword_rankings
+    ADD COLUMN region_code VARCHAR(8) NOT NULL DEFAULT 'KR' AFTER ranking_date,
+    ADD COLUMN strategy VARCHAR(32) NOT NULL DEFAULT 'weekly_view_sum' AFTER region_code;
+
+-- 교체된 순위표 보관용 테이블
+CREATE TABLE IF NOT EXISTS youtube_keyword_rankings_archive (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    original_id BIGINT NOT NULL,
+    ranking_date DATE NOT NULL,
+    region_code VARCHAR(8) NOT NULL,
+    strategy VARCHAR(32) NOT NULL,
+    ranking INT NOT NULL,
+    keyword_id BIGINT NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    score BIGINT NOT NULL,
+    archived_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_keyword_rankings_archive_scope (ranking_date, region_code, strategy)
+);
+
+-- 같은 날 여러 번 적재된 순위표는 마지막 적재분만 남기고 아카이브로 이동
+CREATE TEMPORARY TABLE tmp_latest_keyword_ranking_load AS
+SELECT ranking_date, region_code, strategy, MAX(id) AS first_id
+FROM youtube_keyword_rankings
+WHERE ranking = 1
+GROUP BY ranking_date, region_code, strategy;
+
+INSERT INTO youtube_keyword_rankings_archive (
+    original_id, ranking_date, region_code, strategy, ranking, keyword_id, keyword_text, score
+)
+SELECT ykr.id, ykr.ranking_date, ykr.region_code, ykr.strategy, ykr.ranking, ykr.keyword_id, ykr.keyword_text, ykr.score
+FROM youtube_keyword_rankings AS ykr
+JOIN tmp_latest_keyword_ranking_load AS latest
+    ON latest.ranking_date = ykr.ranking_date
+    AND latest.region_code = ykr.region_code
+    AND latest.strategy = ykr.strategy
+WHERE ykr.id < latest.first_id;
+
+DELETE ykr
+FROM youtube_keyword_rankings AS ykr
+JOIN tmp_latest_keyword_ranking_load AS latest
+    ON latest.ranking_date = ykr.ranking_date
+    AND latest.region_code = ykr.region_code
+    AND latest.strategy = ykr.strategy
+WHERE ykr.id < latest.first_id;
+
+DROP TEMPORARY TABLE tmp_latest_keyword_ranking_load;
+
+ALTER TABLE youtube_keyword_rankings
+    ADD UNIQUE KEY uk_keyword_rankings_scope (ranking_date, region_code, strategy, ranking);
@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS scheduler_job_runs (
+    job_name VARCHAR(64) NOT NULL PRIMARY KEY,
+    last_success_at TIMESTAMP NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+);
@@ -0,0 +1,6 @@
+ALTER TABLE youtube_videos
+    ADD COLUMN is_available BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN unavailable_at TIMESTAMP NULL DEFAULT NULL,
+    ADD COLUMN stats_refreshed_at TIMESTAMP NULL DEFAULT NULL;
+
+CREATE INDEX idx_youtube_videos_published_at ON youtube_videos (published_at);
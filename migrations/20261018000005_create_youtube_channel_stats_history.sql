@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS youtube_channel_stats_history (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    channel_id VARCHAR(64) NOT NULL,
+    subscriber_count BIGINT NOT NULL,
+    view_count BIGINT NOT NULL,
+    video_count BIGINT NOT NULL,
+    recorded_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_channel_stats_history_channel_recorded (channel_id, recorded_at)
+);
@@ -0,0 +1,17 @@
+ALTER TABLE youtube_channels
+    ADD COLUMN analyzed_at TIMESTAMP NULL DEFAULT NULL,
+    ADD COLUMN reanalysis_started_at TIMESTAMP NULL DEFAULT NULL;
+
+UPDATE youtube_channels SET analyzed_at = created_at WHERE is_finished = TRUE;
+
+ALTER TABLE youtube_channel_keywords
+    ADD UNIQUE KEY uk_channel_keywords_channel_keyword (youtube_channel_id, keyword_text);
+
+CREATE TABLE IF NOT EXISTS youtube_channel_keyword_snapshots (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    youtube_channel_id BIGINT NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    view_count BIGINT NOT NULL,
+    snapshot_at TIMESTAMP NOT NULL,
+    INDEX idx_channel_keyword_snapshots_channel (youtube_channel_id)
+);
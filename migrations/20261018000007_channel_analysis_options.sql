@@ -0,0 +1,5 @@
+ALTER TABLE youtube_channels
+    ADD COLUMN analysis_max_videos INT NULL DEFAULT NULL,
+    ADD COLUMN analysis_published_after TIMESTAMP NULL DEFAULT NULL,
+    ADD COLUMN analysis_published_before TIMESTAMP NULL DEFAULT NULL,
+    ADD COLUMN analysis_video_format VARCHAR(16) NOT NULL DEFAULT 'all';
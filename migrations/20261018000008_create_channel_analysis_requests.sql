@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS youtube_channel_analysis_requests (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_public_id VARCHAR(255) NOT NULL,
+    channel_handle VARCHAR(255) NOT NULL,
+    requested_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    INDEX idx_channel_analysis_requests_user_requested (user_public_id, requested_at),
+    INDEX idx_channel_analysis_requests_handle (channel_handle)
+);
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS youtube_channel_keyword_months (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    youtube_channel_id BIGINT NOT NULL,
+    month DATE NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    view_count BIGINT NOT NULL,
+    UNIQUE KEY uk_channel_keyword_months (youtube_channel_id, month, keyword_text)
+);
@@ -0,0 +1,23 @@
+CREATE TABLE IF NOT EXISTS youtube_channel_videos (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    youtube_channel_id BIGINT NOT NULL,
+    video_id VARCHAR(64) NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    published_at TIMESTAMP NOT NULL,
+    duration INT NOT NULL,
+    view_count BIGINT NOT NULL,
+    like_count BIGINT NOT NULL,
+    comment_count BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_channel_videos_channel_video (youtube_channel_id, video_id),
+    INDEX idx_channel_videos_channel_published (youtube_channel_id, published_at)
+);
+
+CREATE TABLE IF NOT EXISTS youtube_channel_video_keywords (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    youtube_channel_id BIGINT NOT NULL,
+    video_id VARCHAR(64) NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    UNIQUE KEY uk_channel_video_keywords (youtube_channel_id, keyword_text, video_id)
+);
@@ -0,0 +1,9 @@
+ALTER TABLE youtube_videos
+    ADD COLUMN category_id VARCHAR(8) NULL DEFAULT NULL;
+
+-- 원본 메타데이터가 남아 있는 영상은 카테고리를 채워 둠
+UPDATE youtube_videos AS yv
+    JOIN youtube_raw_videos AS yrv ON yrv.video_id = yv.video_id
+SET yv.category_id = JSON_UNQUOTE(JSON_EXTRACT(yrv.raw_metadata, '$.snippet.categoryId'));
+
+CREATE INDEX idx_youtube_videos_category_published ON youtube_videos (category_id, published_at);
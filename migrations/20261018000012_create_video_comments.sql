@@ -0,0 +1,24 @@
+CREATE TABLE IF NOT EXISTS youtube_video_comments (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    video_id VARCHAR(64) NOT NULL,
+    comment_id VARCHAR(128) NOT NULL,
+    author_display_name VARCHAR(255) NOT NULL,
+    text_display TEXT NOT NULL,
+    like_count BIGINT NOT NULL DEFAULT 0,
+    reply_count BIGINT NOT NULL DEFAULT 0,
+    published_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_video_comments_comment (comment_id),
+    INDEX idx_video_comments_video (video_id, like_count)
+);
+
+CREATE TABLE IF NOT EXISTS youtube_video_comment_keywords (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    video_id VARCHAR(64) NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    mention_count INT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_video_comment_keywords (video_id, keyword_text),
+    INDEX idx_video_comment_keywords_keyword (keyword_text)
+);
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS youtube_keyword_sentiments (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    sentiment_date DATE NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    positive_ratio DOUBLE NOT NULL,
+    neutral_ratio DOUBLE NOT NULL,
+    negative_ratio DOUBLE NOT NULL,
+    sample_count INT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_keyword_sentiments_date_keyword (sentiment_date, keyword_text)
+);
@@ -0,0 +1,26 @@
+CREATE TABLE IF NOT EXISTS youtube_keyword_watchlists (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    user_public_id VARCHAR(255) NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    enter_top_n INT NULL,
+    climb_threshold INT NULL,
+    alert_on_drop_off BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_keyword_watchlists_user_keyword (user_public_id, keyword_text),
+    INDEX idx_keyword_watchlists_keyword (keyword_text)
+);
+
+CREATE TABLE IF NOT EXISTS youtube_keyword_alerts (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    watchlist_id BIGINT NOT NULL,
+    user_public_id VARCHAR(255) NOT NULL,
+    keyword_text VARCHAR(255) NOT NULL,
+    alert_type VARCHAR(20) NOT NULL,
+    ranking_date DATE NOT NULL,
+    previous_ranking INT NULL,
+    current_ranking INT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uk_keyword_alerts_watchlist_date_type (watchlist_id, ranking_date, alert_type),
+    INDEX idx_keyword_alerts_user_date (user_public_id, ranking_date)
+);
@@ -0,0 +1,3 @@
+-- 아카이브에서 복원한 행 표시, 이미 아카이브 파일에 있으므로 다시 아카이브하지 않음
+ALTER TABLE youtube_raw_videos
+    ADD COLUMN restored_at TIMESTAMP NULL DEFAULT NULL;
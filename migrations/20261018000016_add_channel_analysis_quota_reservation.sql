@@ -0,0 +1,9 @@
+-- 새 영상이 없어 한도를 돌려준 재분석 요청 표시, 월 사용량 집계에서 제외
+ALTER TABLE youtube_channel_analysis_requests
+    ADD COLUMN is_refunded BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- 사용자별 잠금 행, 같은 사용자의 동시 분석 요청이 한도를 함께 넘지 않도록 한도 확인과 요청 저장을 직렬화
+CREATE TABLE IF NOT EXISTS youtube_channel_analysis_quota_locks (
+    user_public_id VARCHAR(255) PRIMARY KEY,
+    reserved_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,2 @@
+-- 날짜별 알림 교체 시 ranking_date 로 삭제
+CREATE INDEX idx_keyword_alerts_ranking_date ON youtube_keyword_alerts (ranking_date);
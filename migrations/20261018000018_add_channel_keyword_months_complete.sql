@@ -0,0 +1,4 @@
+-- 월별 키워드 이력이 분석 범위 전체를 담고 있는지 표시, 전체 분석에서만 TRUE 로 바뀜
+-- 기존 채널은 월별 이력 도입 전에 분석했거나 추가 분석으로 일부만 쌓였을 수 있어 FALSE 로 두고 다음 재분석을 전체 분석으로 실행
+ALTER TABLE youtube_channels
+    ADD COLUMN keyword_months_complete BOOLEAN NOT NULL DEFAULT FALSE;
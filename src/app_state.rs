@@ -5,13 +5,22 @@ use crate::auth::auth_service::AuthService;
 use crate::user::user_service::UserService;
 use crate::config::Config;
 use crate::gemini::gemini_api_util::GeminiAPIClient;
+use crate::pipeline::pipeline_model::{JOB_CHANNEL_CLEANUP, JOB_CHANNEL_STATS_REFRESH, JOB_COMMENT_COLLECTION, JOB_KEYWORD_SENTIMENT, JOB_RAW_VIDEO_ARCHIVE, JOB_VIDEO_COLLECTION, JOB_VIDEO_STATS_REFRESH};
+use crate::pipeline::pipeline_repository::PipelineSqlxRepository;
+use crate::pipeline::pipeline_service::PipelineService;
 use crate::redis::redis_repository::RedisRepository;
 use crate::user::user_repository::{UserRepository, UserSqlxRepository};
 use crate::youtube::youtube_channel::youtube_channel_repository::YoutubeChannelSqlxRepository;
 use crate::youtube::youtube_channel::youtube_channel_service::YoutubeChannelService;
 use crate::youtube::youtube_data_api::youtube_data_api_util::YoutubeDataAPIClient;
-use crate::youtube::youtube_video::youtube_raw_video_repository::YoutubeRawVideoSqlxRepository;
-use crate::youtube::youtube_video::youtube_video_repository::YoutubeVideoSqlxRepository;
+use crate::youtube::youtube_video::youtube_keyword_idea_service::KeywordIdeaService;
+use crate::youtube::youtube_video::youtube_keyword_watchlist_repository::{YoutubeKeywordWatchlistRepository, YoutubeKeywordWatchlistSqlxRepository};
+use crate::youtube::youtube_video::youtube_keyword_watchlist_service::KeywordWatchlistService;
+use crate::youtube::youtube_video::youtube_raw_video_archive_service::RawVideoArchiveService;
+use crate::youtube::youtube_video::youtube_raw_video_repository::{YoutubeRawVideoRepository, YoutubeRawVideoSqlxRepository};
+use crate::youtube::youtube_video::youtube_video_comment_repository::YoutubeVideoCommentSqlxRepository;
+use crate::youtube::youtube_video::youtube_video_comment_service::YoutubeVideoCommentService;
+use crate::youtube::youtube_video::youtube_video_repository::{YoutubeVideoRepository, YoutubeVideoSqlxRepository};
 use crate::youtube::youtube_video::youtube_video_service::YoutubeVideoService;
 
 #[derive(Clone)]
@@ -20,6 +29,11 @@ pub struct AppState {
     pub auth_service: AuthService,
     pub youtube_video_service: YoutubeVideoService,
     pub youtube_channel_service: YoutubeChannelService,
+    pub youtube_video_comment_service: YoutubeVideoCommentService,
+    pub keyword_idea_service: KeywordIdeaService,
+    pub keyword_watchlist_service: KeywordWatchlistService,
+    pub pipeline_service: PipelineService,
+    pub raw_video_archive_service: RawVideoArchiveService,
 }
 
 impl AppState {
@@ -44,22 +58,70 @@ impl AppState {
         
         let gemini_api_client = Arc::new(GeminiAPIClient::new(&config));
         
-        let batch_youtube_data_client = YoutubeDataAPIClient::new(&config.batch_google_api_key);
-        let youtube_data_client = YoutubeDataAPIClient::new(&config.google_api_key);
-        let youtube_raw_video_repository = YoutubeRawVideoSqlxRepository::new(db_pool.clone());
-        let youtube_video_repository = YoutubeVideoSqlxRepository::new(db_pool.clone());
+        let batch_youtube_data_client = Arc::new(YoutubeDataAPIClient::new(
+            &config.batch_google_api_key,
+            "batch",
+            Arc::clone(&redis_repository),
+        ));
+        let youtube_data_client = YoutubeDataAPIClient::new(
+            &config.google_api_key,
+            "default",
+            Arc::clone(&redis_repository),
+        );
+        let pipeline_repository = PipelineSqlxRepository::new(db_pool.clone());
+        let pipeline_service = PipelineService::new(
+            Arc::clone(&redis_repository),
+            Arc::new(pipeline_repository),
+            vec![
+                (JOB_VIDEO_COLLECTION, config.video_collection_cron.clone()),
+                (JOB_CHANNEL_CLEANUP, config.channel_cleanup_cron.clone()),
+                (JOB_VIDEO_STATS_REFRESH, config.video_stats_refresh_cron.clone()),
+                (JOB_CHANNEL_STATS_REFRESH, config.channel_stats_refresh_cron.clone()),
+                (JOB_COMMENT_COLLECTION, config.comment_collection_cron.clone()),
+                (JOB_KEYWORD_SENTIMENT, config.keyword_sentiment_cron.clone()),
+                (JOB_RAW_VIDEO_ARCHIVE, config.raw_video_archive_cron.clone()),
+            ],
+        )
+            .unwrap_or_else(|e| panic!("Failed to build pipeline schedules: {}", e));
+        
+        let youtube_raw_video_repository: Arc<dyn YoutubeRawVideoRepository> = Arc::new(YoutubeRawVideoSqlxRepository::new(db_pool.clone()));
+        let youtube_video_repository: Arc<dyn YoutubeVideoRepository> = Arc::new(YoutubeVideoSqlxRepository::new(db_pool.clone()));
+        let youtube_keyword_watchlist_repository: Arc<dyn YoutubeKeywordWatchlistRepository> = Arc::new(YoutubeKeywordWatchlistSqlxRepository::new(db_pool.clone()));
         let youtube_video_service = YoutubeVideoService::new(
-            Arc::new(batch_youtube_data_client),
-            Arc::new(youtube_raw_video_repository),
-            Arc::new(youtube_video_repository),
+            Arc::clone(&batch_youtube_data_client),
+            Arc::clone(&youtube_raw_video_repository),
+            Arc::clone(&youtube_video_repository),
+            Arc::clone(&gemini_api_client),
+            Arc::clone(&youtube_keyword_watchlist_repository),
+            pipeline_service.clone(),
+        );
+        let keyword_idea_service = KeywordIdeaService::new(
+            Arc::clone(&youtube_video_repository),
+            Arc::clone(&gemini_api_client),
+            Arc::clone(&redis_repository),
+        );
+        let keyword_watchlist_service = KeywordWatchlistService::new(youtube_keyword_watchlist_repository);
+        
+        let youtube_video_comment_repository = YoutubeVideoCommentSqlxRepository::new(db_pool.clone());
+        let youtube_video_comment_service = YoutubeVideoCommentService::new(
+            batch_youtube_data_client,
+            Arc::new(youtube_video_comment_repository),
             Arc::clone(&gemini_api_client),
         );
         
         let youtube_channel_repository = YoutubeChannelSqlxRepository::new(db_pool.clone());
         let youtube_channel_service = YoutubeChannelService::new(
             Arc::new(youtube_channel_repository),
+            youtube_video_repository,
             Arc::new(youtube_data_client),
             Arc::clone(&gemini_api_client),
+            config.channel_analysis_monthly_quota,
+        );
+        
+        let raw_video_archive_service = RawVideoArchiveService::new(
+            youtube_raw_video_repository,
+            &config.raw_video_archive_dir,
+            config.raw_video_retention_days,
         );
         
         let auth_service = AuthService::new(
@@ -72,6 +134,11 @@ impl AppState {
             auth_service,
             youtube_video_service,
             youtube_channel_service,
+            youtube_video_comment_service,
+            keyword_idea_service,
+            keyword_watchlist_service,
+            pipeline_service,
+            raw_video_archive_service,
         }
     }
 }
\ No newline at end of file
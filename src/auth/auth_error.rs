@@ -25,12 +25,16 @@ pub enum AuthError {
     
     #[error("비밀번호가 일치하지 않습니다.")]
     InvalidPassword,
+    
+    #[error("권한이 없습니다.")]
+    Forbidden,
 }
 
 impl ResponseError for AuthError {
     fn status_code(&self) -> StatusCode {
         match self {
             AuthError::Unauthorized => StatusCode::UNAUTHORIZED,
+            AuthError::Forbidden => StatusCode::FORBIDDEN,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
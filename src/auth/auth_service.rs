@@ -9,6 +9,7 @@ use crate::auth::auth_error::AuthError;
 use crate::auth::auth_model::{Claims, LoginRequest};
 use crate::config::Config;
 use crate::redis::redis_repository::RedisRepository;
+use crate::user::user_model::USER_ROLE_ADMIN;
 use crate::user::user_repository::UserRepository;
 
 #[derive(Clone)]
@@ -32,6 +33,24 @@ impl AuthService {
         }
     }
     
+    pub async fn authorize_admin(&self, user_public_id: &str) -> Result<(), AuthError> {
+        let user = self.user_repository
+            .find_by_public_id(user_public_id)
+            .await
+            .map_err(|db_error| {
+                match db_error {
+                    sqlx::Error::RowNotFound => AuthError::UserNotFound,
+                    _ => db_error.into(),
+                }
+            })?;
+        
+        if user.role != USER_ROLE_ADMIN {
+            return Err(AuthError::Forbidden);
+        }
+        
+        Ok(())
+    }
+    
     pub async fn login(&self, request: LoginRequest) -> Result<HttpResponse, AuthError> {
         let user = self.user_repository.find_by_email(&request.email).await?;
         
@@ -0,0 +1,280 @@
+use std::error::Error;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Asia::Seoul;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::json;
+use for_research::app_state::AppState;
+use for_research::config::Config;
+use for_research::pipeline::pipeline_model::{JOB_CHANNEL_CLEANUP, JOB_VIDEO_COLLECTION, JOB_VIDEO_STATS_REFRESH};
+use for_research::user::user_model::{UserCreateRequest, USER_ROLE_ADMIN};
+
+#[derive(Parser)]
+#[command(name = "admin", about = "for-research 운영 도구")]
+struct Cli {
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    output: OutputFormat,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// 쇼츠 수집 파이프라인 실행
+    Collect,
+
+    /// 순위 기간 내 영상의 조회수 등 통계만 갱신
+    RefreshStats,
+
+    /// 특정 날짜의 키워드 순위 재계산 (기본값: 오늘)
+    RecomputeRankings {
+        #[arg(long)]
+        date: Option<NaiveDate>,
+    },
+
+    /// 채널 키워드 재분석 (기본값: 마지막 분석 이후 업로드된 영상만 추가 분석)
+    ReanalyzeChannel {
+        channel_handle: String,
+        /// 저장된 분석 옵션으로 처음부터 다시 분석하고, 성공하면 기존 결과와 교체
+        #[arg(long)]
+        full: bool,
+    },
+
+    /// 사용자 생성 (비밀번호는 표준 입력에서 한 줄로 읽음)
+    CreateUser {
+        #[arg(long)]
+        email: String,
+        #[arg(long)]
+        username: String,
+        #[arg(long)]
+        admin: bool,
+    },
+
+    /// 사용자를 관리자로 승격
+    PromoteUser {
+        email: String,
+    },
+
+    /// 분석이 끝나지 않은 오래된 채널 삭제
+    CleanupChannels,
+
+    /// YouTube Data API 할당량 사용량 출력
+    Quota,
+
+    /// 보관 기간이 지난 원본 데이터를 gzip JSONL 로 내보내고 삭제
+    ArchiveRawVideos,
+
+    /// 원본 데이터 아카이브 파일을 DB 로 복원
+    RestoreRawVideos {
+        path: PathBuf,
+    },
+}
+
+struct CommandOutput {
+    text: String,
+    json: serde_json::Value,
+}
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let cli = Cli::parse();
+    let config = Config::from_env();
+    let app_state = AppState::new(&config).await;
+
+    match run(&app_state, cli.command).await {
+        Ok(output) => match cli.output {
+            OutputFormat::Text => println!("{}", output.text),
+            OutputFormat::Json => println!("{}", output.json),
+        },
+        Err(e) => {
+            match cli.output {
+                OutputFormat::Text => eprintln!("[Admin] 실패: {}", e),
+                OutputFormat::Json => eprintln!("{}", json!({ "error": e.to_string() })),
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
+async fn run(app_state: &AppState, command: Command) -> Result<CommandOutput, Box<dyn Error>> {
+    match command {
+        Command::Collect => {
+            let collected = app_state.pipeline_service
+                .run_exclusive(JOB_VIDEO_COLLECTION, || async {
+                    app_state.youtube_video_service
+                        .run_video_collection_pipeline()
+                        .await
+                        .map_err(|e| e.to_string())
+                })
+                .await?;
+            if collected.is_none() {
+                return Err("다른 인스턴스에서 데이터 수집 중입니다.".into());
+            }
+            Ok(CommandOutput {
+                text: "데이터 수집 완료".to_string(),
+                json: json!({ "status": "ok" }),
+            })
+        }
+        Command::RefreshStats => {
+            let summary = app_state.pipeline_service
+                .run_exclusive(JOB_VIDEO_STATS_REFRESH, || async {
+                    app_state.youtube_video_service
+                        .refresh_tracked_video_statistics()
+                        .await
+                        .map_err(|e| e.to_string())
+                })
+                .await?;
+            let Some(summary) = summary else {
+                return Err("다른 인스턴스에서 통계 갱신 중입니다.".into());
+            };
+            Ok(CommandOutput {
+                text: format!(
+                    "통계 갱신 완료: 추적 {}건, 갱신 {}건, 비공개 처리 {}건",
+                    summary.tracked_video_count,
+                    summary.refreshed_video_count,
+                    summary.unavailable_video_count
+                ),
+                json: serde_json::to_value(&summary)?,
+            })
+        }
+        Command::RecomputeRankings { date } => {
+            let date = date.unwrap_or_else(|| Utc::now().with_timezone(&Seoul).date_naive());
+            // 수집 작업과 같은 날짜의 순위표를 동시에 교체하지 않도록 같은 락에서 실행 (수집 성공으로 기록하지는 않음)
+            let recomputed = app_state.pipeline_service
+                .run_locked(JOB_VIDEO_COLLECTION, || async {
+                    app_state.youtube_video_service
+                        .calculate_and_save_daily_rankings(date)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                })
+                .await?;
+            if recomputed.is_none() {
+                return Err("다른 인스턴스에서 데이터 수집 중입니다.".into());
+            }
+            Ok(CommandOutput {
+                text: format!("{} 키워드 순위 재계산 완료", date),
+                json: json!({ "status": "ok", "rankingDate": date }),
+            })
+        }
+        Command::ReanalyzeChannel { channel_handle, full } => {
+            let channel = app_state.youtube_channel_service
+                .reanalyze_youtube_channel_keywords(channel_handle, full)
+                .await?;
+            Ok(CommandOutput {
+                text: format!("채널 '{}' ({}) 재분석 완료", channel.channel_handle, channel.channel_title),
+                json: serde_json::to_value(&channel)?,
+            })
+        }
+        Command::CreateUser { email, username, admin } => {
+            let password = read_password()?;
+            let request = UserCreateRequest { email: email.clone(), username, password };
+            app_state.user_service.create_user(request).await?;
+            if admin {
+                app_state.user_service.update_user_role(&email, USER_ROLE_ADMIN).await?;
+            }
+
+            let user = app_state.user_service.get_user_by_email(&email).await?;
+            Ok(CommandOutput {
+                text: format!("사용자 생성 완료: id={} email={} role={}", user.id, user.email, user.role),
+                json: serde_json::to_value(&user)?,
+            })
+        }
+        Command::PromoteUser { email } => {
+            app_state.user_service.update_user_role(&email, USER_ROLE_ADMIN).await?;
+
+            let user = app_state.user_service.get_user_by_email(&email).await?;
+            Ok(CommandOutput {
+                text: format!("관리자 승격 완료: id={} email={}", user.id, user.email),
+                json: serde_json::to_value(&user)?,
+            })
+        }
+        Command::CleanupChannels => {
+            let cleaned = app_state.pipeline_service
+                .run_exclusive(JOB_CHANNEL_CLEANUP, || async {
+                    app_state.youtube_channel_service
+                        .cleanup_stale_channels()
+                        .await
+                        .map_err(|e| e.to_string())
+                })
+                .await?;
+            if cleaned.is_none() {
+                return Err("다른 인스턴스에서 채널 정리 중입니다.".into());
+            }
+            Ok(CommandOutput {
+                text: "미완료 채널 정리 완료".to_string(),
+                json: json!({ "status": "ok" }),
+            })
+        }
+        Command::Quota => {
+            let usages = vec![
+                app_state.youtube_video_service.get_youtube_quota_usage().await?,
+                app_state.youtube_channel_service.get_youtube_quota_usage().await?,
+            ];
+            let text = usages
+                .iter()
+                .map(|usage| {
+                    format!(
+                        "[{}] {} (PT): {} / {} units 사용, {} units 남음",
+                        usage.api_key_label,
+                        usage.quota_date,
+                        usage.used_units,
+                        usage.daily_limit,
+                        usage.remaining_units
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+            Ok(CommandOutput {
+                text,
+                json: serde_json::to_value(&usages)?,
+            })
+        }
+        Command::ArchiveRawVideos => {
+            let archive_files = app_state.raw_video_archive_service.archive_expired_raw_videos().await?;
+            let text = archive_files
+                .iter()
+                .map(|file| format!("{}: {}건 보관, {}건 삭제 ({})", file.archive_date, file.archived_count, file.deleted_count, file.path))
+                .chain(std::iter::once(format!("원본 데이터 아카이브 완료: {}개 파일", archive_files.len())))
+                .collect::<Vec<_>>()
+                .join("\n");
+            Ok(CommandOutput {
+                text,
+                json: serde_json::to_value(&archive_files)?,
+            })
+        }
+        Command::RestoreRawVideos { path } => {
+            let restored_count = app_state.raw_video_archive_service.restore_archive(&path).await?;
+            Ok(CommandOutput {
+                text: format!("{} 복원 완료: {}건", path.display(), restored_count),
+                json: json!({ "status": "ok", "path": path, "restoredCount": restored_count }),
+            })
+        }
+    }
+}
+
+// 명령행 인자는 셸 기록과 ps 에 남으므로 비밀번호는 표준 입력으로 받음
+fn read_password() -> Result<String, Box<dyn Error>> {
+    if io::stdin().is_terminal() {
+        eprint!("비밀번호: ");
+    }
+
+    let mut password = String::new();
+    io::stdin().read_line(&mut password)?;
+    let password = password.trim_end_matches(['\r', '\n']).to_string();
+    if password.is_empty() {
+        return Err("비밀번호가 비어 있습니다.".into());
+    }
+
+    Ok(password)
+}
\ No newline at end of file
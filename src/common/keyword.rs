@@ -0,0 +1,4 @@
+// 띄어쓰기, 대소문자만 다른 키워드는 같은 키워드로 취급
+pub fn normalize_keyword(keyword_text: &str) -> String {
+    keyword_text.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase()
+}
\ No newline at end of file
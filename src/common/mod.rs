@@ -1 +1,5 @@
-pub mod pagination;
\ No newline at end of file
+pub mod keyword;
+pub mod pagination;
+pub mod statistics;
+#[cfg(test)]
+pub mod test_support;
\ No newline at end of file
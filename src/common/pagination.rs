@@ -9,8 +9,8 @@ pub struct PaginationQuery {
     pub size: u32
 }
 
-fn default_page() -> u32 { 0 }
-fn default_size() -> u32 { 10 }
+pub fn default_page() -> u32 { 0 }
+pub fn default_size() -> u32 { 10 }
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct Page<T> {
@@ -0,0 +1,44 @@
+// 정렬된 값에서 선형 보간으로 백분위수 계산 (p: 0.0 ~ 1.0)
+pub fn percentile(sorted_values: &[f64], p: f64) -> f64 {
+    if sorted_values.is_empty() {
+        return 0.0;
+    }
+    
+    let rank = p.clamp(0.0, 1.0) * (sorted_values.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let weight = rank - lower as f64;
+    
+    sorted_values[lower] + (sorted_values[upper] - sorted_values[lower]) * weight
+}
+
+pub fn median(sorted_values: &[f64]) -> f64 {
+    percentile(sorted_values, 0.5)
+}
+
+pub fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+// 응답에 쓰기 좋게 소수점 넷째 자리까지 반올림
+pub fn round4(value: f64) -> f64 {
+    (value * 10_000.0).round() / 10_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn percentile_interpolates_between_values() {
+        let values = [10.0, 20.0, 30.0, 40.0];
+        
+        assert_eq!(median(&values), 25.0);
+        assert_eq!(percentile(&values, 0.9), 37.0);
+        assert_eq!(percentile(&[], 0.5), 0.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::NaiveDate;
+use crate::config::{AppRole, Config};
+use crate::gemini::gemini_api_util::GeminiAPIClient;
+use crate::redis::redis_repository::RedisRepository;
+use crate::youtube::youtube_channel::youtube_channel_model::{YoutubeChannel, YoutubeChannelKeyword};
+use crate::youtube::youtube_data_api::youtube_data_api_util::YoutubeDataAPIClient;
+use crate::youtube::youtube_video::youtube_video_model::{YoutubeKeywordRanking, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM};
+
+// 서비스 단위 테스트용 설정, 외부 API 는 호출하지 않는 경로에서만 사용
+pub fn test_config() -> Config {
+    Config {
+        server_address: "127.0.0.1:0".to_string(),
+        database_url: "mysql://localhost/test".to_string(),
+        batch_google_api_key: "test".to_string(),
+        google_api_key: "test".to_string(),
+        jwt_secret: "test".to_string(),
+        redis_url: "redis://127.0.0.1:0/".to_string(),
+        app_role: AppRole::All,
+        video_collection_cron: "0 1 9 * * *".to_string(),
+        channel_cleanup_cron: "0 */10 * * * *".to_string(),
+        raw_video_archive_cron: "0 30 4 * * *".to_string(),
+        video_stats_refresh_cron: "0 30 */3 * * *".to_string(),
+        channel_stats_refresh_cron: "0 0 6 * * *".to_string(),
+        comment_collection_cron: "0 0 11 * * *".to_string(),
+        keyword_sentiment_cron: "0 0 12 * * *".to_string(),
+        raw_video_archive_dir: "archive/raw_videos".to_string(),
+        raw_video_retention_days: 90,
+        channel_analysis_monthly_quota: 10,
+    }
+}
+
+// 연결하지 않는 풀, 실제로 Redis 를 쓰면 곧바로 실패
+pub fn test_redis_repository() -> Arc<RedisRepository> {
+    let client = redis::Client::open(test_config().redis_url.as_str()).unwrap();
+    let pool = r2d2::Pool::builder()
+        .connection_timeout(Duration::from_millis(50))
+        .build_unchecked(client);
+    
+    Arc::new(RedisRepository::new(pool))
+}
+
+pub fn test_youtube_data_api_client() -> Arc<YoutubeDataAPIClient> {
+    Arc::new(YoutubeDataAPIClient::new("test", "test", test_redis_repository()))
+}
+
+pub fn test_gemini_api_client() -> Arc<GeminiAPIClient> {
+    Arc::new(GeminiAPIClient::new(&test_config()))
+}
+
+// 분석이 끝난 채널, 필요한 필드만 구조체 갱신 문법으로 덮어써서 사용
+pub fn test_channel(channel_handle: &str) -> YoutubeChannel {
+    YoutubeChannel {
+        id: 0,
+        channel_id: format!("UC{}", channel_handle),
+        channel_handle: channel_handle.to_string(),
+        channel_title: channel_handle.to_string(),
+        thumbnail_url: String::new(),
+        description: String::new(),
+        subscriber_count: 0,
+        view_count: 0,
+        video_count: 0,
+        is_finished: true,
+        analyzed_at: None,
+        keyword_months_complete: true,
+        analysis_max_videos: None,
+        analysis_published_after: None,
+        analysis_published_before: None,
+        analysis_video_format: "all".to_string(),
+        created_at: Default::default(),
+        updated_at: Default::default(),
+    }
+}
+
+// 기본 지역, 전략의 순위표 항목, keyword_id 는 순위와 같은 값
+pub fn test_keyword_ranking(ranking_date: NaiveDate, ranking: i32, keyword_text: &str, score: i64) -> YoutubeKeywordRanking {
+    YoutubeKeywordRanking {
+        id: 0,
+        ranking_date,
+        region_code: RANKING_REGION_CODE.to_string(),
+        strategy: RANKING_STRATEGY_WEEKLY_VIEW_SUM.to_string(),
+        ranking,
+        keyword_id: ranking as i64,
+        keyword_text: keyword_text.to_string(),
+        score,
+    }
+}
+
+pub fn test_channel_keyword(keyword_text: &str, view_count: i64) -> YoutubeChannelKeyword {
+    YoutubeChannelKeyword {
+        id: 0,
+        youtube_channel_id: 0,
+        keyword_text: keyword_text.to_string(),
+        view_count,
+    }
+}
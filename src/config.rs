@@ -1,4 +1,35 @@
 use std::env;
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum AppRole {
+    Api,
+    Worker,
+    All,
+}
+
+impl AppRole {
+    pub fn runs_api(&self) -> bool {
+        matches!(self, AppRole::Api | AppRole::All)
+    }
+    
+    pub fn runs_worker(&self) -> bool {
+        matches!(self, AppRole::Worker | AppRole::All)
+    }
+}
+
+impl FromStr for AppRole {
+    type Err = String;
+    
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_ascii_lowercase().as_str() {
+            "api" => Ok(AppRole::Api),
+            "worker" => Ok(AppRole::Worker),
+            "all" => Ok(AppRole::All),
+            _ => Err(format!("알 수 없는 APP_ROLE: {}", value)),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct Config {
@@ -8,6 +39,17 @@ pub struct Config {
     pub google_api_key: String,
     pub jwt_secret: String,
     pub redis_url: String,
+    pub app_role: AppRole,
+    pub video_collection_cron: String,
+    pub channel_cleanup_cron: String,
+    pub raw_video_archive_cron: String,
+    pub video_stats_refresh_cron: String,
+    pub channel_stats_refresh_cron: String,
+    pub comment_collection_cron: String,
+    pub keyword_sentiment_cron: String,
+    pub raw_video_archive_dir: String,
+    pub raw_video_retention_days: i64,
+    pub channel_analysis_monthly_quota: i64,
 }
 
 impl Config {
@@ -21,6 +63,26 @@ impl Config {
             google_api_key: env::var("GOOGLE_API_KEY").expect("GOOGLE_API_KEY must be set"),
             jwt_secret: env::var("JWT_SECRET").expect("JWT_SECRET must be set"),
             redis_url: env::var("REDIS_URL").expect("REDIS_URL must be set"),
+            app_role: env::var("APP_ROLE")
+                .map(|role| role.parse().expect("APP_ROLE must be one of api, worker, all"))
+                .unwrap_or(AppRole::All),
+            // 초 단위 필드를 포함한 cron 표현식 (Asia/Seoul 기준)
+            video_collection_cron: env::var("VIDEO_COLLECTION_CRON").unwrap_or_else(|_| "0 1 9 * * *".to_string()),
+            channel_cleanup_cron: env::var("CHANNEL_CLEANUP_CRON").unwrap_or_else(|_| "0 */10 * * * *".to_string()),
+            video_stats_refresh_cron: env::var("VIDEO_STATS_REFRESH_CRON").unwrap_or_else(|_| "0 30 */3 * * *".to_string()),
+            channel_stats_refresh_cron: env::var("CHANNEL_STATS_REFRESH_CRON").unwrap_or_else(|_| "0 0 6 * * *".to_string()),
+            // 일일 수집으로 오늘 순위표가 만들어진 뒤 실행
+            comment_collection_cron: env::var("COMMENT_COLLECTION_CRON").unwrap_or_else(|_| "0 0 11 * * *".to_string()),
+            // 댓글 수집이 끝난 뒤 실행
+            keyword_sentiment_cron: env::var("KEYWORD_SENTIMENT_CRON").unwrap_or_else(|_| "0 0 12 * * *".to_string()),
+            raw_video_archive_cron: env::var("RAW_VIDEO_ARCHIVE_CRON").unwrap_or_else(|_| "0 30 4 * * *".to_string()),
+            raw_video_archive_dir: env::var("RAW_VIDEO_ARCHIVE_DIR").unwrap_or_else(|_| "archive/raw_videos".to_string()),
+            raw_video_retention_days: env::var("RAW_VIDEO_RETENTION_DAYS")
+                .map(|days| days.parse().expect("RAW_VIDEO_RETENTION_DAYS must be a number"))
+                .unwrap_or(90),
+            channel_analysis_monthly_quota: env::var("CHANNEL_ANALYSIS_MONTHLY_QUOTA")
+                .map(|quota| quota.parse().expect("CHANNEL_ANALYSIS_MONTHLY_QUOTA must be a number"))
+                .unwrap_or(10),
         }
     }
 }
\ No newline at end of file
@@ -4,9 +4,11 @@ use serde::Serialize;
 use thiserror::Error;
 use utoipa::ToSchema;
 use crate::auth::auth_error::AuthError;
+use crate::pipeline::pipeline_error::PipelineError;
 use crate::user::user_error::UserError;
 use crate::youtube::youtube_channel::youtube_channel_error::YoutubeChannelError;
 use crate::youtube::youtube_data_api::youtube_data_api_error::YoutubeDataAPIError;
+use crate::youtube::youtube_video::youtube_video_error::YoutubeVideoError;
 
 #[derive(Error, Debug)]
 pub enum AppError {
@@ -23,7 +25,13 @@ pub enum AppError {
     YoutubeChannel(#[from] YoutubeChannelError),
     
     #[error(transparent)]
-    Auth(#[from] AuthError)
+    YoutubeVideo(#[from] YoutubeVideoError),
+    
+    #[error(transparent)]
+    Auth(#[from] AuthError),
+    
+    #[error(transparent)]
+    Pipeline(#[from] PipelineError),
 }
 
 #[derive(Serialize, ToSchema)]
@@ -48,14 +56,40 @@ impl ResponseError for AppError {
             },
             AppError::YoutubeChannel(e) => match e {   
                 YoutubeChannelError::ChannelNotFound(_) => StatusCode::NOT_FOUND,
-                YoutubeChannelError::ChannelDuplicated(_) => StatusCode::CONFLICT,
+                YoutubeChannelError::AnalysisInProgress(_) => StatusCode::CONFLICT,
+                YoutubeChannelError::InvalidAnalysisOptions(_) => StatusCode::BAD_REQUEST,
+                YoutubeChannelError::InvalidComparisonChannels => StatusCode::BAD_REQUEST,
+                YoutubeChannelError::AnalysisQuotaExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
+                YoutubeChannelError::BenchmarkDataUnavailable(_) => StatusCode::UNPROCESSABLE_ENTITY,
                 _ => StatusCode::INTERNAL_SERVER_ERROR,
             },
+            AppError::YoutubeVideo(e) => match e {
+                YoutubeVideoError::InvalidTimezone(_) => StatusCode::BAD_REQUEST,
+                YoutubeVideoError::InvalidDateRange(_, _) => StatusCode::BAD_REQUEST,
+                YoutubeVideoError::KeywordNotFound(_) => StatusCode::NOT_FOUND,
+                YoutubeVideoError::IdeaGenerationFailed(_) => StatusCode::BAD_GATEWAY,
+                YoutubeVideoError::IdeaGenerationInProgress(_) => StatusCode::CONFLICT,
+                YoutubeVideoError::IdeaRateLimitExceeded(_) => StatusCode::TOO_MANY_REQUESTS,
+                YoutubeVideoError::InvalidWatchlistRule(_) => StatusCode::BAD_REQUEST,
+                YoutubeVideoError::WatchlistLimitExceeded(_) => StatusCode::CONFLICT,
+                YoutubeVideoError::WatchlistNotFound(_) => StatusCode::NOT_FOUND,
+            },
             AppError::Auth(e) => match e {
                 AuthError::Unauthorized => StatusCode::UNAUTHORIZED,
                 AuthError::UserNotFound => StatusCode::NOT_FOUND,
                 AuthError::InvalidPassword => StatusCode::BAD_REQUEST,
+                AuthError::Forbidden => StatusCode::FORBIDDEN,
                 _ => StatusCode::INTERNAL_SERVER_ERROR,
+            },
+            AppError::Pipeline(e) => match e {
+                PipelineError::InvalidDateRange(_, _) => StatusCode::BAD_REQUEST,
+                PipelineError::RankingsAlreadyExist(_) => StatusCode::CONFLICT,
+                PipelineError::InvalidCronExpression(_, _) => StatusCode::INTERNAL_SERVER_ERROR,
+                PipelineError::RedisError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                PipelineError::DatabaseError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                PipelineError::JobFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+                PipelineError::LockLost(_) => StatusCode::CONFLICT,
+                PipelineError::JobAlreadyRunning(_) => StatusCode::CONFLICT,
             }
         }
     }
@@ -32,4 +32,41 @@ pub struct GeminiPromptVideoData<'a> {
     pub title: &'a str,
     pub description: &'a str,
     pub tags: Vec<&'a str>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GeminiSentimentResponse {
+    pub keyword_text: String,
+    pub positive: i64,
+    pub neutral: i64,
+    pub negative: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GeminiContentIdea {
+    pub hook: String,
+    pub format: String,
+    pub suggested_title: String,
+    #[serde(default)]
+    pub hashtags: Vec<String>,
+}
+
+#[derive(Serialize)]
+pub struct GeminiPromptIdeaVideoData<'a> {
+    pub title: &'a str,
+    pub description: &'a str,
+    pub view_count: i64,
+}
+
+#[derive(Serialize)]
+pub struct GeminiPromptSentimentData<'a> {
+    pub keyword_text: &'a str,
+    pub texts: Vec<&'a str>,
+}
+
+#[derive(Serialize)]
+pub struct GeminiPromptCommentData<'a> {
+    pub video_id: &'a str,
+    pub title: &'a str,
+    pub comments: Vec<&'a str>,
 }
\ No newline at end of file
@@ -4,7 +4,8 @@ use std::time::Duration;
 use tokio_retry::Retry;
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
 use crate::config::Config;
-use crate::gemini::gemini_api_model::{GeminiApiResponse, GeminiKeywordResponse, GeminiPromptVideoData};
+use serde::de::DeserializeOwned;
+use crate::gemini::gemini_api_model::{GeminiApiResponse, GeminiContentIdea, GeminiPromptIdeaVideoData, GeminiKeywordResponse, GeminiPromptCommentData, GeminiPromptSentimentData, GeminiPromptVideoData, GeminiSentimentResponse};
 use crate::youtube::youtube_video::youtube_video_model::YoutubeVideo;
 
 #[derive(Clone)]
@@ -46,6 +47,70 @@ impl GeminiAPIClient {
         let prompt = prompt_template.replace("__VIDEO_DATA_PLACEHOLDER__", &prompt_data_str);
         println!("{}", prompt);
         
+        let keywords_results: Vec<GeminiKeywordResponse> = self.generate_json(&prompt).await?;
+        let keyword_map: HashMap<String, Vec<String>> = keywords_results
+            .into_iter()
+            .map(|res| (res.video_id, res.keywords))
+            .collect();
+        
+        Ok(keyword_map)
+    }
+    
+    // 영상별 댓글에서 시청자가 이야기하는 키워드 추출 (video_id -> keywords)
+    pub async fn extract_comment_keywords_with_gemini(
+        &self,
+        videos: &[GeminiPromptCommentData<'_>],
+    ) -> Result<HashMap<String, Vec<String>>, Box<dyn Error>> {
+        if videos.is_empty() {
+            return Ok(HashMap::new());
+        }
+        
+        let prompt_data_str = serde_json::to_string(videos)?;
+        let prompt_template = include_str!("comment_keyword_extraction_prompt.txt");
+        let prompt = prompt_template.replace("__COMMENT_DATA_PLACEHOLDER__", &prompt_data_str);
+        
+        let keywords_results: Vec<GeminiKeywordResponse> = self.generate_json(&prompt).await?;
+        let keyword_map: HashMap<String, Vec<String>> = keywords_results
+            .into_iter()
+            .map(|res| (res.video_id, res.keywords))
+            .collect();
+        
+        Ok(keyword_map)
+    }
+    
+    // 키워드별 제목, 댓글을 긍정/중립/부정으로 분류한 개수
+    pub async fn classify_sentiments_with_gemini(
+        &self,
+        keywords: &[GeminiPromptSentimentData<'_>],
+    ) -> Result<Vec<GeminiSentimentResponse>, Box<dyn Error>> {
+        if keywords.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let prompt_data_str = serde_json::to_string(keywords)?;
+        let prompt_template = include_str!("sentiment_classification_prompt.txt");
+        let prompt = prompt_template.replace("__SENTIMENT_DATA_PLACEHOLDER__", &prompt_data_str);
+        
+        self.generate_json(&prompt).await
+    }
+    
+    // 키워드의 상위 영상을 참고한 쇼츠 기획안
+    pub async fn generate_content_ideas_with_gemini(
+        &self,
+        keyword_text: &str,
+        videos: &[GeminiPromptIdeaVideoData<'_>],
+    ) -> Result<Vec<GeminiContentIdea>, Box<dyn Error>> {
+        let prompt_data_str = serde_json::to_string(videos)?;
+        let prompt_template = include_str!("content_idea_prompt.txt");
+        let prompt = prompt_template
+            .replace("__KEYWORD_PLACEHOLDER__", keyword_text)
+            .replace("__VIDEO_DATA_PLACEHOLDER__", &prompt_data_str);
+        
+        self.generate_json(&prompt).await
+    }
+    
+    // 프롬프트를 보내고 JSON 응답을 원하는 타입으로 파싱
+    async fn generate_json<T: DeserializeOwned>(&self, prompt: &str) -> Result<T, Box<dyn Error>> {
         let api_url = "https://generativelanguage.googleapis.com/v1beta/models/gemini-2.0-flash:generateContent";
         
         let response = self.http_client
@@ -66,22 +131,16 @@ impl GeminiAPIClient {
             .json::<GeminiApiResponse>()
             .await?;
         
-        let keyword_json_str = response
+        let json_str = response
             .candidates
             .get(0)
             .and_then(|c| c.content.parts.get(0))
             .map(|p| p.text.as_str())
-            .ok_or("Gemini - 키워드 추출 실패")?;
+            .ok_or("Gemini - 응답 생성 실패")?;
         println!("\n--- Gemini로부터 받은 실제 응답 ---");
-        println!("{}", keyword_json_str);
+        println!("{}", json_str);
         println!("------------------------------------\n");
         
-        let keywords_results: Vec<GeminiKeywordResponse> = serde_json::from_str(&keyword_json_str)?;
-        let keyword_map: HashMap<String, Vec<String>> = keywords_results
-            .into_iter()
-            .map(|res| (res.video_id, res.keywords))
-            .collect();
-        
-        Ok(keyword_map)
+        Ok(serde_json::from_str(json_str)?)
     }
 }
\ No newline at end of file
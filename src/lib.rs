@@ -0,0 +1,10 @@
+pub mod user;
+pub mod app_state;
+pub mod config;
+pub mod errors;
+pub mod youtube;
+pub mod gemini;
+pub mod auth;
+pub mod redis;
+pub mod common;
+pub mod pipeline;
\ No newline at end of file
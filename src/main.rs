@@ -1,23 +1,15 @@
-mod user;
-mod app_state;
-mod config;
-mod errors;
-mod youtube;
-mod gemini;
-mod auth;
-mod redis;
-mod common;
-
 use actix_cors::Cors;
 use actix_web::{web, App, HttpServer};
 use actix_web::http::header;
 use utoipa::{Modify, OpenApi};
 use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa_swagger_ui::SwaggerUi;
-use crate::app_state::AppState;
-use crate::user::user_controller::UserApi;
-use youtube::youtube_video_controller::YoutubeApi;
-use crate::auth::auth_controller::AuthApi;
+use for_research::{auth, config, pipeline, user, youtube};
+use for_research::app_state::AppState;
+use for_research::user::user_controller::UserApi;
+use for_research::youtube::youtube_video_controller::YoutubeApi;
+use for_research::auth::auth_controller::AuthApi;
+use for_research::pipeline::pipeline_controller::PipelineApi;
 
 struct SecurityAddon;
 
@@ -43,6 +35,7 @@ impl Modify for SecurityAddon {
         (path = "/api/v1/user", api = UserApi),
         (path = "/api/v1/auth", api = AuthApi),
         (path = "/api/v1/data/youtube", api = YoutubeApi),
+        (path = "/api/v1/pipeline", api = PipelineApi),
     ),
     components(
         schemas(),
@@ -60,7 +53,16 @@ async fn main() -> std::io::Result<()>{
     let app_state = AppState::new(&config).await;
     
     env_logger::init();
-    youtube::youtube_video::youtube_video_scheduler::init_scheduler(app_state.clone());
+    
+    // APP_ROLE=api 인 인스턴스는 스케줄러를 띄우지 않아 웹 서버만 수평 확장 가능
+    if config.app_role.runs_worker() {
+        youtube::youtube_video::youtube_video_scheduler::init_scheduler(app_state.clone());
+    }
+    
+    if !config.app_role.runs_api() {
+        println!("[Worker] 스케줄러 전용으로 실행합니다.");
+        return tokio::signal::ctrl_c().await;
+    }
     
     HttpServer::new(move || {
         let cors = Cors::default()
@@ -81,6 +83,7 @@ async fn main() -> std::io::Result<()>{
             .service(web::scope("/api/v1/user").configure(user::user_controller::user_api))
             .service(web::scope("/api/v1/auth").configure(auth::auth_controller::auth_api))
             .service(web::scope("/api/v1/data/youtube").configure(youtube::youtube_video_controller::youtube_api))
+            .service(web::scope("/api/v1/pipeline").configure(pipeline::pipeline_controller::pipeline_api))
     })
         .bind(&config.server_address)?
         .run()
@@ -0,0 +1,5 @@
+pub mod pipeline_controller;
+pub mod pipeline_model;
+pub mod pipeline_error;
+pub mod pipeline_service;
+pub mod pipeline_repository;
\ No newline at end of file
@@ -0,0 +1,132 @@
+use actix_web::{get, post, web, HttpResponse};
+use utoipa::OpenApi;
+use crate::app_state::AppState;
+use crate::auth::auth_model::AuthenticatedUser;
+use crate::errors::{AppError, ErrorResponse};
+use crate::pipeline::pipeline_model::{JobLockResponse, ReprocessRequestQuery, ScheduledJobResponse};
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        request_reprocess_raw_videos,
+        get_job_locks,
+        get_scheduled_jobs,
+    ),
+    components(),
+    tags(
+        (name = "Pipeline", description = "Data pipeline admin endpoints")
+    )
+)]
+pub struct PipelineApi;
+
+pub fn pipeline_api(config: &mut web::ServiceConfig) {
+    config.service(request_reprocess_raw_videos)
+        .service(get_job_locks)
+        .service(get_scheduled_jobs);
+}
+
+#[utoipa::path(
+    post,
+    path = "/reprocess",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ReprocessRequestQuery
+    ),
+    responses(
+        (
+            status = 202,
+            description = "reprocess stored raw videos and recompute rankings. raw metadata keeps only the latest snapshot per video, so past charts can miss videos collected again later",
+            content_type = "application/json"
+        ),
+        (
+            status = 400,
+            body = ErrorResponse,
+            description = "invalid date range",
+        ),
+        (
+            status = 409,
+            body = ErrorResponse,
+            description = "rankings already exist in the range and force is not set, or video collection is already running",
+        ),
+        (
+            status = 403,
+            body = ErrorResponse,
+            description = "admin only",
+        )
+    ),
+    tags = ["Pipeline"]
+)]
+#[post("/reprocess")]
+pub async fn request_reprocess_raw_videos(
+    state: web::Data<AppState>,
+    query: web::Query<ReprocessRequestQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    state.auth_service.authorize_admin(&auth_user.public_id).await?;
+    let response = state.youtube_video_service.request_reprocess_raw_videos(query.from, query.to, query.force).await?;
+    Ok(HttpResponse::Accepted().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/locks",
+    security(
+        ("bearerAuth" = [])
+    ),
+    responses(
+        (
+            status = 200,
+            body = Vec<JobLockResponse>,
+            description = "get scheduled job locks",
+            content_type = "application/json"
+        ),
+        (
+            status = 403,
+            body = ErrorResponse,
+            description = "admin only",
+        )
+    ),
+    tags = ["Pipeline"]
+)]
+#[get("/locks")]
+pub async fn get_job_locks(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    state.auth_service.authorize_admin(&auth_user.public_id).await?;
+    let response = state.pipeline_service.get_job_locks().await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/jobs",
+    security(
+        ("bearerAuth" = [])
+    ),
+    responses(
+        (
+            status = 200,
+            body = Vec<ScheduledJobResponse>,
+            description = "get scheduled jobs with last success and next run time",
+            content_type = "application/json"
+        ),
+        (
+            status = 403,
+            body = ErrorResponse,
+            description = "admin only",
+        )
+    ),
+    tags = ["Pipeline"]
+)]
+#[get("/jobs")]
+pub async fn get_scheduled_jobs(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    state.auth_service.authorize_admin(&auth_user.public_id).await?;
+    let response = state.pipeline_service.get_scheduled_jobs().await?;
+    Ok(HttpResponse::Ok().json(response))
+}
\ No newline at end of file
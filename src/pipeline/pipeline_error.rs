@@ -0,0 +1,29 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PipelineError {
+    #[error("재처리 기간이 올바르지 않습니다. ({0} ~ {1})")]
+    InvalidDateRange(NaiveDate, NaiveDate),
+    
+    #[error("이미 순위표가 있는 날짜입니다: {0}. 덮어쓰려면 force=true 로 요청하세요.")]
+    RankingsAlreadyExist(String),
+    
+    #[error("'{0}' cron 표현식이 올바르지 않습니다: {1}")]
+    InvalidCronExpression(String, String),
+    
+    #[error("레디스 오류: {0}")]
+    RedisError(#[from] anyhow::Error),
+    
+    #[error("데이터베이스 처리 오류: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+    
+    #[error("작업 실행 실패: {0}")]
+    JobFailed(String),
+    
+    #[error("'{0}' 락을 잃어 작업을 중단했습니다.")]
+    LockLost(String),
+    
+    #[error("'{0}' 작업이 다른 인스턴스에서 실행 중입니다.")]
+    JobAlreadyRunning(String),
+}
\ No newline at end of file
@@ -0,0 +1,52 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToResponse, ToSchema};
+
+pub const JOB_VIDEO_COLLECTION: &str = "video_collection";
+pub const JOB_CHANNEL_CLEANUP: &str = "channel_cleanup";
+pub const JOB_RAW_VIDEO_ARCHIVE: &str = "raw_video_archive";
+pub const JOB_VIDEO_STATS_REFRESH: &str = "video_stats_refresh";
+pub const JOB_CHANNEL_STATS_REFRESH: &str = "channel_stats_refresh";
+pub const JOB_COMMENT_COLLECTION: &str = "comment_collection";
+pub const JOB_KEYWORD_SENTIMENT: &str = "keyword_sentiment";
+
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ReprocessRequestQuery {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    // 이미 있는 순위표를 재계산 결과로 덮어쓸지 여부
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JobLock {
+    pub token: String,
+    pub holder: String,
+    pub acquired_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SchedulerJobRun {
+    pub job_name: String,
+    pub last_success_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Clone, ToResponse, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledJobResponse {
+    pub job_name: String,
+    pub cron_expression: String,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Serialize, Debug, Clone, ToResponse, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct JobLockResponse {
+    pub job_name: String,
+    pub locked: bool,
+    pub holder: Option<String>,
+    pub acquired_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
\ No newline at end of file
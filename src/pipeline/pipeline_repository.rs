@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use mockall::automock;
+use sqlx::{Error, MySqlPool};
+use crate::pipeline::pipeline_model::SchedulerJobRun;
+
+#[automock]
+#[async_trait]
+pub trait PipelineRepository: Send + Sync {
+    async fn find_job_run(&self, job_name: &str) -> Result<Option<SchedulerJobRun>, Error>;
+    
+    async fn save_job_success(&self, job_name: &str, succeeded_at: DateTime<Utc>) -> Result<(), Error>;
+}
+
+#[derive(Clone)]
+pub struct PipelineSqlxRepository {
+    pub db_pool: MySqlPool,
+}
+
+impl PipelineSqlxRepository {
+    pub fn new(db_pool: MySqlPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PipelineRepository for PipelineSqlxRepository {
+    async fn find_job_run(&self, job_name: &str) -> Result<Option<SchedulerJobRun>, Error> {
+        let job_run = sqlx::query_as!(
+            SchedulerJobRun,
+            r#"
+                SELECT job_name, last_success_at
+                FROM scheduler_job_runs
+                WHERE job_name = ?
+            "#,
+            job_name
+        )
+            .fetch_optional(&self.db_pool)
+            .await?;
+        
+        Ok(job_run)
+    }
+    
+    async fn save_job_success(&self, job_name: &str, succeeded_at: DateTime<Utc>) -> Result<(), Error> {
+        sqlx::query!(
+            r#"
+                INSERT INTO scheduler_job_runs (job_name, last_success_at)
+                VALUES (?, ?)
+                ON DUPLICATE KEY UPDATE last_success_at = VALUES(last_success_at)
+            "#,
+            job_name,
+            succeeded_at
+        )
+            .execute(&self.db_pool)
+            .await?;
+        
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+    use super::*;
+    
+    async fn init_schema(pool: &MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS scheduler_job_runs (
+                job_name VARCHAR(64) NOT NULL PRIMARY KEY,
+                last_success_at TIMESTAMP NOT NULL,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    
+    #[sqlx::test]
+    async fn save_job_success_overwrites_last_success(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let pipeline_repository = PipelineSqlxRepository::new(pool.clone());
+        let first = DateTime::from_timestamp(1_751_328_060, 0).unwrap();
+        let second = first + TimeDelta::days(1);
+        
+        assert!(pipeline_repository.find_job_run("video_collection").await.unwrap().is_none());
+        
+        pipeline_repository.save_job_success("video_collection", first).await.unwrap();
+        pipeline_repository.save_job_success("video_collection", second).await.unwrap();
+        
+        let job_run = pipeline_repository.find_job_run("video_collection").await.unwrap().unwrap();
+        assert_eq!(job_run.last_success_at, second);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,296 @@
+use std::future::Future;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use chrono::{DateTime, TimeDelta, Utc};
+use chrono_tz::Asia::Seoul;
+use cron::Schedule;
+use tokio::sync::oneshot;
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+use crate::pipeline::pipeline_error::PipelineError;
+use crate::pipeline::pipeline_model::{JobLock, JobLockResponse, ScheduledJobResponse};
+use crate::pipeline::pipeline_repository::PipelineRepository;
+use crate::redis::redis_repository::RedisRepository;
+
+const JOB_LOCK_TTL_MILLIS: u64 = 60_000;
+const JOB_LOCK_RENEW_INTERVAL: Duration = Duration::from_secs(20);
+// 성공 기록이 없거나 오래된 작업은 이 기간 안의 실행만 보충 대상으로 봄
+const CATCH_UP_LOOKBACK_DAYS: i64 = 7;
+
+// 작업이 끝나거나 패닉으로 풀려도 락 연장 태스크가 남지 않도록 drop 시 중단
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+#[derive(Clone)]
+struct ScheduledJob {
+    job_name: &'static str,
+    cron_expression: String,
+    schedule: Schedule,
+}
+
+#[derive(Clone)]
+pub struct PipelineService {
+    redis_repository: Arc<RedisRepository>,
+    pipeline_repository: Arc<dyn PipelineRepository>,
+    scheduled_jobs: Vec<ScheduledJob>,
+    instance_id: String,
+}
+
+impl PipelineService {
+    pub fn new(
+        redis_repository: Arc<RedisRepository>,
+        pipeline_repository: Arc<dyn PipelineRepository>,
+        job_schedules: Vec<(&'static str, String)>,
+    ) -> Result<Self, PipelineError> {
+        let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown".to_string());
+        let scheduled_jobs = job_schedules
+            .into_iter()
+            .map(|(job_name, cron_expression)| {
+                let schedule = Schedule::from_str(&cron_expression)
+                    .map_err(|e| PipelineError::InvalidCronExpression(job_name.to_string(), e.to_string()))?;
+                Ok(ScheduledJob { job_name, cron_expression, schedule })
+            })
+            .collect::<Result<Vec<_>, PipelineError>>()?;
+        
+        Ok(Self {
+            redis_repository,
+            pipeline_repository,
+            scheduled_jobs,
+            instance_id: format!("{}:{}", host, std::process::id()),
+        })
+    }
+    
+    // 락을 잡은 인스턴스에서만 작업을 실행하고, 다른 인스턴스가 실행 중이면 None 반환
+    // 락을 잃으면 다른 인스턴스와 동시에 실행되지 않도록 작업을 중단하고, 작업이 성공하면 마지막 성공 시각을 기록
+    pub async fn run_exclusive<T, F, Fut>(&self, job_name: &str, job: F) -> Result<Option<T>, PipelineError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        let output = self.run_locked(job_name, job).await?;
+        if output.is_some() {
+            if let Err(e) = self.pipeline_repository.save_job_success(job_name, Utc::now()).await {
+                eprintln!("[Pipeline] '{}' 성공 기록 저장 실패: {}", job_name, e);
+            }
+        }
+        
+        Ok(output)
+    }
+    
+    // 예약 작업과 같은 락에서 실행하되 성공 기록은 남기지 않음 (예약 실행 보충 판단에 영향을 주지 않는 수동 작업용)
+    pub async fn run_locked<T, F, Fut>(&self, job_name: &str, job: F) -> Result<Option<T>, PipelineError>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, String>>,
+    {
+        let lock = JobLock {
+            token: Uuid::new_v4().to_string(),
+            holder: self.instance_id.clone(),
+            acquired_at: Utc::now(),
+        };
+        let lock_value = serde_json::to_string(&lock).map_err(anyhow::Error::from)?;
+        
+        if !self.redis_repository.acquire_lock(job_name, &lock_value, JOB_LOCK_TTL_MILLIS).await? {
+            return Ok(None);
+        }
+        
+        // 작업이 끝날 때까지 주기적으로 락 연장, 락을 잃으면 태스크가 끝남
+        // 연장이 계속 실패해 TTL 이 지나면 이미 만료된 것으로 보고 락을 잃은 것으로 처리
+        let mut renewal = {
+            let redis_repository = Arc::clone(&self.redis_repository);
+            let job_name = job_name.to_string();
+            let lock_value = lock_value.clone();
+            AbortOnDrop(tokio::spawn(async move {
+                let mut renewed_at = Instant::now();
+                loop {
+                    tokio::time::sleep(JOB_LOCK_RENEW_INTERVAL).await;
+                    match redis_repository.renew_lock(&job_name, &lock_value, JOB_LOCK_TTL_MILLIS).await {
+                        Ok(true) => renewed_at = Instant::now(),
+                        Ok(false) => {
+                            eprintln!("[Lock] '{}' 락을 잃었습니다.", job_name);
+                            break;
+                        }
+                        Err(e) => {
+                            eprintln!("[Lock] '{}' 락 연장 실패: {}", job_name, e);
+                            if renewed_at.elapsed() >= Duration::from_millis(JOB_LOCK_TTL_MILLIS) {
+                                eprintln!("[Lock] '{}' 락이 만료되었습니다.", job_name);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }))
+        };
+        
+        let job_result = tokio::select! {
+            job_result = job() => job_result,
+            _ = &mut renewal.0 => return Err(PipelineError::LockLost(job_name.to_string())),
+        };
+        
+        drop(renewal);
+        // 작업 결과는 이미 확정됐으므로 해제 실패는 기록만 하고, 락은 TTL 이 지나면 만료
+        if let Err(e) = self.redis_repository.release_lock(job_name, &lock_value).await {
+            eprintln!("[Lock] '{}' 락 해제 실패: {}", job_name, e);
+        }
+        
+        let output = job_result.map_err(PipelineError::JobFailed)?;
+        
+        Ok(Some(output))
+    }
+    
+    // run_locked 와 같은 락에서 작업을 백그라운드로 실행하고, 락을 잡아 작업이 시작됐는지만 바로 반환
+    // 다른 인스턴스가 실행 중이면 false, 작업 결과는 기록만 남김
+    pub async fn spawn_locked<F, Fut>(&self, job_name: &'static str, job: F) -> Result<bool, PipelineError>
+    where
+        F: FnOnce() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        let (started_tx, started_rx) = oneshot::channel();
+        let pipeline_service = self.clone();
+        let handle = tokio::spawn(async move {
+            pipeline_service
+                .run_locked(job_name, move || {
+                    let _ = started_tx.send(());
+                    job()
+                })
+                .await
+        });
+        
+        // 작업을 시작하지 못하고 끝나면 송신자가 버려져 수신이 실패함
+        if started_rx.await.is_ok() {
+            tokio::spawn(async move {
+                match handle.await {
+                    Ok(Ok(_)) => {}
+                    Ok(Err(e)) => eprintln!("[Background Job] '{}' 실패: {}", job_name, e),
+                    Err(e) => eprintln!("[Background Job] '{}' 비정상 종료: {}", job_name, e),
+                }
+            });
+            return Ok(true);
+        }
+        
+        match handle.await {
+            Ok(result) => result.map(|output| output.is_some()),
+            Err(e) => Err(PipelineError::JobFailed(e.to_string())),
+        }
+    }
+    
+    pub fn next_run_at(&self, job_name: &str, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let scheduled_job = self.scheduled_jobs.iter().find(|job| job.job_name == job_name)?;
+        
+        scheduled_job.schedule
+            .after(&after.with_timezone(&Seoul))
+            .next()
+            .map(|next_run_at| next_run_at.with_timezone(&Utc))
+    }
+    
+    // 마지막 성공 이후 이미 지난 예정 실행 중 가장 최근 실행 시각, 보충할 실행이 없으면 None
+    // 성공 기록이 없는 작업(첫 배포)도 최근 기간 안에 지난 실행이 있으면 보충
+    pub async fn find_missed_run(&self, job_name: &str) -> Result<Option<DateTime<Utc>>, PipelineError> {
+        let Some(scheduled_job) = self.scheduled_jobs.iter().find(|job| job.job_name == job_name) else {
+            return Ok(None);
+        };
+        
+        let now = Utc::now();
+        let lookback_start = now - TimeDelta::days(CATCH_UP_LOOKBACK_DAYS);
+        let since = match self.pipeline_repository.find_job_run(job_name).await? {
+            Some(job_run) => job_run.last_success_at.max(lookback_start),
+            None => lookback_start,
+        };
+        
+        Ok(latest_run_between(&scheduled_job.schedule, since, now))
+    }
+    
+    pub async fn get_scheduled_jobs(&self) -> Result<Vec<ScheduledJobResponse>, PipelineError> {
+        let now = Utc::now();
+        let mut responses = Vec::with_capacity(self.scheduled_jobs.len());
+        
+        for scheduled_job in &self.scheduled_jobs {
+            let job_run = self.pipeline_repository.find_job_run(scheduled_job.job_name).await?;
+            responses.push(ScheduledJobResponse {
+                job_name: scheduled_job.job_name.to_string(),
+                cron_expression: scheduled_job.cron_expression.clone(),
+                last_success_at: job_run.map(|job_run| job_run.last_success_at),
+                next_run_at: self.next_run_at(scheduled_job.job_name, now),
+            });
+        }
+        
+        Ok(responses)
+    }
+    
+    pub async fn get_job_locks(&self) -> Result<Vec<JobLockResponse>, PipelineError> {
+        let mut responses = Vec::with_capacity(self.scheduled_jobs.len());
+        
+        for scheduled_job in &self.scheduled_jobs {
+            let job_name = scheduled_job.job_name;
+            let response = match self.redis_repository.get_lock(job_name).await? {
+                Some((lock_value, ttl_millis)) => {
+                    let lock = serde_json::from_str::<JobLock>(&lock_value).ok();
+                    JobLockResponse {
+                        job_name: job_name.to_string(),
+                        locked: true,
+                        holder: lock.as_ref().map(|lock| lock.holder.clone()),
+                        acquired_at: lock.as_ref().map(|lock| lock.acquired_at),
+                        expires_at: (ttl_millis >= 0).then(|| Utc::now() + TimeDelta::milliseconds(ttl_millis)),
+                    }
+                }
+                None => JobLockResponse {
+                    job_name: job_name.to_string(),
+                    locked: false,
+                    holder: None,
+                    acquired_at: None,
+                    expires_at: None,
+                },
+            };
+            responses.push(response);
+        }
+        
+        Ok(responses)
+    }
+}
+
+fn latest_run_between(schedule: &Schedule, after: DateTime<Utc>, until: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    schedule
+        .after(&after.with_timezone(&Seoul))
+        .map(|run_at| run_at.with_timezone(&Utc))
+        .take_while(|run_at| *run_at <= until)
+        .last()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn utc(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+    
+    #[test]
+    fn latest_run_between_returns_most_recent_missed_run() {
+        // 매일 09:01 KST (00:01 UTC)
+        let schedule = Schedule::from_str("0 1 9 * * *").unwrap();
+        
+        assert_eq!(
+            latest_run_between(&schedule, utc("2026-01-01T00:00:00Z"), utc("2026-01-03T00:00:00Z")),
+            Some(utc("2026-01-02T00:01:00Z"))
+        );
+        assert_eq!(latest_run_between(&schedule, utc("2026-01-02T00:01:00Z"), utc("2026-01-03T00:00:00Z")), None);
+    }
+    
+    #[test]
+    fn new_rejects_invalid_cron_expression() {
+        let pipeline_repository = Arc::new(crate::pipeline::pipeline_repository::MockPipelineRepository::new());
+        let result = PipelineService::new(
+            crate::common::test_support::test_redis_repository(),
+            pipeline_repository,
+            vec![("video_collection", "매일 아침".to_string())],
+        );
+        
+        assert!(matches!(result, Err(PipelineError::InvalidCronExpression(job_name, _)) if job_name == "video_collection"));
+    }
+}
\ No newline at end of file
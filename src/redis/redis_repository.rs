@@ -1,5 +1,6 @@
+use chrono::NaiveDate;
 use r2d2::Pool;
-use redis::{Client, Commands};
+use redis::{Client, Commands, ExistenceCheck, Script, SetExpiry, SetOptions};
 
 pub struct RedisRepository {
     pool: Pool<Client>,
@@ -34,4 +35,104 @@ impl RedisRepository {
         
         Ok(())
     }
+    
+    pub async fn increment_youtube_quota_usage(&self, api_key_label: &str, quota_date: NaiveDate, units: i64) -> Result<i64, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("youtube:quota:{}:{}", api_key_label, quota_date);
+        let used_units: i64 = conn.incr(&key, units)?;
+        let _: () = conn.expire(&key, 172800)?;
+        
+        Ok(used_units)
+    }
+    
+    pub async fn get_youtube_quota_usage(&self, api_key_label: &str, quota_date: NaiveDate) -> Result<i64, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("youtube:quota:{}:{}", api_key_label, quota_date);
+        let used_units: Option<i64> = conn.get(&key)?;
+        
+        Ok(used_units.unwrap_or(0))
+    }
+    
+    pub async fn set_keyword_ideas(&self, keyword_text: &str, generated_date: NaiveDate, value: &str) -> Result<(), anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("keyword:ideas:{}:{}", generated_date, keyword_text);
+        let _: () = conn.set_ex(&key, value, 172800)?;
+        
+        Ok(())
+    }
+    
+    pub async fn get_keyword_ideas(&self, keyword_text: &str, generated_date: NaiveDate) -> Result<Option<String>, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("keyword:ideas:{}:{}", generated_date, keyword_text);
+        let value: Option<String> = conn.get(&key)?;
+        
+        Ok(value)
+    }
+    
+    pub async fn increment_keyword_idea_requests(&self, user_public_id: &str, request_date: NaiveDate) -> Result<i64, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("user:{}:keyword_ideas:{}", user_public_id, request_date);
+        let request_count: i64 = conn.incr(&key, 1)?;
+        let _: () = conn.expire(&key, 172800)?;
+        
+        Ok(request_count)
+    }
+    
+    pub async fn decrement_keyword_idea_requests(&self, user_public_id: &str, request_date: NaiveDate) -> Result<(), anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("user:{}:keyword_ideas:{}", user_public_id, request_date);
+        let _: i64 = conn.decr(&key, 1)?;
+        
+        Ok(())
+    }
+    
+    pub async fn acquire_lock(&self, lock_name: &str, lock_value: &str, ttl_millis: u64) -> Result<bool, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("lock:{}", lock_name);
+        let options = SetOptions::default()
+            .conditional_set(ExistenceCheck::NX)
+            .with_expiration(SetExpiry::PX(ttl_millis));
+        let result: Option<String> = conn.set_options(&key, lock_value, options)?;
+        
+        Ok(result.is_some())
+    }
+    
+    pub async fn renew_lock(&self, lock_name: &str, lock_value: &str, ttl_millis: u64) -> Result<bool, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("lock:{}", lock_name);
+        // 본인이 잡은 락일 때만 만료 시간 연장
+        let script = Script::new(r#"
+            if redis.call('GET', KEYS[1]) == ARGV[1] then
+                return redis.call('PEXPIRE', KEYS[1], ARGV[2])
+            end
+            return 0
+        "#);
+        let renewed: i64 = script.key(&key).arg(lock_value).arg(ttl_millis).invoke(&mut *conn)?;
+        
+        Ok(renewed == 1)
+    }
+    
+    pub async fn release_lock(&self, lock_name: &str, lock_value: &str) -> Result<bool, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("lock:{}", lock_name);
+        // 본인이 잡은 락일 때만 해제
+        let script = Script::new(r#"
+            if redis.call('GET', KEYS[1]) == ARGV[1] then
+                return redis.call('DEL', KEYS[1])
+            end
+            return 0
+        "#);
+        let released: i64 = script.key(&key).arg(lock_value).invoke(&mut *conn)?;
+        
+        Ok(released == 1)
+    }
+    
+    pub async fn get_lock(&self, lock_name: &str) -> Result<Option<(String, i64)>, anyhow::Error> {
+        let mut conn = self.pool.get()?;
+        let key = format!("lock:{}", lock_name);
+        let lock_value: Option<String> = conn.get(&key)?;
+        let ttl_millis: i64 = conn.pttl(&key)?;
+        
+        Ok(lock_value.map(|value| (value, ttl_millis)))
+    }
 }
\ No newline at end of file
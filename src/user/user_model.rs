@@ -2,6 +2,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::{ToResponse, ToSchema};
 
+pub const USER_ROLE_USER: &str = "user";
+pub const USER_ROLE_ADMIN: &str = "admin";
+
 #[derive(Serialize, Debug, Clone, sqlx::FromRow, PartialEq)]
 pub struct User {
     pub id: i64,
@@ -9,6 +12,7 @@ pub struct User {
     pub password: String,
     pub username: String,
     pub public_id: String,
+    pub role: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -32,6 +36,7 @@ pub struct UserResponse {
     pub id: i64,
     pub email: String,
     pub username: String,
+    pub role: String,
     pub created_at: DateTime<Utc>,
 }
 
@@ -41,6 +46,7 @@ impl From<User> for UserResponse {
             id: user.id,
             email: user.email,
             username: user.username,
+            role: user.role,
             created_at: user.created_at,
         }
     }
@@ -21,6 +21,8 @@ pub trait UserRepository: Send + Sync {
     async fn create(&self, user: User) -> Result<i64, Error>;
     
     async fn update(&self, id: i64, email: &str, username: &str) -> Result<(), Error>;
+    
+    async fn update_role_by_email(&self, email: &str, role: &str) -> Result<(), Error>;
 }
 
 #[derive(Clone)]
@@ -40,7 +42,7 @@ impl UserRepository for UserSqlxRepository {
         let user = sqlx::query_as!(
             User,
             r#"
-                SELECT id, email, password, username, public_id, created_at, updated_at
+                SELECT id, email, password, username, public_id, role, created_at, updated_at
                 FROM users
                 WHERE id = ?
             "#,
@@ -55,7 +57,7 @@ impl UserRepository for UserSqlxRepository {
         let user = sqlx::query_as!(
             User,
             r#"
-                SELECT id, email, password, username, public_id, created_at, updated_at
+                SELECT id, email, password, username, public_id, role, created_at, updated_at
                 FROM users
                 WHERE email = ?
             "#,
@@ -71,7 +73,7 @@ impl UserRepository for UserSqlxRepository {
         let user = sqlx::query_as!(
             User,
             r#"
-                SELECT id, email, password, username, public_id, created_at, updated_at
+                SELECT id, email, password, username, public_id, role, created_at, updated_at
                 FROM users
                 WHERE public_id = ?
             "#,
@@ -131,10 +133,10 @@ impl UserRepository for UserSqlxRepository {
     async fn create(&self, user: User) -> Result<i64, Error> {
         let result = sqlx::query!(
             r#"
-                INSERT INTO users (email, password, username, public_id)
-                VALUES (?, ?, ?, ?)
+                INSERT INTO users (email, password, username, public_id, role)
+                VALUES (?, ?, ?, ?, ?)
             "#,
-            user.email, user.password, user.username, user.public_id
+            user.email, user.password, user.username, user.public_id, user.role
         )
             .execute(&self.db_pool)
             .await?;
@@ -158,12 +160,29 @@ impl UserRepository for UserSqlxRepository {
             
         Ok(())
     }
+    
+    async fn update_role_by_email(&self, email: &str, role: &str) -> Result<(), Error> {
+        sqlx::query!(
+            r#"
+                UPDATE users
+                SET role = ?
+                WHERE email = ?
+            "#,
+            role,
+            email
+        )
+            .execute(&self.db_pool)
+            .await?;
+        
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use uuid::Uuid;
     use super::*;
+    use crate::user::user_model::{USER_ROLE_ADMIN, USER_ROLE_USER};
 
     async fn init_schema(pool: &MySqlPool) {
         sqlx::query("
@@ -173,6 +192,7 @@ mod tests {
                 password VARCHAR(255) NOT NULL,
                 username VARCHAR(255) NOT NULL UNIQUE,
                 public_id VARCHAR(255) NOT NULL UNIQUE,
+                role VARCHAR(20) NOT NULL DEFAULT 'user',
                 created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
                 updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
             )
@@ -195,6 +215,7 @@ mod tests {
             password: password.to_string(),
             username: username.to_string(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -228,6 +249,7 @@ mod tests {
             password: password.to_string(),
             username: username1.to_string(),
             public_id: uuid1.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -237,6 +259,7 @@ mod tests {
             password: password.to_string(),
             username: username2.to_string(),
             public_id: uuid2.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -264,6 +287,7 @@ mod tests {
             password: password.to_string(),
             username: username.to_string(),
             public_id: uuid1.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -273,6 +297,7 @@ mod tests {
             password: password.to_string(),
             username: username.to_string(),
             public_id: uuid2.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -300,6 +325,7 @@ mod tests {
                 password: password.to_string(),
                 username: username1.to_string(),
                 public_id: uuid.clone(),
+                role: USER_ROLE_USER.to_string(),
                 created_at: Default::default(),
                 updated_at: Default::default(),
             };
@@ -309,6 +335,7 @@ mod tests {
                 password: password.to_string(),
                 username: username2.to_string(),
                 public_id: uuid.clone(),
+                role: USER_ROLE_USER.to_string(),
                 created_at: Default::default(),
                 updated_at: Default::default(),
             };
@@ -330,6 +357,7 @@ mod tests {
             password: "test".to_string(),
             username: "test".to_string(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -366,6 +394,7 @@ mod tests {
             password: "test".to_string(),
             username: "test".to_string(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -392,6 +421,7 @@ mod tests {
             password: "test".to_string(),
             username: "test".to_string(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -413,6 +443,7 @@ mod tests {
             password: "test".to_string(),
             username: "test".to_string(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -446,6 +477,7 @@ mod tests {
             password: "test".to_string(),
             username: "test".to_string(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -479,6 +511,7 @@ mod tests {
             password: "test".to_string(),
             username: "test".to_string(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -506,6 +539,7 @@ mod tests {
             password: "password".to_string(),
             username: "userA".to_string(),
             public_id: uuid1.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -515,6 +549,7 @@ mod tests {
             password: "password".to_string(),
             username: "userB".to_string(),
             public_id: uuid2.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -537,6 +572,7 @@ mod tests {
             password: "password".to_string(),
             username: "userA".to_string(),
             public_id: uuid1.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -546,6 +582,7 @@ mod tests {
             password: "password".to_string(),
             username: "userB".to_string(),
             public_id: uuid2.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -555,4 +592,28 @@ mod tests {
         let result = user_repository.update(user1_id,"userB@email.com","userAA").await;
         assert!(result.is_err());
     }
+
+    #[sqlx::test]
+    async fn update_role_by_email_success(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let user_repository = UserSqlxRepository::new(pool);
+        let uuid = Uuid::new_v4().to_string();
+        let user = User {
+            id: Default::default(),
+            email: "admin@example.com".to_string(),
+            password: "password".to_string(),
+            username: "admin".to_string(),
+            public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        };
+        let user_id = user_repository.create(user).await.unwrap();
+
+        let result = user_repository.update_role_by_email("admin@example.com", USER_ROLE_ADMIN).await;
+        assert!(result.is_ok());
+
+        let user = user_repository.find_by_id(user_id).await.unwrap();
+        assert_eq!(user.role, USER_ROLE_ADMIN);
+    }
 }
\ No newline at end of file
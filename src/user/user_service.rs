@@ -2,7 +2,7 @@ use std::sync::Arc;
 use bcrypt::{hash, DEFAULT_COST};
 use uuid::Uuid;
 use crate::user::user_error::UserError;
-use crate::user::user_model::{User, UserCreateRequest, UserResponse, UserUpdateRequest};
+use crate::user::user_model::{User, UserCreateRequest, UserResponse, UserUpdateRequest, USER_ROLE_USER};
 use crate::user::user_repository::UserRepository;
 
 #[derive(Clone)]
@@ -50,6 +50,7 @@ impl UserService {
             password: hashed_password,
             username: request.username,
             public_id: uuid.to_string(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -62,13 +63,32 @@ impl UserService {
         let result = self.user_repository.update(user_update.id, &user_update.email, &user_update.username).await?;
         Ok(())
     }
+    
+    pub async fn get_user_by_email(&self, email: &str) -> Result<UserResponse, UserError> {
+        self.user_repository
+            .find_by_email(email)
+            .await
+            .map_err(|db_error| {
+                match db_error {
+                    sqlx::Error::RowNotFound => UserError::NotFound,
+                    _ => db_error.into(),
+                }
+            })
+            .map(|user| user.into())
+    }
+    
+    pub async fn update_user_role(&self, email: &str, role: &str) -> Result<(), UserError> {
+        self.get_user_by_email(email).await?;
+        self.user_repository.update_role_by_email(email, role).await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::user::user_repository::MockUserRepository;
-    use crate::user::user_model::{User};
+    use crate::user::user_model::{User, USER_ROLE_ADMIN};
     use mockall::predicate::*;
     use std::sync::Arc;
     use bcrypt::verify;
@@ -92,6 +112,7 @@ mod tests {
             password: hash(password.clone(), DEFAULT_COST).unwrap(),
             username: username.clone(),
             public_id: uuid.clone(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         };
@@ -180,6 +201,7 @@ mod tests {
             password: password.to_string(),
             username: name.to_string(),
             public_id: Default::default(),
+            role: USER_ROLE_USER.to_string(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -232,4 +254,22 @@ mod tests {
         
         assert!(matches!(result, Err(UserError::DatabaseError(_))));
     }
+
+    #[tokio::test]
+    async fn update_user_role_fails_when_user_not_found() {
+        let mut user_repository = MockUserRepository::new();
+        let email = "unknown@example.com";
+
+        user_repository.expect_find_by_email()
+            .with(eq(email))
+            .times(1)
+            .returning(|_| Err(sqlx::Error::RowNotFound));
+        user_repository.expect_update_role_by_email().never();
+
+        let user_service = UserService::new(Arc::new(user_repository));
+
+        let result = user_service.update_user_role(email, USER_ROLE_ADMIN).await;
+
+        assert!(matches!(result, Err(UserError::NotFound)));
+    }
 }
\ No newline at end of file
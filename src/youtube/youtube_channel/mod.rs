@@ -1,4 +1,7 @@
 pub mod youtube_channel_model;
 pub mod youtube_channel_repository;
 pub mod youtube_channel_service;
-pub mod youtube_channel_error;
\ No newline at end of file
+pub mod youtube_channel_error;
+pub mod youtube_channel_comparison;
+pub mod youtube_channel_benchmark;
+pub mod youtube_channel_gap;
\ No newline at end of file
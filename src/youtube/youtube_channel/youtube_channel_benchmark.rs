@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+use chrono::{Datelike, Timelike};
+use chrono_tz::Asia::Seoul;
+use crate::common::statistics::{mean, median, round4};
+use crate::youtube::youtube_channel::youtube_channel_model::{ChannelBenchmarkResponse, FormatBenchmark, PublishSlotBenchmark, YoutubeChannel, YoutubeChannelVideo, SHORTS_MAX_DURATION_SECONDS};
+
+// 요일, 시간대 추천은 표본이 너무 적으면 의미가 없으므로 최소 영상 수를 둠
+const MIN_SLOT_VIDEO_COUNT: usize = 2;
+
+// 채널 분석 때 저장한 영상으로 업로드 성과 지표 계산
+pub fn build_channel_benchmark(channel: &YoutubeChannel, videos: &[YoutubeChannelVideo]) -> ChannelBenchmarkResponse {
+    let views: Vec<f64> = videos.iter().map(|video| video.view_count as f64).collect();
+    let mean_views = mean(&views);
+    let views_per_subscriber = if channel.subscriber_count > 0 {
+        round4(mean_views / channel.subscriber_count as f64)
+    } else {
+        0.0
+    };
+    
+    let mut published_ats: Vec<_> = videos.iter().map(|video| video.published_at).collect();
+    published_ats.sort();
+    let longest_gap_days = published_ats
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).num_seconds() as f64 / 86_400.0)
+        .fold(0.0, f64::max);
+    // 첫 업로드부터 마지막 업로드까지를 기간으로 보되 최소 1주로 계산
+    let uploads_per_week = match (published_ats.first(), published_ats.last()) {
+        (Some(first), Some(last)) => {
+            let weeks = ((*last - *first).num_seconds() as f64 / (86_400.0 * 7.0)).max(1.0);
+            round4(videos.len() as f64 / weeks)
+        }
+        _ => 0.0,
+    };
+    
+    let (shorts, long_form): (Vec<&YoutubeChannelVideo>, Vec<&YoutubeChannelVideo>) = videos
+        .iter()
+        .partition(|video| video.duration <= SHORTS_MAX_DURATION_SECONDS);
+    
+    let mut weekday_views: HashMap<String, Vec<f64>> = HashMap::new();
+    let mut hour_views: HashMap<String, Vec<f64>> = HashMap::new();
+    for video in videos {
+        let published_at = video.published_at.with_timezone(&Seoul);
+        weekday_views.entry(published_at.weekday().to_string()).or_default().push(video.view_count as f64);
+        hour_views.entry(published_at.hour().to_string()).or_default().push(video.view_count as f64);
+    }
+    
+    ChannelBenchmarkResponse {
+        channel_handle: channel.channel_handle.clone(),
+        video_count: videos.len(),
+        median_views: sorted_median(views),
+        mean_views: round4(mean_views),
+        views_per_subscriber,
+        uploads_per_week,
+        longest_gap_days: round4(longest_gap_days),
+        shorts: format_benchmark(&shorts, videos.len()),
+        long_form: format_benchmark(&long_form, videos.len()),
+        best_weekday: best_slot(weekday_views),
+        best_hour: best_slot(hour_views),
+    }
+}
+
+fn format_benchmark(videos: &[&YoutubeChannelVideo], total_count: usize) -> FormatBenchmark {
+    let views: Vec<f64> = videos.iter().map(|video| video.view_count as f64).collect();
+    
+    FormatBenchmark {
+        video_count: videos.len(),
+        ratio: if total_count > 0 { round4(videos.len() as f64 / total_count as f64) } else { 0.0 },
+        mean_views: round4(mean(&views)),
+        median_views: sorted_median(views),
+    }
+}
+
+// 중앙값 조회수가 가장 높은 슬롯 (동률이면 슬롯 이름 순)
+fn best_slot(slot_views: HashMap<String, Vec<f64>>) -> Option<PublishSlotBenchmark> {
+    slot_views
+        .into_iter()
+        .filter(|(_, views)| views.len() >= MIN_SLOT_VIDEO_COUNT)
+        .map(|(slot, views)| PublishSlotBenchmark {
+            slot,
+            video_count: views.len(),
+            median_views: sorted_median(views),
+        })
+        .max_by(|a, b| {
+            a.median_views
+                .total_cmp(&b.median_views)
+                .then_with(|| b.slot.cmp(&a.slot))
+        })
+}
+
+fn sorted_median(mut values: Vec<f64>) -> f64 {
+    values.sort_by(f64::total_cmp);
+    round4(median(&values))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use super::*;
+    use crate::common::test_support::test_channel;
+    
+    fn video(published_at: &str, duration: i32, view_count: i64) -> YoutubeChannelVideo {
+        YoutubeChannelVideo {
+            id: 0,
+            youtube_channel_id: 1,
+            video_id: published_at.to_string(),
+            title: String::new(),
+            published_at: published_at.parse::<DateTime<Utc>>().unwrap(),
+            duration,
+            view_count,
+            like_count: 0,
+            comment_count: 0,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        }
+    }
+    
+    #[test]
+    fn build_channel_benchmark_computes_cadence_and_format_split() {
+        let channel = YoutubeChannel {
+            id: 1,
+            subscriber_count: 1_000,
+            ..test_channel("@test")
+        };
+        // KST 기준 2026-01-05(월) 19시, 2026-01-12(월) 19시, 2026-01-26(월) 20시
+        let videos = vec![
+            video("2026-01-05T10:00:00Z", 30, 1_000),
+            video("2026-01-12T10:00:00Z", 600, 3_000),
+            video("2026-01-26T11:00:00Z", 45, 2_000),
+        ];
+        
+        let benchmark = build_channel_benchmark(&channel, &videos);
+        
+        assert_eq!(benchmark.median_views, 2_000.0);
+        assert_eq!(benchmark.views_per_subscriber, 2.0);
+        assert_eq!(benchmark.uploads_per_week, 0.998);
+        assert_eq!(benchmark.longest_gap_days, 14.0417);
+        assert_eq!(benchmark.shorts.video_count, 2);
+        assert_eq!(benchmark.long_form.median_views, 3_000.0);
+        assert_eq!(benchmark.best_weekday.unwrap().slot, "Mon");
+        assert_eq!(benchmark.best_hour.unwrap().slot, "19");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+use crate::common::statistics::round4;
+use crate::youtube::youtube_channel::youtube_channel_error::YoutubeChannelError;
+use crate::youtube::youtube_channel::youtube_channel_model::{ChannelComparisonEntry, ChannelComparisonResponse, ChannelKeywordResponse, ChannelSimilarityResponse, SharedKeywordResponse, YoutubeChannel, YoutubeChannelKeyword};
+
+const UNIQUE_KEYWORD_LIMIT: usize = 50;
+const SHARED_KEYWORD_LIMIT: usize = 100;
+
+// 채널별 전체 키워드 조회수로 공통 키워드, 채널 고유 키워드, 조회수 점유율, 유사도를 계산
+pub fn compare_channel_keywords(channels: &[(YoutubeChannel, Vec<YoutubeChannelKeyword>)]) -> ChannelComparisonResponse {
+    let keyword_maps: Vec<HashMap<&str, i64>> = channels
+        .iter()
+        .map(|(_, keywords)| {
+            keywords
+                .iter()
+                .map(|keyword| (keyword.keyword_text.as_str(), keyword.view_count))
+                .collect()
+        })
+        .collect();
+    let total_volume: i64 = keyword_maps.iter().flat_map(|keyword_map| keyword_map.values()).sum();
+    
+    let entries = channels
+        .iter()
+        .zip(&keyword_maps)
+        .enumerate()
+        .map(|(index, ((channel, keywords), keyword_map))| {
+            let keyword_volume: i64 = keyword_map.values().sum();
+            let mut unique_keywords: Vec<&YoutubeChannelKeyword> = keywords
+                .iter()
+                .filter(|keyword| {
+                    keyword_maps
+                        .iter()
+                        .enumerate()
+                        .all(|(other, other_map)| other == index || !other_map.contains_key(keyword.keyword_text.as_str()))
+                })
+                .collect();
+            unique_keywords.sort_by(|a, b| b.view_count.cmp(&a.view_count));
+            
+            ChannelComparisonEntry {
+                channel_handle: channel.channel_handle.clone(),
+                channel_title: channel.channel_title.clone(),
+                keyword_count: keyword_map.len(),
+                keyword_volume,
+                volume_share: ratio(keyword_volume as f64, total_volume as f64),
+                unique_keywords: unique_keywords
+                    .into_iter()
+                    .take(UNIQUE_KEYWORD_LIMIT)
+                    .map(ChannelKeywordResponse::from)
+                    .collect(),
+            }
+        })
+        .collect();
+    
+    // 모든 채널에 등장하는 키워드만 공통 키워드로 취급
+    let mut shared_keywords: Vec<SharedKeywordResponse> = match keyword_maps.split_first() {
+        Some((first, rest)) => first
+            .keys()
+            .filter(|keyword_text| rest.iter().all(|keyword_map| keyword_map.contains_key(*keyword_text)))
+            .map(|keyword_text| {
+                let view_counts: Vec<i64> = keyword_maps.iter().map(|keyword_map| keyword_map[keyword_text]).collect();
+                SharedKeywordResponse {
+                    keyword_text: keyword_text.to_string(),
+                    total_view_count: view_counts.iter().sum(),
+                    view_counts,
+                }
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    shared_keywords.sort_by(|a, b| {
+        b.total_view_count
+            .cmp(&a.total_view_count)
+            .then_with(|| a.keyword_text.cmp(&b.keyword_text))
+    });
+    shared_keywords.truncate(SHARED_KEYWORD_LIMIT);
+    
+    let mut similarities = Vec::new();
+    for (index, (channel, _)) in channels.iter().enumerate() {
+        for (other, (other_channel, _)) in channels.iter().enumerate().skip(index + 1) {
+            similarities.push(ChannelSimilarityResponse {
+                channel_handle: channel.channel_handle.clone(),
+                other_channel_handle: other_channel.channel_handle.clone(),
+                score: cosine_similarity(&keyword_maps[index], &keyword_maps[other]),
+            });
+        }
+    }
+    
+    ChannelComparisonResponse {
+        channels: entries,
+        shared_keywords,
+        similarities,
+    }
+}
+
+// 키워드별 조회수 벡터의 코사인 유사도 (채널 규모와 무관하게 키워드 구성만 비교)
+fn cosine_similarity(a: &HashMap<&str, i64>, b: &HashMap<&str, i64>) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .filter_map(|(keyword_text, view_count)| b.get(keyword_text).map(|other| *view_count as f64 * *other as f64))
+        .sum();
+    let norm = |keyword_map: &HashMap<&str, i64>| {
+        keyword_map.values().map(|view_count| (*view_count as f64).powi(2)).sum::<f64>().sqrt()
+    };
+    
+    ratio(dot, norm(a) * norm(b))
+}
+
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator <= 0.0 {
+        return 0.0;
+    }
+    
+    round4(numerator / denominator)
+}
+
+// 키워드 한 줄에 채널별 조회수를 열로 나열
+pub fn comparison_to_csv(comparison: &ChannelComparisonResponse) -> Result<String, YoutubeChannelError> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let to_csv_error = |e: csv::Error| YoutubeChannelError::CsvExport(e.to_string());
+    
+    let mut header = vec!["keyword_text".to_string(), "keyword_type".to_string()];
+    header.extend(comparison.channels.iter().map(|channel| channel.channel_handle.clone()));
+    writer.write_record(&header).map_err(to_csv_error)?;
+    
+    for shared_keyword in &comparison.shared_keywords {
+        let mut record = vec![shared_keyword.keyword_text.clone(), "shared".to_string()];
+        record.extend(shared_keyword.view_counts.iter().map(|view_count| view_count.to_string()));
+        writer.write_record(&record).map_err(to_csv_error)?;
+    }
+    
+    for (index, channel) in comparison.channels.iter().enumerate() {
+        for keyword in &channel.unique_keywords {
+            let mut record = vec![keyword.keyword_text.clone(), "unique".to_string()];
+            record.extend((0..comparison.channels.len()).map(|column| {
+                if column == index { keyword.view_count.to_string() } else { "0".to_string() }
+            }));
+            writer.write_record(&record).map_err(to_csv_error)?;
+        }
+    }
+    
+    let bytes = writer
+        .into_inner()
+        .map_err(|e| YoutubeChannelError::CsvExport(e.to_string()))?;
+    let body = String::from_utf8(bytes).map_err(|e| YoutubeChannelError::CsvExport(e.to_string()))?;
+    
+    // 엑셀에서 한글이 깨지지 않도록 BOM 추가
+    Ok(format!("\u{feff}{}", body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::{test_channel, test_channel_keyword};
+    
+    #[test]
+    fn compare_channel_keywords_splits_shared_and_unique_keywords() {
+        let channels = vec![
+            (test_channel("@first"), vec![test_channel_keyword("먹방", 300), test_channel_keyword("브이로그", 100)]),
+            (test_channel("@second"), vec![test_channel_keyword("먹방", 100), test_channel_keyword("게임", 500)]),
+        ];
+        
+        let comparison = compare_channel_keywords(&channels);
+        
+        assert_eq!(comparison.shared_keywords.len(), 1);
+        assert_eq!(comparison.shared_keywords[0].keyword_text, "먹방");
+        assert_eq!(comparison.shared_keywords[0].view_counts, vec![300, 100]);
+        assert_eq!(comparison.channels[0].unique_keywords[0].keyword_text, "브이로그");
+        assert_eq!(comparison.channels[1].unique_keywords[0].keyword_text, "게임");
+        assert_eq!(comparison.channels[0].volume_share, 0.4);
+        assert_eq!(comparison.channels[1].volume_share, 0.6);
+        assert_eq!(comparison.similarities.len(), 1);
+        assert!(comparison.similarities[0].score > 0.0 && comparison.similarities[0].score < 1.0);
+    }
+    
+    #[test]
+    fn compare_channel_keywords_scores_identical_channels_as_one() {
+        let channels = vec![
+            (test_channel("@first"), vec![test_channel_keyword("먹방", 300), test_channel_keyword("게임", 100)]),
+            (test_channel("@second"), vec![test_channel_keyword("먹방", 30), test_channel_keyword("게임", 10)]),
+        ];
+        
+        let comparison = compare_channel_keywords(&channels);
+        
+        assert_eq!(comparison.similarities[0].score, 1.0);
+        assert!(comparison.channels.iter().all(|channel| channel.unique_keywords.is_empty()));
+    }
+    
+    #[test]
+    fn compare_channel_keywords_shares_keywords_outside_top_keywords() {
+        // 두 번째 채널의 조회수가 가장 낮은 키워드도 공통 키워드로 잡히고 고유 키워드에서는 빠짐
+        let mut second_keywords: Vec<YoutubeChannelKeyword> = (0..1000)
+            .map(|index| test_channel_keyword(&format!("게임{}", index), 10_000 - index))
+            .collect();
+        second_keywords.push(test_channel_keyword("브이로그", 1));
+        let channels = vec![
+            (test_channel("@first"), vec![test_channel_keyword("먹방", 300), test_channel_keyword("브이로그", 100)]),
+            (test_channel("@second"), second_keywords),
+        ];
+        
+        let comparison = compare_channel_keywords(&channels);
+        
+        let shared_texts: Vec<&str> = comparison.shared_keywords.iter().map(|keyword| keyword.keyword_text.as_str()).collect();
+        assert_eq!(shared_texts, vec!["브이로그"]);
+        assert_eq!(comparison.shared_keywords[0].view_counts, vec![100, 1]);
+        let unique_texts: Vec<&str> = comparison.channels[0].unique_keywords.iter().map(|keyword| keyword.keyword_text.as_str()).collect();
+        assert_eq!(unique_texts, vec!["먹방"]);
+        assert_eq!(comparison.channels[1].keyword_count, 1001);
+    }
+}
@@ -5,6 +5,21 @@ pub enum YoutubeChannelError {
     #[error("채널 '{0}'을 찾을 수 없습니다.")]
     ChannelNotFound(String),
     
-    #[error("채널 '{0}'의 데이터가 이미 존재합니다.")]
-    ChannelDuplicated(String),
+    #[error("채널 '{0}'을 이미 분석하고 있습니다.")]
+    AnalysisInProgress(String),
+    
+    #[error("분석 옵션이 올바르지 않습니다: {0}")]
+    InvalidAnalysisOptions(String),
+    
+    #[error("비교할 채널은 서로 다른 2~5개여야 합니다.")]
+    InvalidComparisonChannels,
+    
+    #[error("이번 달 채널 분석 한도({0}회)를 모두 사용했습니다. 다음 달 1일에 초기화됩니다.")]
+    AnalysisQuotaExceeded(i64),
+    
+    #[error("채널 '{0}'의 저장된 영상이 없어 벤치마크를 계산할 수 없습니다. 전체 재분석 후 다시 시도해 주세요.")]
+    BenchmarkDataUnavailable(String),
+    
+    #[error("CSV 변환 실패: {0}")]
+    CsvExport(String),
 }
\ No newline at end of file
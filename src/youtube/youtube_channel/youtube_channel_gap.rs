@@ -0,0 +1,185 @@
+use std::collections::{HashMap, HashSet};
+use chrono::NaiveDate;
+use crate::common::keyword::normalize_keyword;
+use crate::common::statistics::round4;
+use crate::youtube::youtube_channel::youtube_channel_model::{ChannelKeywordGapResponse, KeywordCooccurrence, RideNowKeywordResponse, UncoveredTrendResponse, YoutubeChannelKeyword};
+use crate::youtube::youtube_video::youtube_video_model::YoutubeKeywordRanking;
+
+const GAP_RESULT_LIMIT: usize = 30;
+const RELATED_KEYWORD_LIMIT: usize = 3;
+
+// 채널이 다룬 전체 키워드를 정규화한 키워드별 조회수로 묶음 (정규화하면 같아지는 키워드는 합산)
+pub fn covered_keywords(keywords: &[YoutubeChannelKeyword]) -> HashMap<String, i64> {
+    let mut covered = HashMap::new();
+    for keyword in keywords {
+        *covered.entry(normalize_keyword(&keyword.keyword_text)).or_insert(0) += keyword.view_count;
+    }
+    
+    covered
+}
+
+// covered_keywords 는 채널이 다룬 전체 키워드(정규화)별 조회수, channel_keywords 는 조회수 상위 키워드
+// 순위 강도 = (차트 크기 - 순위 + 1) / 차트 크기, 1위가 1.0
+// 미개척 키워드: 순위 강도 x 함께 등장한 채널 키워드들의 조회수 점유율 합
+// 지금 탈 키워드: 순위 강도 x (0.5 + 0.5 x 채널 최상위 키워드 대비 조회수 비율), 상위 키워드 밖이라도 다룬 키워드는 모두 포함
+pub fn build_keyword_gap(
+    channel_handle: String,
+    ranking_date: Option<NaiveDate>,
+    rankings: &[YoutubeKeywordRanking],
+    covered_keywords: &HashMap<String, i64>,
+    channel_keywords: &[YoutubeChannelKeyword],
+    cooccurrences: &[KeywordCooccurrence],
+) -> ChannelKeywordGapResponse {
+    let chart_size = rankings.len().max(1) as f64;
+    let trend_strength = |ranking: i32| (chart_size - ranking as f64 + 1.0).max(0.0) / chart_size;
+    
+    let total_view_count: i64 = channel_keywords.iter().map(|keyword| keyword.view_count).sum();
+    let max_view_count = covered_keywords.values().copied().max().unwrap_or(0);
+    let channel_keyword_map: HashMap<String, &YoutubeChannelKeyword> = channel_keywords
+        .iter()
+        .map(|keyword| (normalize_keyword(&keyword.keyword_text), keyword))
+        .collect();
+    let share = |view_count: i64| if total_view_count > 0 { view_count as f64 / total_view_count as f64 } else { 0.0 };
+    
+    let mut cooccurrence_map: HashMap<String, Vec<&KeywordCooccurrence>> = HashMap::new();
+    for cooccurrence in cooccurrences {
+        cooccurrence_map
+            .entry(normalize_keyword(&cooccurrence.trending_keyword))
+            .or_default()
+            .push(cooccurrence);
+    }
+    
+    let mut uncovered = Vec::new();
+    let mut ride_now = Vec::new();
+    for ranking in rankings {
+        let normalized = normalize_keyword(&ranking.keyword_text);
+        
+        if let Some(&channel_view_count) = covered_keywords.get(&normalized) {
+            let coverage = if max_view_count > 0 { channel_view_count as f64 / max_view_count as f64 } else { 0.0 };
+            ride_now.push(RideNowKeywordResponse {
+                keyword_text: ranking.keyword_text.clone(),
+                ranking: ranking.ranking,
+                score: ranking.score,
+                channel_view_count,
+                opportunity_score: round4(trend_strength(ranking.ranking) * (0.5 + 0.5 * coverage)),
+            });
+            continue;
+        }
+        
+        let Some(related) = cooccurrence_map.get(&normalized) else { continue };
+        let mut related_keywords: Vec<(&YoutubeChannelKeyword, i64)> = Vec::new();
+        let mut seen = HashSet::new();
+        for cooccurrence in related {
+            let key = normalize_keyword(&cooccurrence.channel_keyword);
+            if let Some(channel_keyword) = channel_keyword_map.get(&key) {
+                if seen.insert(key) {
+                    related_keywords.push((channel_keyword, cooccurrence.video_count));
+                }
+            }
+        }
+        if related_keywords.is_empty() {
+            continue;
+        }
+        
+        let relevance: f64 = related_keywords.iter().map(|(keyword, _)| share(keyword.view_count)).sum();
+        related_keywords.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| b.0.view_count.cmp(&a.0.view_count)));
+        
+        uncovered.push(UncoveredTrendResponse {
+            keyword_text: ranking.keyword_text.clone(),
+            ranking: ranking.ranking,
+            score: ranking.score,
+            cooccurrence_video_count: related_keywords.iter().map(|(_, video_count)| video_count).sum(),
+            related_keywords: related_keywords
+                .iter()
+                .take(RELATED_KEYWORD_LIMIT)
+                .map(|(keyword, _)| keyword.keyword_text.clone())
+                .collect(),
+            opportunity_score: round4(trend_strength(ranking.ranking) * relevance),
+        });
+    }
+    
+    uncovered.sort_by(|a, b| b.opportunity_score.total_cmp(&a.opportunity_score).then_with(|| a.ranking.cmp(&b.ranking)));
+    uncovered.truncate(GAP_RESULT_LIMIT);
+    ride_now.sort_by(|a, b| b.opportunity_score.total_cmp(&a.opportunity_score).then_with(|| a.ranking.cmp(&b.ranking)));
+    ride_now.truncate(GAP_RESULT_LIMIT);
+    
+    ChannelKeywordGapResponse {
+        channel_handle,
+        ranking_date,
+        uncovered,
+        ride_now,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::{test_channel_keyword, test_keyword_ranking};
+    
+    fn cooccurrence(trending_keyword: &str, channel_keyword: &str, video_count: i64) -> KeywordCooccurrence {
+        KeywordCooccurrence {
+            trending_keyword: trending_keyword.to_string(),
+            channel_keyword: channel_keyword.to_string(),
+            video_count,
+        }
+    }
+    
+    #[test]
+    fn build_keyword_gap_splits_uncovered_and_ride_now() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let rankings = vec![
+            test_keyword_ranking(date, 1, "두바이 초콜릿", 0),
+            test_keyword_ranking(date, 2, "먹방", 0),
+            test_keyword_ranking(date, 3, "편의점", 0),
+            test_keyword_ranking(date, 4, "축구", 0),
+        ];
+        let channel_keywords = vec![
+            test_channel_keyword("먹방", 600),
+            test_channel_keyword("디저트", 300),
+            test_channel_keyword("브이로그", 100),
+        ];
+        let cooccurrences = vec![
+            cooccurrence("두바이 초콜릿", "디저트", 5),
+            cooccurrence("두바이 초콜릿", "먹방", 2),
+            cooccurrence("편의점", "브이로그", 1),
+        ];
+        
+        let covered = covered_keywords(&channel_keywords);
+        
+        let gap = build_keyword_gap("@test".to_string(), None, &rankings, &covered, &channel_keywords, &cooccurrences);
+        
+        assert_eq!(gap.ride_now.len(), 1);
+        assert_eq!(gap.ride_now[0].keyword_text, "먹방");
+        assert_eq!(gap.ride_now[0].opportunity_score, 0.75);
+        assert_eq!(gap.uncovered.len(), 2);
+        assert_eq!(gap.uncovered[0].keyword_text, "두바이 초콜릿");
+        assert_eq!(gap.uncovered[0].related_keywords, vec!["디저트", "먹방"]);
+        assert_eq!(gap.uncovered[0].opportunity_score, 0.9);
+        assert_eq!(gap.uncovered[1].opportunity_score, 0.05);
+    }
+    
+    #[test]
+    fn build_keyword_gap_rides_keywords_covered_outside_top_keywords() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let rankings = vec![
+            test_keyword_ranking(date, 1, "두바이 초콜릿", 0),
+            test_keyword_ranking(date, 2, "편의점", 0),
+        ];
+        let channel_keywords = vec![test_channel_keyword("디저트", 300)];
+        // 조회수 상위 키워드 밖에서 다룬 키워드
+        let covered = covered_keywords(&[test_channel_keyword("디저트", 300), test_channel_keyword("두바이초콜릿", 30)]);
+        let cooccurrences = vec![
+            cooccurrence("두바이 초콜릿", "디저트", 5),
+            cooccurrence("편의점", "디저트", 1),
+        ];
+        
+        let gap = build_keyword_gap("@test".to_string(), None, &rankings, &covered, &channel_keywords, &cooccurrences);
+        
+        assert_eq!(gap.ride_now.len(), 1);
+        assert_eq!(gap.ride_now[0].keyword_text, "두바이 초콜릿");
+        assert_eq!(gap.ride_now[0].channel_view_count, 30);
+        assert_eq!(gap.ride_now[0].opportunity_score, 0.55);
+        assert_eq!(gap.uncovered.len(), 1);
+        assert_eq!(gap.uncovered[0].keyword_text, "편의점");
+    }
+}
\ No newline at end of file
@@ -1,7 +1,10 @@
-use chrono::{DateTime, Utc};
+use std::str::FromStr;
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
-use utoipa::{ToResponse, ToSchema};
+use utoipa::{IntoParams, ToResponse, ToSchema};
+use crate::common::pagination::{default_page, default_size};
 use crate::youtube::youtube_data_api::youtube_data_api_model::ChannelItem;
+use crate::youtube::youtube_video::youtube_video_model::YoutubeVideo;
 
 #[derive(Debug, Serialize, Clone, sqlx::FromRow)]
 pub struct YoutubeChannel {
@@ -15,6 +18,13 @@ pub struct YoutubeChannel {
     pub view_count: i64,
     pub video_count: i64,
     pub is_finished: bool,
+    pub analyzed_at: Option<DateTime<Utc>>,
+    // 전체 분석을 거쳐 월별 키워드 이력이 분석 범위 전체를 담고 있는지 여부
+    pub keyword_months_complete: bool,
+    pub analysis_max_videos: Option<i32>,
+    pub analysis_published_after: Option<DateTime<Utc>>,
+    pub analysis_published_before: Option<DateTime<Utc>>,
+    pub analysis_video_format: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -27,6 +37,70 @@ pub struct YoutubeChannelKeyword {
     pub view_count: i64,
 }
 
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct YoutubeChannelKeywordSnapshot {
+    pub id: i64,
+    pub youtube_channel_id: i64,
+    pub keyword_text: String,
+    pub view_count: i64,
+    pub snapshot_at: DateTime<Utc>,
+}
+
+// 업로드 월(KST 기준 1일)별 키워드 조회수 합계
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct YoutubeChannelKeywordMonth {
+    pub id: i64,
+    pub youtube_channel_id: i64,
+    pub month: NaiveDate,
+    pub keyword_text: String,
+    pub view_count: i64,
+}
+
+// 채널 분석에 사용한 영상 (트렌드 수집용 youtube_videos 와 분리)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct YoutubeChannelVideo {
+    pub id: i64,
+    pub youtube_channel_id: i64,
+    pub video_id: String,
+    pub title: String,
+    pub published_at: DateTime<Utc>,
+    pub duration: i32,
+    pub view_count: i64,
+    pub like_count: i64,
+    pub comment_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct YoutubeChannelVideoKeyword {
+    pub id: i64,
+    pub youtube_channel_id: i64,
+    pub video_id: String,
+    pub keyword_text: String,
+}
+
+// 한 번의 분석에서 나온 결과, 저장소에서 하나의 트랜잭션으로 반영
+#[derive(Debug, Clone)]
+pub struct ChannelAnalysisResult {
+    pub youtube_channel_id: i64,
+    pub keywords: Vec<YoutubeChannelKeyword>,
+    pub keyword_months: Vec<YoutubeChannelKeywordMonth>,
+    pub videos: Vec<YoutubeChannelVideo>,
+    pub video_keywords: Vec<YoutubeChannelVideoKeyword>,
+    pub analyzed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct YoutubeChannelStatsHistory {
+    pub id: i64,
+    pub channel_id: String,
+    pub subscriber_count: i64,
+    pub view_count: i64,
+    pub video_count: i64,
+    pub recorded_at: DateTime<Utc>,
+}
+
 #[derive(Serialize, Debug, Clone, ToResponse, ToSchema)]
 pub struct ChannelResponse {
     pub channel_id: String,
@@ -38,23 +112,414 @@ pub struct ChannelResponse {
     pub view_count: i64,
     pub video_count: i64,
     pub is_finished: bool,
+    pub analyzed_at: Option<DateTime<Utc>>,
+    pub analysis_options: ChannelAnalysisOptions,
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelDetailResponse {
+    #[serde(flatten)]
+    pub channel: ChannelResponse,
+    pub keyword_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+// 이번 달 분석 한도에서 차감한 요청, 재분석에서 새 영상이 없으면 request_id 로 되돌림
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnalysisQuotaReservation {
+    pub request_id: i64,
+    pub used_count: i64,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct UserChannelAnalysis {
+    #[sqlx(flatten)]
+    pub channel: YoutubeChannel,
+    pub last_requested_at: DateTime<Utc>,
+    pub request_count: i64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct MyChannelAnalysisResponse {
+    #[serde(flatten)]
+    pub channel: ChannelResponse,
+    pub last_requested_at: DateTime<Utc>,
+    pub request_count: i64,
+}
+
+impl From<&UserChannelAnalysis> for MyChannelAnalysisResponse {
+    fn from(item: &UserChannelAnalysis) -> Self {
+        Self {
+            channel: ChannelResponse::from(&item.channel),
+            last_requested_at: item.last_requested_at,
+            request_count: item.request_count,
+        }
+    }
+}
+
+// 2024년 10월부터 쇼츠 최대 길이가 3분으로 늘어남
+pub const SHORTS_MAX_DURATION_SECONDS: i32 = 180;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoFormat {
+    #[default]
+    All,
+    Shorts,
+    LongForm,
+}
+
+impl VideoFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VideoFormat::All => "all",
+            VideoFormat::Shorts => "shorts",
+            VideoFormat::LongForm => "long_form",
+        }
+    }
+    
+    pub fn matches(&self, duration_seconds: i32) -> bool {
+        match self {
+            VideoFormat::All => true,
+            VideoFormat::Shorts => duration_seconds <= SHORTS_MAX_DURATION_SECONDS,
+            VideoFormat::LongForm => duration_seconds > SHORTS_MAX_DURATION_SECONDS,
+        }
+    }
+}
+
+impl FromStr for VideoFormat {
+    type Err = String;
+    
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "all" => Ok(VideoFormat::All),
+            "shorts" => Ok(VideoFormat::Shorts),
+            "long_form" => Ok(VideoFormat::LongForm),
+            _ => Err(format!("알 수 없는 영상 형식: {}", value)),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default, PartialEq, ToSchema)]
+pub struct ChannelAnalysisOptions {
+    pub max_videos: Option<u32>,
+    pub published_after: Option<DateTime<Utc>>,
+    pub published_before: Option<DateTime<Utc>>,
+    pub video_format: VideoFormat,
+}
+
 #[derive(Serialize, Debug, Clone, ToResponse, ToSchema)]
 pub struct ChannelKeywordResponse {
     pub keyword_text: String,
     pub view_count: i64,
 }
 
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelStatsPoint {
+    pub subscriber_count: i64,
+    pub view_count: i64,
+    pub video_count: i64,
+    pub recorded_at: DateTime<Utc>,
+}
+
+// 직전 분석 시점의 상위 키워드와 현재 상위 키워드 비교
+// 추가 분석은 조회수를 더하기만 하므로 exited 는 키워드가 사라진 것이 아니라 다른 키워드에 밀려 상위권에서 빠진 것
+#[derive(Serialize, Debug, Clone, ToResponse, ToSchema)]
+pub struct ChannelKeywordDiffResponse {
+    pub channel_handle: String,
+    pub snapshot_at: Option<DateTime<Utc>>,
+    pub entered_top_keywords: Vec<ChannelKeywordResponse>,
+    pub exited_top_keywords: Vec<ChannelKeywordResponse>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelVideoResponse {
+    pub video_id: String,
+    pub title: String,
+    pub published_at: DateTime<Utc>,
+    pub duration: i32,
+    pub view_count: i64,
+    pub like_count: i64,
+    pub comment_count: i64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelKeywordVideosResponse {
+    pub channel_handle: String,
+    pub keyword_text: String,
+    pub videos: Vec<ChannelVideoResponse>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct FormatBenchmark {
+    pub video_count: usize,
+    pub ratio: f64,
+    pub median_views: f64,
+    pub mean_views: f64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct PublishSlotBenchmark {
+    // 요일(Mon~Sun) 또는 KST 시각(0~23)
+    pub slot: String,
+    pub video_count: usize,
+    pub median_views: f64,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct KeywordCooccurrence {
+    pub trending_keyword: String,
+    pub channel_keyword: String,
+    pub video_count: i64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct UncoveredTrendResponse {
+    pub keyword_text: String,
+    pub ranking: i32,
+    pub score: i64,
+    // 이 트렌드 키워드와 같은 영상에 함께 붙은 채널 키워드
+    pub related_keywords: Vec<String>,
+    pub cooccurrence_video_count: i64,
+    pub opportunity_score: f64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct RideNowKeywordResponse {
+    pub keyword_text: String,
+    pub ranking: i32,
+    pub score: i64,
+    pub channel_view_count: i64,
+    pub opportunity_score: f64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelKeywordGapResponse {
+    pub channel_handle: String,
+    pub ranking_date: Option<NaiveDate>,
+    // 채널이 다룬 적 없지만 채널 주제와 함께 등장하는 트렌드 키워드
+    pub uncovered: Vec<UncoveredTrendResponse>,
+    // 채널이 이미 다루고 있고 지금 순위에 오른 키워드
+    pub ride_now: Vec<RideNowKeywordResponse>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelBenchmarkResponse {
+    pub channel_handle: String,
+    pub video_count: usize,
+    pub median_views: f64,
+    pub mean_views: f64,
+    pub views_per_subscriber: f64,
+    pub uploads_per_week: f64,
+    pub longest_gap_days: f64,
+    pub shorts: FormatBenchmark,
+    pub long_form: FormatBenchmark,
+    pub best_weekday: Option<PublishSlotBenchmark>,
+    pub best_hour: Option<PublishSlotBenchmark>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelKeywordMonthResponse {
+    // YYYY-MM
+    pub month: String,
+    pub total_view_count: i64,
+    pub keywords: Vec<ChannelKeywordResponse>,
+    // 직전 업로드 월 상위 키워드와 비교해 새로 들어오거나 빠진 키워드
+    pub entered_keywords: Vec<String>,
+    pub exited_keywords: Vec<String>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelKeywordTimelineResponse {
+    pub channel_handle: String,
+    pub months: Vec<ChannelKeywordMonthResponse>,
+    // 월별 집계를 저장하기 전에 분석한 채널은 키워드만 있고 월별 기록이 없으므로 전체 재분석이 필요
+    pub requires_full_reanalysis: bool,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelComparisonEntry {
+    pub channel_handle: String,
+    pub channel_title: String,
+    pub keyword_count: usize,
+    pub keyword_volume: i64,
+    pub volume_share: f64,
+    pub unique_keywords: Vec<ChannelKeywordResponse>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct SharedKeywordResponse {
+    pub keyword_text: String,
+    pub total_view_count: i64,
+    // channels 순서와 같은 순서의 채널별 조회수
+    pub view_counts: Vec<i64>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+pub struct ChannelSimilarityResponse {
+    pub channel_handle: String,
+    pub other_channel_handle: String,
+    pub score: f64,
+}
+
+#[derive(Serialize, Debug, Clone, ToResponse, ToSchema)]
+pub struct ChannelComparisonResponse {
+    pub channels: Vec<ChannelComparisonEntry>,
+    pub shared_keywords: Vec<SharedKeywordResponse>,
+    pub similarities: Vec<ChannelSimilarityResponse>,
+}
+
+#[derive(Serialize, Debug, Clone, ToResponse, ToSchema)]
+pub struct ChannelGrowthResponse {
+    pub channel_handle: String,
+    pub subscriber_growth: i64,
+    pub view_growth: i64,
+    pub video_growth: i64,
+    pub history: Vec<ChannelStatsPoint>,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct ChannelStatsRefreshSummary {
+    pub channel_count: usize,
+    pub refreshed_channel_count: usize,
+}
+
 #[derive(Deserialize)]
 pub struct ChannelRequestQuery {
     pub channel_handle: String,
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ChannelCompareQuery {
+    // 쉼표로 구분한 채널 핸들 2~5개
+    pub channel_handles: String,
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelSortField {
+    #[default]
+    Title,
+    SubscriberCount,
+    ViewCount,
+    VideoCount,
+    AnalyzedAt,
+}
+
+impl ChannelSortField {
+    pub fn column(&self) -> &'static str {
+        match self {
+            ChannelSortField::Title => "channel_title",
+            ChannelSortField::SubscriberCount => "subscriber_count",
+            ChannelSortField::ViewCount => "view_count",
+            ChannelSortField::VideoCount => "video_count",
+            ChannelSortField::AnalyzedAt => "analyzed_at",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, Default, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ChannelListFilter {
+    pub search: Option<String>,
+    pub is_finished: Option<bool>,
+    pub min_subscribers: Option<i64>,
+    pub max_subscribers: Option<i64>,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ChannelListQuery {
+    #[serde(default = "default_page")]
+    pub page: u32,
+    #[serde(default = "default_size")]
+    pub size: u32,
+    // 채널 제목 또는 핸들 부분 일치
+    pub search: Option<String>,
+    pub is_finished: Option<bool>,
+    pub min_subscribers: Option<i64>,
+    pub max_subscribers: Option<i64>,
+    #[serde(default)]
+    pub sort: ChannelSortField,
+    #[serde(default)]
+    pub direction: SortDirection,
+}
+
+impl ChannelListQuery {
+    pub fn filter(&self) -> ChannelListFilter {
+        ChannelListFilter {
+            search: self.search
+                .as_deref()
+                .map(str::trim)
+                .filter(|search| !search.is_empty())
+                .map(str::to_string),
+            is_finished: self.is_finished,
+            min_subscribers: self.min_subscribers,
+            max_subscribers: self.max_subscribers,
+        }
+    }
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ChannelKeywordVideosQuery {
+    pub channel_handle: String,
+    pub keyword_text: String,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct ChannelGrowthQuery {
+    pub channel_handle: String,
+    #[serde(default = "default_growth_days")]
+    pub days: u32,
+}
+
+fn default_growth_days() -> u32 { 30 }
+
+#[derive(Deserialize, IntoParams)]
 pub struct AnalyzeChannelRequestQuery {
     pub channel_handle: String,
+    pub max_videos: Option<u32>,
+    pub published_after: Option<DateTime<Utc>>,
+    pub published_before: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub video_format: VideoFormat,
+}
+
+impl AnalyzeChannelRequestQuery {
+    pub fn analysis_options(&self) -> ChannelAnalysisOptions {
+        ChannelAnalysisOptions {
+            max_videos: self.max_videos,
+            published_after: self.published_after,
+            published_before: self.published_before,
+            video_format: self.video_format,
+        }
+    }
 }
 
 impl From<&ChannelItem> for YoutubeChannel {
@@ -74,6 +539,12 @@ impl From<&ChannelItem> for YoutubeChannel {
             view_count: view_count,
             video_count: video_count,
             is_finished: false,
+            analyzed_at: None,
+            keyword_months_complete: false,
+            analysis_max_videos: None,
+            analysis_published_after: None,
+            analysis_published_before: None,
+            analysis_video_format: VideoFormat::All.as_str().to_string(),
             created_at: Default::default(),
             updated_at: Default::default(),
         }
@@ -92,11 +563,44 @@ impl From<&YoutubeChannel> for ChannelResponse {
             view_count: item.view_count,
             video_count: item.video_count,
             is_finished: item.is_finished,
+            analyzed_at: item.analyzed_at,
+            analysis_options: ChannelAnalysisOptions::from(item),
             updated_at: item.updated_at,
         }
     }
 }
 
+impl From<&YoutubeChannel> for ChannelAnalysisOptions {
+    fn from(item: &YoutubeChannel) -> Self {
+        Self {
+            max_videos: item.analysis_max_videos.map(|max_videos| max_videos as u32),
+            published_after: item.analysis_published_after,
+            published_before: item.analysis_published_before,
+            video_format: item.analysis_video_format.parse().unwrap_or_default(),
+        }
+    }
+}
+
+impl From<&YoutubeChannelStatsHistory> for ChannelStatsPoint {
+    fn from(item: &YoutubeChannelStatsHistory) -> Self {
+        Self {
+            subscriber_count: item.subscriber_count,
+            view_count: item.view_count,
+            video_count: item.video_count,
+            recorded_at: item.recorded_at,
+        }
+    }
+}
+
+impl From<&YoutubeChannelKeywordSnapshot> for ChannelKeywordResponse {
+    fn from(item: &YoutubeChannelKeywordSnapshot) -> Self {
+        Self {
+            keyword_text: item.keyword_text.clone(),
+            view_count: item.view_count,
+        }
+    }
+}
+
 impl From<&YoutubeChannelKeyword> for ChannelKeywordResponse {
     fn from(item: &YoutubeChannelKeyword) -> Self {
         Self {
@@ -104,4 +608,36 @@ impl From<&YoutubeChannelKeyword> for ChannelKeywordResponse {
             view_count: item.view_count,
         }
     }
+}
+
+impl YoutubeChannelVideo {
+    pub fn from_video(youtube_channel_id: i64, video: &YoutubeVideo) -> Self {
+        Self {
+            id: 0,
+            youtube_channel_id,
+            video_id: video.video_id.clone(),
+            title: video.title.clone(),
+            published_at: video.published_at,
+            duration: video.duration,
+            view_count: video.view_count,
+            like_count: video.like_count,
+            comment_count: video.comment_count,
+            created_at: Default::default(),
+            updated_at: Default::default(),
+        }
+    }
+}
+
+impl From<&YoutubeChannelVideo> for ChannelVideoResponse {
+    fn from(item: &YoutubeChannelVideo) -> Self {
+        Self {
+            video_id: item.video_id.clone(),
+            title: item.title.clone(),
+            published_at: item.published_at,
+            duration: item.duration,
+            view_count: item.view_count,
+            like_count: item.like_count,
+            comment_count: item.comment_count,
+        }
+    }
 }
\ No newline at end of file
@@ -1,26 +1,73 @@
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use mockall::automock;
-use sqlx::{Error, MySqlPool};
-use crate::youtube::youtube_channel::youtube_channel_model::{YoutubeChannel, YoutubeChannelKeyword};
+use sqlx::mysql::MySqlArguments;
+use sqlx::query::QueryAs;
+use sqlx::{Error, MySql, MySqlConnection, MySqlPool};
+use crate::youtube::youtube_channel::youtube_channel_model::{AnalysisQuotaReservation, ChannelAnalysisResult, ChannelListFilter, KeywordCooccurrence, ChannelSortField, SortDirection, UserChannelAnalysis, YoutubeChannel, YoutubeChannelKeyword, YoutubeChannelKeywordMonth, YoutubeChannelVideo, YoutubeChannelVideoKeyword, YoutubeChannelKeywordSnapshot, YoutubeChannelStatsHistory};
 
 #[automock]
 #[async_trait]
 pub trait YoutubeChannelRepository: Send + Sync {
     async fn save_channel(&self, channel: YoutubeChannel) -> Result<i64, Error>;
     
-    async fn save_channel_keywords(&self, keywords: Vec<YoutubeChannelKeyword>) -> Result<(), Error>;
-    
     async fn channel_exists_by_handle(&self, handle: &str) -> Result<bool, Error>;
     
-    async fn find_all_channels(&self, limit: u32, offset: u32) -> Result<Vec<YoutubeChannel>, Error>;
+    async fn find_channel_by_handle(&self, handle: &str) -> Result<Option<YoutubeChannel>, Error>;
+    
+    async fn find_all_channels(&self, filter: &ChannelListFilter, sort: ChannelSortField, direction: SortDirection, limit: u32, offset: u32) -> Result<Vec<YoutubeChannel>, Error>;
     
-    async fn count_all_channels(&self) -> Result<i64, Error>;
+    async fn count_all_channels(&self, filter: &ChannelListFilter) -> Result<i64, Error>;
     
     async fn find_keywords_by_channel_handle_order_by_view_count(&self, channel_handle: &str, limit: u32) -> Result<Vec<YoutubeChannelKeyword>, Error>;
     
-    async fn update_channel_finished_by_id(&self, io: i64) -> Result<(), Error>;
+    async fn count_keywords_by_channel_id(&self, youtube_channel_id: i64) -> Result<i64, Error>;
+    
+    async fn find_keywords_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelKeyword>, Error>;
+    
+    async fn replace_channel_analysis(&self, analysis: &ChannelAnalysisResult) -> Result<(), Error>;
     
     async fn delete_channel_not_finished(&self) -> Result<(), Error>;
+    
+    async fn delete_channel_by_handle(&self, handle: &str) -> Result<bool, Error>;
+    
+    async fn find_finished_channels(&self) -> Result<Vec<YoutubeChannel>, Error>;
+    
+    async fn save_channel_stats(&self, channels: &[YoutubeChannel], recorded_at: DateTime<Utc>) -> Result<(), Error>;
+    
+    async fn find_channel_stats_history(&self, channel_id: &str, since: DateTime<Utc>) -> Result<Vec<YoutubeChannelStatsHistory>, Error>;
+    
+    async fn try_start_reanalysis(&self, id: i64) -> Result<bool, Error>;
+    
+    async fn finish_reanalysis(&self, id: i64, analyzed_at: Option<DateTime<Utc>>) -> Result<(), Error>;
+    
+    async fn merge_channel_analysis(&self, analysis: &ChannelAnalysisResult) -> Result<(), Error>;
+    
+    async fn find_keyword_snapshot_order_by_view_count(&self, youtube_channel_id: i64, limit: u32) -> Result<Vec<YoutubeChannelKeywordSnapshot>, Error>;
+    
+    async fn find_keyword_months_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelKeywordMonth>, Error>;
+    
+    async fn find_videos_by_channel_keyword(&self, youtube_channel_id: i64, keyword_text: &str, limit: u32) -> Result<Vec<YoutubeChannelVideo>, Error>;
+    
+    async fn find_videos_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelVideo>, Error>;
+    
+    async fn find_video_keywords_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelVideoKeyword>, Error>;
+    
+    async fn reserve_analysis_request(&self, user_public_id: &str, channel_handle: &str, since: DateTime<Utc>, quota: i64) -> Result<Option<AnalysisQuotaReservation>, Error>;
+    
+    async fn refund_analysis_request(&self, request_id: i64) -> Result<(), Error>;
+    
+    async fn find_channels_requested_by_user(&self, user_public_id: &str, limit: u32, offset: u32) -> Result<Vec<UserChannelAnalysis>, Error>;
+    
+    async fn count_channels_requested_by_user(&self, user_public_id: &str) -> Result<i64, Error>;
+    
+    async fn find_keyword_cooccurrences(
+        &self,
+        trending_keywords: &[String],
+        channel_keywords: &[String],
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<KeywordCooccurrence>, Error>;
 }
 
 #[derive(Clone)]
@@ -39,8 +86,9 @@ impl YoutubeChannelRepository for YoutubeChannelSqlxRepository {
             r#"
                 INSERT INTO youtube_channels (
                     channel_id, channel_handle, channel_title, thumbnail_url, description,
-                                              subscriber_count, view_count, video_count
-                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                                              subscriber_count, view_count, video_count,
+                    analysis_max_videos, analysis_published_after, analysis_published_before, analysis_video_format
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
             "#,
             channel.channel_id,
             channel.channel_handle,
@@ -49,7 +97,11 @@ impl YoutubeChannelRepository for YoutubeChannelSqlxRepository {
             channel.description,
             channel.subscriber_count,
             channel.view_count,
-            channel.video_count
+            channel.video_count,
+            channel.analysis_max_videos,
+            channel.analysis_published_after,
+            channel.analysis_published_before,
+            channel.analysis_video_format
         )
             .execute(&self.db_pool)
             .await?
@@ -58,32 +110,6 @@ impl YoutubeChannelRepository for YoutubeChannelSqlxRepository {
         Ok(youtube_channel_id)
     }
     
-    async fn save_channel_keywords(&self, keywords: Vec<YoutubeChannelKeyword>) -> Result<(), Error> {
-        if keywords.is_empty() {
-            return Ok(());
-        }
-        
-        let mut tx = self.db_pool.begin().await?;
-        
-        let mut query_builder = String::from(
-            "INSERT INTO youtube_channel_keywords (youtube_channel_id, keyword_text, view_count) VALUES "
-        );
-        query_builder.push_str(&vec!["(?, ?, ?)"; keywords.len()].join(", "));
-        
-        let mut query = sqlx::query(&query_builder);
-        for keyword in keywords {
-            query = query
-                .bind(keyword.youtube_channel_id)
-                .bind(keyword.keyword_text)
-                .bind(keyword.view_count);
-        }
-        
-        query.execute(&mut *tx).await?;
-        tx.commit().await?;
-        
-        Ok(())
-    }
-    
     async fn channel_exists_by_handle(&self, handle: &str) -> Result<bool, Error> {
         let result = sqlx::query!(
             r#"
@@ -99,37 +125,60 @@ impl YoutubeChannelRepository for YoutubeChannelSqlxRepository {
         Ok(result.is_some())
     }
     
-    async fn find_all_channels(&self, limit: u32, offset: u32) -> Result<Vec<YoutubeChannel>, Error> {
-        let channels = sqlx::query_as!(
+    async fn find_channel_by_handle(&self, handle: &str) -> Result<Option<YoutubeChannel>, Error> {
+        let channel = sqlx::query_as!(
             YoutubeChannel,
             r#"
                 SELECT id, channel_id, channel_handle, channel_title, thumbnail_url, description, subscriber_count,
                        view_count, video_count,
-                       CAST(is_finished AS UNSIGNED) AS "is_finished: bool", created_at, updated_at
+                       CAST(is_finished AS UNSIGNED) AS "is_finished: bool", analyzed_at,
+                       CAST(keyword_months_complete AS UNSIGNED) AS "keyword_months_complete: bool",
+                       analysis_max_videos, analysis_published_after, analysis_published_before, analysis_video_format,
+                       created_at, updated_at
                 FROM youtube_channels
-                ORDER BY channel_title
-                LIMIT ? OFFSET ?
+                WHERE channel_handle = ?
             "#,
-            limit,
-            offset
+            handle
         )
+            .fetch_optional(&self.db_pool)
+            .await?;
+        
+        Ok(channel)
+    }
+    
+    async fn find_all_channels(&self, filter: &ChannelListFilter, sort: ChannelSortField, direction: SortDirection, limit: u32, offset: u32) -> Result<Vec<YoutubeChannel>, Error> {
+        // 정렬 컬럼과 방향은 enum 에서만 오므로 문자열로 조립해도 안전
+        let query_str = format!(
+            "SELECT id, channel_id, channel_handle, channel_title, thumbnail_url, description, subscriber_count, \
+                    view_count, video_count, is_finished, analyzed_at, keyword_months_complete, \
+                    analysis_max_videos, analysis_published_after, analysis_published_before, analysis_video_format, \
+                    created_at, updated_at \
+             FROM youtube_channels{} \
+             ORDER BY {} {}, id {} \
+             LIMIT ? OFFSET ?",
+            channel_filter_clause(filter),
+            sort.column(),
+            direction.as_sql(),
+            direction.as_sql()
+        );
+        
+        let channels = bind_channel_filter(sqlx::query_as::<_, YoutubeChannel>(&query_str), filter)
+            .bind(limit)
+            .bind(offset)
             .fetch_all(&self.db_pool)
             .await?;
         
         Ok(channels)
     }
     
-    async fn count_all_channels(&self) -> Result<i64, Error> {
-        let row = sqlx::query!(
-            r#"
-                SELECT COUNT(*) as count
-                FROM youtube_channels
-            "#
-        )
+    async fn count_all_channels(&self, filter: &ChannelListFilter) -> Result<i64, Error> {
+        let query_str = format!("SELECT COUNT(*) FROM youtube_channels{}", channel_filter_clause(filter));
+        
+        let (count,) = bind_channel_filter(sqlx::query_as::<_, (i64,)>(&query_str), filter)
             .fetch_one(&self.db_pool)
             .await?;
         
-        Ok(row.count)
+        Ok(count)
     }
     
     async fn find_keywords_by_channel_handle_order_by_view_count(&self, channel_handle: &str, limit: u32) -> Result<Vec<YoutubeChannelKeyword>, Error> {
@@ -152,33 +201,885 @@ impl YoutubeChannelRepository for YoutubeChannelSqlxRepository {
         Ok(keywords)
     }
     
-    async fn update_channel_finished_by_id(&self, id: i64) -> Result<(), Error> {
+    async fn count_keywords_by_channel_id(&self, youtube_channel_id: i64) -> Result<i64, Error> {
+        let row = sqlx::query!(
+            r#"
+                SELECT COUNT(*) as count
+                FROM youtube_channel_keywords
+                WHERE youtube_channel_id = ?
+            "#,
+            youtube_channel_id
+        )
+            .fetch_one(&self.db_pool)
+            .await?;
+        
+        Ok(row.count)
+    }
+    
+    async fn find_keywords_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelKeyword>, Error> {
+        let keywords = sqlx::query_as!(
+            YoutubeChannelKeyword,
+            r#"
+                SELECT id, youtube_channel_id, keyword_text, view_count
+                FROM youtube_channel_keywords
+                WHERE youtube_channel_id = ?
+                ORDER BY view_count DESC
+            "#,
+            youtube_channel_id
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(keywords)
+    }
+    
+    // 기존 키워드를 스냅샷으로 남긴 뒤 키워드, 월별 키워드, 영상을 새 결과로 교체하고 분석 완료 처리
+    // 분석이 실패하면 호출되지 않으므로 기존 결과가 그대로 남는다
+    async fn replace_channel_analysis(&self, analysis: &ChannelAnalysisResult) -> Result<(), Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        insert_keyword_snapshot(&mut tx, analysis.youtube_channel_id, analysis.analyzed_at).await?;
+        delete_channel_analysis(&mut tx, analysis.youtube_channel_id).await?;
+        upsert_channel_keywords(&mut tx, &analysis.keywords).await?;
+        upsert_channel_keyword_months(&mut tx, &analysis.keyword_months).await?;
+        upsert_channel_videos(&mut tx, &analysis.videos, &analysis.video_keywords).await?;
+        
         sqlx::query!(
             r#"
                 UPDATE youtube_channels
-                SET is_finished = true
+                SET is_finished = true, analyzed_at = ?, keyword_months_complete = true
                 WHERE id = ?
             "#,
-            id
+            analysis.analyzed_at,
+            analysis.youtube_channel_id
         )
-            .execute(&self.db_pool)
+            .execute(&mut *tx)
             .await?;
         
+        tx.commit().await?;
+        
         Ok(())
     }
     
+    // 분석을 끝내지 못한 채널은 키워드, 월별 키워드, 영상까지 함께 삭제
     async fn delete_channel_not_finished(&self) -> Result<(), Error> {
-        sqlx::query!(
+        let mut tx = self.db_pool.begin().await?;
+        
+        let channel_ids = sqlx::query_scalar!(
             r#"
-                DELETE
+                SELECT id
                 FROM youtube_channels
                 WHERE is_finished = false
                 AND created_at < NOW() - INTERVAL 1 HOUR
+                FOR UPDATE
             "#
+        )
+            .fetch_all(&mut *tx)
+            .await?;
+        
+        for channel_id in channel_ids {
+            delete_channel_analysis(&mut tx, channel_id).await?;
+            
+            sqlx::query!(
+                r#"
+                    DELETE
+                    FROM youtube_channels
+                    WHERE id = ?
+                "#,
+                channel_id
+            )
+                .execute(&mut *tx)
+                .await?;
+        }
+        
+        tx.commit().await?;
+        
+        Ok(())
+    }
+    
+    async fn delete_channel_by_handle(&self, handle: &str) -> Result<bool, Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE ycks
+                FROM youtube_channel_keyword_snapshots AS ycks
+                JOIN youtube_channels AS yc ON yc.id = ycks.youtube_channel_id
+                WHERE yc.channel_handle = ?
+            "#,
+            handle
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE ycvk
+                FROM youtube_channel_video_keywords AS ycvk
+                JOIN youtube_channels AS yc ON yc.id = ycvk.youtube_channel_id
+                WHERE yc.channel_handle = ?
+            "#,
+            handle
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE ycv
+                FROM youtube_channel_videos AS ycv
+                JOIN youtube_channels AS yc ON yc.id = ycv.youtube_channel_id
+                WHERE yc.channel_handle = ?
+            "#,
+            handle
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE yckm
+                FROM youtube_channel_keyword_months AS yckm
+                JOIN youtube_channels AS yc ON yc.id = yckm.youtube_channel_id
+                WHERE yc.channel_handle = ?
+            "#,
+            handle
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE yck
+                FROM youtube_channel_keywords AS yck
+                JOIN youtube_channels AS yc ON yc.id = yck.youtube_channel_id
+                WHERE yc.channel_handle = ?
+            "#,
+            handle
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        let result = sqlx::query!(
+            r#"
+                DELETE
+                FROM youtube_channels
+                WHERE channel_handle = ?
+            "#,
+            handle
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        tx.commit().await?;
+        
+        Ok(result.rows_affected() > 0)
+    }
+    
+    async fn find_finished_channels(&self) -> Result<Vec<YoutubeChannel>, Error> {
+        let channels = sqlx::query_as!(
+            YoutubeChannel,
+            r#"
+                SELECT id, channel_id, channel_handle, channel_title, thumbnail_url, description, subscriber_count,
+                       view_count, video_count,
+                       CAST(is_finished AS UNSIGNED) AS "is_finished: bool", analyzed_at,
+                       CAST(keyword_months_complete AS UNSIGNED) AS "keyword_months_complete: bool",
+                       analysis_max_videos, analysis_published_after, analysis_published_before, analysis_video_format,
+                       created_at, updated_at
+                FROM youtube_channels
+                WHERE is_finished = true
+                ORDER BY id
+            "#
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(channels)
+    }
+    
+    // 채널의 현재 통계를 갱신하고 같은 값을 이력에 추가
+    // 이력은 YouTube 채널 ID 기준이라 재분석으로 채널 행이 다시 만들어져도 유지됨
+    async fn save_channel_stats(&self, channels: &[YoutubeChannel], recorded_at: DateTime<Utc>) -> Result<(), Error> {
+        if channels.is_empty() {
+            return Ok(());
+        }
+        
+        let mut tx = self.db_pool.begin().await?;
+        
+        for channel in channels {
+            sqlx::query!(
+                r#"
+                    UPDATE youtube_channels
+                    SET subscriber_count = ?, view_count = ?, video_count = ?
+                    WHERE id = ?
+                "#,
+                channel.subscriber_count,
+                channel.view_count,
+                channel.video_count,
+                channel.id
+            )
+                .execute(&mut *tx)
+                .await?;
+        }
+        
+        let mut query_builder = String::from(
+            "INSERT INTO youtube_channel_stats_history (channel_id, subscriber_count, view_count, video_count, recorded_at) VALUES "
+        );
+        query_builder.push_str(&vec!["(?, ?, ?, ?, ?)"; channels.len()].join(", "));
+        
+        let mut query = sqlx::query(&query_builder);
+        for channel in channels {
+            query = query
+                .bind(&channel.channel_id)
+                .bind(channel.subscriber_count)
+                .bind(channel.view_count)
+                .bind(channel.video_count)
+                .bind(recorded_at);
+        }
+        query.execute(&mut *tx).await?;
+        
+        tx.commit().await?;
+        
+        Ok(())
+    }
+    
+    async fn find_channel_stats_history(&self, channel_id: &str, since: DateTime<Utc>) -> Result<Vec<YoutubeChannelStatsHistory>, Error> {
+        let history = sqlx::query_as!(
+            YoutubeChannelStatsHistory,
+            r#"
+                SELECT id, channel_id, subscriber_count, view_count, video_count, recorded_at
+                FROM youtube_channel_stats_history
+                WHERE channel_id = ? AND recorded_at >= ?
+                ORDER BY recorded_at
+            "#,
+            channel_id,
+            since
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(history)
+    }
+    
+    // 재분석 중 표시가 1시간 넘게 남아 있으면 중단된 작업으로 보고 다시 점유
+    async fn try_start_reanalysis(&self, id: i64) -> Result<bool, Error> {
+        let result = sqlx::query!(
+            r#"
+                UPDATE youtube_channels
+                SET reanalysis_started_at = NOW()
+                WHERE id = ?
+                  AND is_finished = true
+                  AND (reanalysis_started_at IS NULL OR reanalysis_started_at < NOW() - INTERVAL 1 HOUR)
+            "#,
+            id
+        )
+            .execute(&self.db_pool)
+            .await?;
+        
+        Ok(result.rows_affected() > 0)
+    }
+    
+    async fn finish_reanalysis(&self, id: i64, analyzed_at: Option<DateTime<Utc>>) -> Result<(), Error> {
+        sqlx::query!(
+            r#"
+                UPDATE youtube_channels
+                SET reanalysis_started_at = NULL,
+                    analyzed_at = COALESCE(?, analyzed_at)
+                WHERE id = ?
+            "#,
+            analyzed_at,
+            id
         )
             .execute(&self.db_pool)
             .await?;
         
         Ok(())
     }
+    
+    // 병합 전 키워드를 스냅샷으로 남기고 새 영상의 조회수를 키워드, 월별 키워드에 누적하고 영상을 저장한 뒤 분석 시각 갱신
+    // 한 트랜잭션이라 중간에 실패하면 아무것도 반영되지 않고, 재시도해도 같은 영상의 조회수를 두 번 더하지 않음
+    async fn merge_channel_analysis(&self, analysis: &ChannelAnalysisResult) -> Result<(), Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        insert_keyword_snapshot(&mut tx, analysis.youtube_channel_id, analysis.analyzed_at).await?;
+        upsert_channel_keywords(&mut tx, &analysis.keywords).await?;
+        upsert_channel_keyword_months(&mut tx, &analysis.keyword_months).await?;
+        upsert_channel_videos(&mut tx, &analysis.videos, &analysis.video_keywords).await?;
+        
+        sqlx::query!(
+            r#"
+                UPDATE youtube_channels
+                SET analyzed_at = ?
+                WHERE id = ?
+            "#,
+            analysis.analyzed_at,
+            analysis.youtube_channel_id
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        tx.commit().await?;
+        
+        Ok(())
+    }
+    
+    async fn find_keyword_snapshot_order_by_view_count(&self, youtube_channel_id: i64, limit: u32) -> Result<Vec<YoutubeChannelKeywordSnapshot>, Error> {
+        let snapshot = sqlx::query_as!(
+            YoutubeChannelKeywordSnapshot,
+            r#"
+                SELECT id, youtube_channel_id, keyword_text, view_count, snapshot_at
+                FROM youtube_channel_keyword_snapshots
+                WHERE youtube_channel_id = ?
+                ORDER BY view_count DESC
+                LIMIT ?
+            "#,
+            youtube_channel_id,
+            limit
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(snapshot)
+    }
+    
+    async fn find_keyword_months_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelKeywordMonth>, Error> {
+        let keyword_months = sqlx::query_as!(
+            YoutubeChannelKeywordMonth,
+            r#"
+                SELECT id, youtube_channel_id, month, keyword_text, view_count
+                FROM youtube_channel_keyword_months
+                WHERE youtube_channel_id = ?
+                ORDER BY month, view_count DESC
+            "#,
+            youtube_channel_id
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(keyword_months)
+    }
+    
+    async fn find_videos_by_channel_keyword(&self, youtube_channel_id: i64, keyword_text: &str, limit: u32) -> Result<Vec<YoutubeChannelVideo>, Error> {
+        let videos = sqlx::query_as!(
+            YoutubeChannelVideo,
+            r#"
+                SELECT ycv.id, ycv.youtube_channel_id, ycv.video_id, ycv.title, ycv.published_at, ycv.duration,
+                       ycv.view_count, ycv.like_count, ycv.comment_count, ycv.created_at, ycv.updated_at
+                FROM youtube_channel_video_keywords ycvk
+                JOIN youtube_channel_videos ycv
+                  ON ycv.youtube_channel_id = ycvk.youtube_channel_id AND ycv.video_id = ycvk.video_id
+                WHERE ycvk.youtube_channel_id = ?
+                  AND ycvk.keyword_text = ?
+                ORDER BY ycv.view_count DESC
+                LIMIT ?
+            "#,
+            youtube_channel_id,
+            keyword_text,
+            limit
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(videos)
+    }
+    
+    async fn find_videos_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelVideo>, Error> {
+        let videos = sqlx::query_as!(
+            YoutubeChannelVideo,
+            r#"
+                SELECT id, youtube_channel_id, video_id, title, published_at, duration,
+                       view_count, like_count, comment_count, created_at, updated_at
+                FROM youtube_channel_videos
+                WHERE youtube_channel_id = ?
+                ORDER BY published_at
+            "#,
+            youtube_channel_id
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(videos)
+    }
+    
+    async fn find_video_keywords_by_channel_id(&self, youtube_channel_id: i64) -> Result<Vec<YoutubeChannelVideoKeyword>, Error> {
+        let video_keywords = sqlx::query_as!(
+            YoutubeChannelVideoKeyword,
+            r#"
+                SELECT id, youtube_channel_id, video_id, keyword_text
+                FROM youtube_channel_video_keywords
+                WHERE youtube_channel_id = ?
+            "#,
+            youtube_channel_id
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(video_keywords)
+    }
+    
+    // 사용자별 잠금 행을 먼저 잡아 같은 사용자의 동시 요청을 줄 세운 뒤 한도를 확인하고 요청을 저장
+    // 한도를 넘었으면 아무것도 저장하지 않고 None
+    async fn reserve_analysis_request(&self, user_public_id: &str, channel_handle: &str, since: DateTime<Utc>, quota: i64) -> Result<Option<AnalysisQuotaReservation>, Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        sqlx::query!(
+            r#"
+                INSERT INTO youtube_channel_analysis_quota_locks (user_public_id)
+                VALUES (?)
+                ON DUPLICATE KEY UPDATE reserved_at = CURRENT_TIMESTAMP
+            "#,
+            user_public_id
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        let row = sqlx::query!(
+            r#"
+                SELECT COUNT(*) as count
+                FROM youtube_channel_analysis_requests
+                WHERE user_public_id = ?
+                  AND requested_at >= ?
+                  AND is_refunded = false
+            "#,
+            user_public_id,
+            since
+        )
+            .fetch_one(&mut *tx)
+            .await?;
+        if row.count >= quota {
+            return Ok(None);
+        }
+        
+        let request_id = sqlx::query!(
+            r#"
+                INSERT INTO youtube_channel_analysis_requests (user_public_id, channel_handle)
+                VALUES (?, ?)
+            "#,
+            user_public_id,
+            channel_handle
+        )
+            .execute(&mut *tx)
+            .await?
+            .last_insert_id() as i64;
+        
+        tx.commit().await?;
+        
+        Ok(Some(AnalysisQuotaReservation {
+            request_id,
+            used_count: row.count + 1,
+        }))
+    }
+    
+    async fn refund_analysis_request(&self, request_id: i64) -> Result<(), Error> {
+        sqlx::query!(
+            r#"
+                UPDATE youtube_channel_analysis_requests
+                SET is_refunded = true
+                WHERE id = ?
+            "#,
+            request_id
+        )
+            .execute(&self.db_pool)
+            .await?;
+        
+        Ok(())
+    }
+    
+    // 같은 채널을 여러 번 요청했으면 마지막 요청 시각 기준으로 한 번만 노출
+    async fn find_channels_requested_by_user(&self, user_public_id: &str, limit: u32, offset: u32) -> Result<Vec<UserChannelAnalysis>, Error> {
+        let channels = sqlx::query_as::<_, UserChannelAnalysis>(
+            "SELECT yc.id, yc.channel_id, yc.channel_handle, yc.channel_title, yc.thumbnail_url, yc.description, \
+                    yc.subscriber_count, yc.view_count, yc.video_count, yc.is_finished, yc.analyzed_at, \
+                    yc.keyword_months_complete, \
+                    yc.analysis_max_videos, yc.analysis_published_after, yc.analysis_published_before, \
+                    yc.analysis_video_format, yc.created_at, yc.updated_at, \
+                    MAX(ycar.requested_at) AS last_requested_at, COUNT(*) AS request_count \
+             FROM youtube_channel_analysis_requests ycar \
+             JOIN youtube_channels yc ON yc.channel_handle = ycar.channel_handle \
+             WHERE ycar.user_public_id = ? \
+             GROUP BY yc.id \
+             ORDER BY last_requested_at DESC, yc.id DESC \
+             LIMIT ? OFFSET ?"
+        )
+            .bind(user_public_id)
+            .bind(limit)
+            .bind(offset)
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(channels)
+    }
+    
+    async fn count_channels_requested_by_user(&self, user_public_id: &str) -> Result<i64, Error> {
+        let row = sqlx::query!(
+            r#"
+                SELECT COUNT(DISTINCT yc.id) as count
+                FROM youtube_channel_analysis_requests ycar
+                JOIN youtube_channels yc ON yc.channel_handle = ycar.channel_handle
+                WHERE ycar.user_public_id = ?
+            "#,
+            user_public_id
+        )
+            .fetch_one(&self.db_pool)
+            .await?;
+        
+        Ok(row.count)
+    }
+    
+    // 수집 영상에서 트렌드 키워드와 채널 키워드가 같은 영상에 함께 붙은 횟수
+    async fn find_keyword_cooccurrences(
+        &self,
+        trending_keywords: &[String],
+        channel_keywords: &[String],
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<KeywordCooccurrence>, Error> {
+        if trending_keywords.is_empty() || channel_keywords.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let query_str = format!(
+            "SELECT trending.keyword_text AS trending_keyword, \
+                    channel.keyword_text AS channel_keyword, \
+                    COUNT(DISTINCT yv.id) AS video_count \
+             FROM youtube_videos AS yv \
+             JOIN youtube_video_keywords AS trending_link ON trending_link.video_id = yv.id \
+             JOIN youtube_keywords AS trending ON trending.id = trending_link.keyword_id \
+             JOIN youtube_video_keywords AS channel_link ON channel_link.video_id = yv.id \
+             JOIN youtube_keywords AS channel ON channel.id = channel_link.keyword_id \
+             WHERE yv.updated_at >= ? AND yv.updated_at < ? \
+               AND yv.is_available = TRUE \
+               AND trending.keyword_text IN ({}) \
+               AND channel.keyword_text IN ({}) \
+               AND trending.id <> channel.id \
+             GROUP BY trending.keyword_text, channel.keyword_text",
+            vec!["?"; trending_keywords.len()].join(", "),
+            vec!["?"; channel_keywords.len()].join(", ")
+        );
+        
+        let mut query = sqlx::query_as::<_, KeywordCooccurrence>(&query_str)
+            .bind(since)
+            .bind(until);
+        for keyword_text in trending_keywords.iter().chain(channel_keywords) {
+            query = query.bind(keyword_text);
+        }
+        
+        let cooccurrences = query.fetch_all(&self.db_pool).await?;
+        
+        Ok(cooccurrences)
+    }
+}
+
+// 목록 조회와 개수 조회가 같은 조건을 쓰도록 WHERE 절과 바인딩을 한 곳에서 관리
+fn channel_filter_clause(filter: &ChannelListFilter) -> String {
+    let mut conditions = Vec::new();
+    if filter.search.is_some() {
+        conditions.push("(channel_title LIKE ? OR channel_handle LIKE ?)");
+    }
+    if filter.is_finished.is_some() {
+        conditions.push("is_finished = ?");
+    }
+    if filter.min_subscribers.is_some() {
+        conditions.push("subscriber_count >= ?");
+    }
+    if filter.max_subscribers.is_some() {
+        conditions.push("subscriber_count <= ?");
+    }
+    
+    if conditions.is_empty() {
+        String::new()
+    } else {
+        format!(" WHERE {}", conditions.join(" AND "))
+    }
+}
+
+fn bind_channel_filter<'q, O>(
+    mut query: QueryAs<'q, MySql, O, MySqlArguments>,
+    filter: &ChannelListFilter,
+) -> QueryAs<'q, MySql, O, MySqlArguments> {
+    if let Some(search) = &filter.search {
+        let pattern = format!("%{}%", escape_like(search));
+        query = query.bind(pattern.clone()).bind(pattern);
+    }
+    if let Some(is_finished) = filter.is_finished {
+        query = query.bind(is_finished);
+    }
+    if let Some(min_subscribers) = filter.min_subscribers {
+        query = query.bind(min_subscribers);
+    }
+    if let Some(max_subscribers) = filter.max_subscribers {
+        query = query.bind(max_subscribers);
+    }
+    
+    query
+}
+
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+// 재분석 전후 변화 비교를 위해 현재 키워드를 스냅샷으로 교체
+async fn insert_keyword_snapshot(conn: &mut MySqlConnection, youtube_channel_id: i64, snapshot_at: DateTime<Utc>) -> Result<(), Error> {
+    sqlx::query!(
+        r#"
+            DELETE
+            FROM youtube_channel_keyword_snapshots
+            WHERE youtube_channel_id = ?
+        "#,
+        youtube_channel_id
+    )
+        .execute(&mut *conn)
+        .await?;
+    
+    sqlx::query!(
+        r#"
+            INSERT INTO youtube_channel_keyword_snapshots (youtube_channel_id, keyword_text, view_count, snapshot_at)
+            SELECT youtube_channel_id, keyword_text, view_count, ?
+            FROM youtube_channel_keywords
+            WHERE youtube_channel_id = ?
+        "#,
+        snapshot_at,
+        youtube_channel_id
+    )
+        .execute(&mut *conn)
+        .await?;
+    
+    Ok(())
+}
+
+// 스냅샷과 채널 정보는 남기고 분석 결과만 삭제
+async fn delete_channel_analysis(conn: &mut MySqlConnection, youtube_channel_id: i64) -> Result<(), Error> {
+    sqlx::query!(
+        r#"
+            DELETE
+            FROM youtube_channel_video_keywords
+            WHERE youtube_channel_id = ?
+        "#,
+        youtube_channel_id
+    )
+        .execute(&mut *conn)
+        .await?;
+    
+    sqlx::query!(
+        r#"
+            DELETE
+            FROM youtube_channel_videos
+            WHERE youtube_channel_id = ?
+        "#,
+        youtube_channel_id
+    )
+        .execute(&mut *conn)
+        .await?;
+    
+    sqlx::query!(
+        r#"
+            DELETE
+            FROM youtube_channel_keyword_months
+            WHERE youtube_channel_id = ?
+        "#,
+        youtube_channel_id
+    )
+        .execute(&mut *conn)
+        .await?;
+    
+    sqlx::query!(
+        r#"
+            DELETE
+            FROM youtube_channel_keywords
+            WHERE youtube_channel_id = ?
+        "#,
+        youtube_channel_id
+    )
+        .execute(&mut *conn)
+        .await?;
+    
+    Ok(())
+}
+
+// 새로 분석한 영상의 조회수를 기존 키워드에 누적
+async fn upsert_channel_keywords(conn: &mut MySqlConnection, keywords: &[YoutubeChannelKeyword]) -> Result<(), Error> {
+    for chunk in keywords.chunks(1000) {
+        let mut query_builder = String::from(
+            "INSERT INTO youtube_channel_keywords (youtube_channel_id, keyword_text, view_count) VALUES "
+        );
+        query_builder.push_str(&vec!["(?, ?, ?)"; chunk.len()].join(", "));
+        query_builder.push_str(" ON DUPLICATE KEY UPDATE view_count = view_count + VALUES(view_count)");
+        
+        let mut query = sqlx::query(&query_builder);
+        for keyword in chunk {
+            query = query
+                .bind(keyword.youtube_channel_id)
+                .bind(&keyword.keyword_text)
+                .bind(keyword.view_count);
+        }
+        query.execute(&mut *conn).await?;
+    }
+    
+    Ok(())
+}
+
+async fn upsert_channel_keyword_months(conn: &mut MySqlConnection, keyword_months: &[YoutubeChannelKeywordMonth]) -> Result<(), Error> {
+    for chunk in keyword_months.chunks(1000) {
+        let mut query_builder = String::from(
+            "INSERT INTO youtube_channel_keyword_months (youtube_channel_id, month, keyword_text, view_count) VALUES "
+        );
+        query_builder.push_str(&vec!["(?, ?, ?, ?)"; chunk.len()].join(", "));
+        query_builder.push_str(" ON DUPLICATE KEY UPDATE view_count = view_count + VALUES(view_count)");
+        
+        let mut query = sqlx::query(&query_builder);
+        for keyword_month in chunk {
+            query = query
+                .bind(keyword_month.youtube_channel_id)
+                .bind(keyword_month.month)
+                .bind(&keyword_month.keyword_text)
+                .bind(keyword_month.view_count);
+        }
+        query.execute(&mut *conn).await?;
+    }
+    
+    Ok(())
+}
+
+// 재분석으로 다시 들어온 영상은 최신 통계로 갱신
+async fn upsert_channel_videos(conn: &mut MySqlConnection, videos: &[YoutubeChannelVideo], video_keywords: &[YoutubeChannelVideoKeyword]) -> Result<(), Error> {
+    for chunk in videos.chunks(500) {
+        let mut query_builder = String::from(
+            "INSERT INTO youtube_channel_videos \
+             (youtube_channel_id, video_id, title, published_at, duration, view_count, like_count, comment_count) VALUES "
+        );
+        query_builder.push_str(&vec!["(?, ?, ?, ?, ?, ?, ?, ?)"; chunk.len()].join(", "));
+        query_builder.push_str(
+            " ON DUPLICATE KEY UPDATE title = VALUES(title), view_count = VALUES(view_count), \
+              like_count = VALUES(like_count), comment_count = VALUES(comment_count)"
+        );
+        
+        let mut query = sqlx::query(&query_builder);
+        for video in chunk {
+            query = query
+                .bind(video.youtube_channel_id)
+                .bind(&video.video_id)
+                .bind(&video.title)
+                .bind(video.published_at)
+                .bind(video.duration)
+                .bind(video.view_count)
+                .bind(video.like_count)
+                .bind(video.comment_count);
+        }
+        query.execute(&mut *conn).await?;
+    }
+    
+    for chunk in video_keywords.chunks(1000) {
+        let mut query_builder = String::from(
+            "INSERT IGNORE INTO youtube_channel_video_keywords (youtube_channel_id, video_id, keyword_text) VALUES "
+        );
+        query_builder.push_str(&vec!["(?, ?, ?)"; chunk.len()].join(", "));
+        
+        let mut query = sqlx::query(&query_builder);
+        for video_keyword in chunk {
+            query = query
+                .bind(video_keyword.youtube_channel_id)
+                .bind(&video_keyword.video_id)
+                .bind(&video_keyword.keyword_text);
+        }
+        query.execute(&mut *conn).await?;
+    }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    async fn init_schema(pool: &MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_channel_analysis_requests (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                user_public_id VARCHAR(255) NOT NULL,
+                channel_handle VARCHAR(255) NOT NULL,
+                requested_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                is_refunded BOOLEAN NOT NULL DEFAULT FALSE,
+                INDEX idx_channel_analysis_requests_user_requested (user_public_id, requested_at)
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+        
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_channel_analysis_quota_locks (
+                user_public_id VARCHAR(255) PRIMARY KEY,
+                reserved_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    
+    fn month_start() -> DateTime<Utc> {
+        Utc::now() - chrono::TimeDelta::days(1)
+    }
+    
+    #[sqlx::test]
+    async fn reserve_analysis_request_stops_at_quota(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_channel_repository = YoutubeChannelSqlxRepository::new(pool);
+        
+        let first = youtube_channel_repository.reserve_analysis_request("user", "@first", month_start(), 2).await.unwrap();
+        let second = youtube_channel_repository.reserve_analysis_request("user", "@second", month_start(), 2).await.unwrap();
+        let third = youtube_channel_repository.reserve_analysis_request("user", "@third", month_start(), 2).await.unwrap();
+        let other_user = youtube_channel_repository.reserve_analysis_request("other", "@first", month_start(), 2).await.unwrap();
+        
+        assert_eq!(first.map(|reservation| reservation.used_count), Some(1));
+        assert_eq!(second.map(|reservation| reservation.used_count), Some(2));
+        assert_eq!(third, None);
+        assert_eq!(other_user.map(|reservation| reservation.used_count), Some(1));
+    }
+    
+    #[sqlx::test]
+    async fn refunded_request_is_not_counted(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_channel_repository = YoutubeChannelSqlxRepository::new(pool);
+        
+        let reservation = youtube_channel_repository
+            .reserve_analysis_request("user", "@first", month_start(), 1)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(youtube_channel_repository.reserve_analysis_request("user", "@second", month_start(), 1).await.unwrap().is_none());
+        
+        youtube_channel_repository.refund_analysis_request(reservation.request_id).await.unwrap();
+        
+        assert!(youtube_channel_repository.reserve_analysis_request("user", "@second", month_start(), 1).await.unwrap().is_some());
+    }
+    
+    #[sqlx::test]
+    async fn concurrent_reservations_do_not_exceed_quota(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_channel_repository = YoutubeChannelSqlxRepository::new(pool);
+        
+        let handles: Vec<_> = (0..6)
+            .map(|index| {
+                let youtube_channel_repository = youtube_channel_repository.clone();
+                tokio::spawn(async move {
+                    youtube_channel_repository
+                        .reserve_analysis_request("user", &format!("@channel{}", index), month_start(), 3)
+                        .await
+                        .unwrap()
+                })
+            })
+            .collect();
+        let mut reserved_count = 0;
+        for handle in handles {
+            if handle.await.unwrap().is_some() {
+                reserved_count += 1;
+            }
+        }
+        
+        assert_eq!(reserved_count, 3);
+    }
 }
\ No newline at end of file
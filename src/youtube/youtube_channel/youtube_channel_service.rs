@@ -1,45 +1,103 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::sync::Arc;
+use chrono::{DateTime, Datelike, NaiveDate, SubsecRound, TimeDelta, Utc};
+use chrono_tz::Asia::Seoul;
+use crate::common::keyword::normalize_keyword;
 use crate::common::pagination::{Page, PaginationQuery};
 use crate::errors::AppError;
 use crate::gemini::gemini_api_util::GeminiAPIClient;
+use crate::youtube::youtube_channel::youtube_channel_benchmark::build_channel_benchmark;
+use crate::youtube::youtube_channel::youtube_channel_comparison::compare_channel_keywords;
 use crate::youtube::youtube_channel::youtube_channel_error::YoutubeChannelError;
-use crate::youtube::youtube_channel::youtube_channel_model::{ChannelKeywordResponse, ChannelResponse, YoutubeChannel, YoutubeChannelKeyword};
+use crate::youtube::youtube_channel::youtube_channel_gap::{build_keyword_gap, covered_keywords};
+use crate::youtube::youtube_channel::youtube_channel_model::{AnalysisQuotaReservation, ChannelAnalysisOptions, ChannelAnalysisResult, ChannelBenchmarkResponse, ChannelKeywordGapResponse, ChannelDetailResponse, ChannelListQuery, ChannelComparisonResponse, ChannelGrowthResponse, ChannelKeywordDiffResponse, ChannelKeywordMonthResponse, ChannelKeywordResponse, ChannelKeywordTimelineResponse, ChannelKeywordVideosResponse, ChannelVideoResponse, ChannelResponse, ChannelStatsPoint, MyChannelAnalysisResponse, ChannelStatsRefreshSummary, YoutubeChannel, YoutubeChannelKeyword, YoutubeChannelKeywordMonth, YoutubeChannelKeywordSnapshot, YoutubeChannelVideo, YoutubeChannelVideoKeyword};
 use crate::youtube::youtube_channel::youtube_channel_repository::YoutubeChannelRepository;
-use crate::youtube::youtube_data_api::youtube_data_api_model::ChannelItem;
-use crate::youtube::youtube_data_api::youtube_data_api_util::YoutubeDataAPIClient;
-use crate::youtube::youtube_video::youtube_video_model::YoutubeVideo;
+use crate::youtube::youtube_data_api::youtube_data_api_model::{ChannelItem, VideoItem, YoutubeQuotaUsage};
+use crate::youtube::youtube_data_api::youtube_data_api_util::{select_playlist_video_ids, YoutubeDataAPIClient};
+use crate::youtube::youtube_video::youtube_video_model::{YoutubeVideo, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM};
+use crate::youtube::youtube_video::youtube_video_repository::YoutubeVideoRepository;
+
+const CHANNEL_KEYWORD_LIMIT: u32 = 100;
+const TIMELINE_KEYWORD_LIMIT: usize = 10;
+const KEYWORD_VIDEO_LIMIT: u32 = 100;
+const GAP_RANKING_LIMIT: u32 = 100;
+const GAP_CHANNEL_KEYWORD_LIMIT: usize = 200;
 
 #[derive(Clone)]
 pub struct YoutubeChannelService {
     youtube_channel_repository: Arc<dyn YoutubeChannelRepository>,
+    youtube_video_repository: Arc<dyn YoutubeVideoRepository>,
     youtube_data_api_client: Arc<YoutubeDataAPIClient>,
-    gemini_api_client: Arc<GeminiAPIClient>
+    gemini_api_client: Arc<GeminiAPIClient>,
+    monthly_analysis_quota: i64,
 }
 
 impl YoutubeChannelService {
     pub fn new(
         youtube_channel_repository: Arc<dyn YoutubeChannelRepository>,
+        youtube_video_repository: Arc<dyn YoutubeVideoRepository>,
         youtube_data_api_client: Arc<YoutubeDataAPIClient>,
-        gemini_api_client: Arc<GeminiAPIClient>
+        gemini_api_client: Arc<GeminiAPIClient>,
+        monthly_analysis_quota: i64,
     ) -> Self {
         Self {
             youtube_channel_repository,
+            youtube_video_repository,
             youtube_data_api_client,
             gemini_api_client,
+            monthly_analysis_quota,
         }
     }
     
-    pub async fn request_analyze_youtube_channel_keywords(&self, channel_handle: String) -> Result<serde_json::Value, AppError> {
-        let search_query = if channel_handle.starts_with('@') {
-            channel_handle
-        } else {
-            format!("@{}", channel_handle)
-        };
+    pub async fn request_analyze_youtube_channel_keywords(
+        &self,
+        user_public_id: &str,
+        channel_handle: String,
+        analysis_options: ChannelAnalysisOptions
+    ) -> Result<serde_json::Value, AppError> {
+        let search_query = normalize_channel_handle(channel_handle);
+        validate_analysis_options(&analysis_options)?;
         
-        if self.youtube_channel_repository.channel_exists_by_handle(&search_query).await? {
-            return Err(YoutubeChannelError::ChannelDuplicated(search_query.to_string()))?;
+        let existing_channel = self.youtube_channel_repository.find_channel_by_handle(&search_query).await?;
+        if let Some(channel) = &existing_channel {
+            check_stored_analysis_options(&analysis_options, &ChannelAnalysisOptions::from(channel))?;
+        }
+        
+        // 요청이 접수된 경우만 한도에 포함 (분석 중 충돌, 채널 없음은 차감하지 않음)
+        // 이미 분석한 채널은 저장된 분석 옵션으로 마지막 분석 이후 업로드된 영상만 추가 분석
+        if let Some(channel) = existing_channel {
+            if !self.youtube_channel_repository.try_start_reanalysis(channel.id).await? {
+                return Err(YoutubeChannelError::AnalysisInProgress(search_query))?;
+            }
+            let reservation = match self.reserve_analysis_quota(user_public_id, &search_query).await {
+                Ok(reservation) => reservation,
+                Err(e) => {
+                    self.youtube_channel_repository.finish_reanalysis(channel.id, None).await?;
+                    return Err(e);
+                }
+            };
+            
+            // 새 영상이 없거나 분석에 실패하면 분석한 것이 없으므로 한도를 돌려줌
+            let service_clone = self.clone();
+            tokio::spawn(async move {
+                match service_clone.run_reanalysis(channel, false).await.map_err(|e| e.to_string()) {
+                    Ok(0) => {
+                        println!("[Background Job] 채널 '{}' 추가 분석 완료 (새 영상 없음, 한도 환불)", search_query);
+                        service_clone.refund_analysis_quota(reservation.request_id, &search_query).await;
+                    }
+                    Ok(video_count) => println!("[Background Job] 채널 '{}' 추가 분석 성공! (새 영상 {}개)", search_query, video_count),
+                    Err(e) => {
+                        eprintln!("[Background Job] 채널 '{}' 추가 분석 실패 (한도 환불): {}", search_query, e);
+                        service_clone.refund_analysis_quota(reservation.request_id, &search_query).await;
+                    }
+                }
+            });
+            
+            return Ok(serde_json::json!({
+                "message": "채널 재분석 요청이 접수되었습니다. 마지막 분석 이후 업로드된 영상만 분석하며, 새 영상이 없으면 한도를 차감하지 않습니다.",
+                "remaining_analyses": self.monthly_analysis_quota - reservation.used_count
+            }));
         }
         
         let channel_details = self.youtube_data_api_client
@@ -47,42 +105,239 @@ impl YoutubeChannelService {
             .await?
             .ok_or_else(|| YoutubeChannelError::ChannelNotFound(search_query.to_string()))?;
         
-        let channel = YoutubeChannel::from(&channel_details);
-        let youtube_channel_id = self.youtube_channel_repository
-                                     .save_channel(channel)
-                                     .await?;
+        let reservation = self.reserve_analysis_quota(user_public_id, &search_query).await?;
+        let channel = with_analysis_options(YoutubeChannel::from(&channel_details), &analysis_options);
+        let youtube_channel_id = match self.youtube_channel_repository.save_channel(channel).await {
+            Ok(youtube_channel_id) => youtube_channel_id,
+            Err(e) => {
+                self.youtube_channel_repository.refund_analysis_request(reservation.request_id).await?;
+                return Err(e)?;
+            }
+        };
         
+        // 분석에 실패하면 한도를 돌려줌
         let service_clone = self.clone();
         tokio::spawn(async move {
-           if let Err(e) = service_clone.analyze_youtube_channel_keywords(youtube_channel_id, channel_details).await {
-               eprintln!("[Background Job] 채널 '{}' 분석 실패: {}", search_query, e);
+           if let Err(e) = service_clone.analyze_youtube_channel_keywords(youtube_channel_id, channel_details, analysis_options).await.map_err(|e| e.to_string()) {
+               eprintln!("[Background Job] 채널 '{}' 분석 실패 (한도 환불): {}", search_query, e);
+               service_clone.refund_analysis_quota(reservation.request_id, &search_query).await;
            } else {
                println!("[Background Job] 채널 '{}' 분석 성공!", search_query);
            }
         });
         
         Ok(serde_json::json!({
-            "message": "채널 분석 요청이 접수되었습니다. 분석에는 몇 분 정도 소요될 수 있습니다."
+            "message": "채널 분석 요청이 접수되었습니다. 분석에는 몇 분 정도 소요될 수 있습니다.",
+            "remaining_analyses": self.monthly_analysis_quota - reservation.used_count
         }))
     }
     
+    async fn reserve_analysis_quota(&self, user_public_id: &str, channel_handle: &str) -> Result<AnalysisQuotaReservation, AppError> {
+        let reservation = self.youtube_channel_repository
+            .reserve_analysis_request(user_public_id, channel_handle, kst_month_start(Utc::now()), self.monthly_analysis_quota)
+            .await?
+            .ok_or(YoutubeChannelError::AnalysisQuotaExceeded(self.monthly_analysis_quota))?;
+        
+        Ok(reservation)
+    }
+    
+    // 백그라운드 작업에서 호출, 환불에 실패해도 작업 결과는 바뀌지 않으므로 기록만 남김
+    async fn refund_analysis_quota(&self, request_id: i64, channel_handle: &str) {
+        if let Err(e) = self.youtube_channel_repository.refund_analysis_request(request_id).await {
+            eprintln!("[Background Job] 채널 '{}' 분석 한도 환불 실패: {}", channel_handle, e);
+        }
+    }
+    
+    // 요청 API 와 같은 점유 절차로 재분석하고 완료될 때까지 대기
+    // full 이면 저장된 분석 옵션으로 처음부터 다시 분석하고, 성공한 경우에만 기존 결과와 교체
+    pub async fn reanalyze_youtube_channel_keywords(&self, channel_handle: String, full: bool) -> Result<ChannelResponse, Box<dyn Error>> {
+        let search_query = normalize_channel_handle(channel_handle);
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&search_query)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(search_query.to_string()))?;
+        
+        if !self.youtube_channel_repository.try_start_reanalysis(channel.id).await? {
+            return Err(YoutubeChannelError::AnalysisInProgress(search_query))?;
+        }
+        self.run_reanalysis(channel, full).await?;
+        
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&search_query)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(search_query.to_string()))?;
+        
+        Ok(ChannelResponse::from(&channel))
+    }
+    
     async fn analyze_youtube_channel_keywords(
         &self, youtube_channel_id: i64, 
-        channel_details: ChannelItem
+        channel_details: ChannelItem,
+        analysis_options: ChannelAnalysisOptions
     ) -> Result<(), Box<dyn Error>> {
-        let upload_playlist_id = channel_details.content_details.related_playlists.uploads.clone();
+        self.replace_channel_keywords(youtube_channel_id, &channel_details, &analysis_options, Utc::now()).await?;
         
-        let video_ids = self.youtube_data_api_client
-                            .get_video_ids_from_playlist(&upload_playlist_id)
-                            .await?;
+        // 분석 시점의 통계를 성장 이력의 시작점으로 기록
+        let channel = YoutubeChannel {
+            id: youtube_channel_id,
+            ..YoutubeChannel::from(&channel_details)
+        };
+        self.youtube_channel_repository.save_channel_stats(&[channel], Utc::now()).await?;
         
-        let mut detailed_videos = Vec::new();
-        for chunk in video_ids.chunks(50) {
-            let details = self.youtube_data_api_client.get_videos_details(&chunk).await?;
-            detailed_videos.extend(details);
+        Ok(())
+    }
+    
+    // 분석 옵션 범위의 영상을 모두 분석한 뒤 기존 결과를 한 번에 교체
+    async fn replace_channel_keywords(
+        &self,
+        youtube_channel_id: i64,
+        channel_details: &ChannelItem,
+        analysis_options: &ChannelAnalysisOptions,
+        analyzed_at: DateTime<Utc>
+    ) -> Result<usize, Box<dyn Error>> {
+        let upload_playlist_id = &channel_details.content_details.related_playlists.uploads;
+        
+        let videos = self.collect_channel_videos(upload_playlist_id, analysis_options, None).await?;
+        let video_keywords = self.extract_video_keywords(youtube_channel_id, &videos).await?;
+        
+        self.youtube_channel_repository
+            .replace_channel_analysis(&channel_analysis_result(youtube_channel_id, &video_keywords, analyzed_at))
+            .await?;
+        
+        Ok(videos.len())
+    }
+    
+    // try_start_reanalysis 로 점유한 채널에서만 호출, 성공 여부와 관계없이 점유 해제
+    async fn run_reanalysis(&self, channel: YoutubeChannel, full: bool) -> Result<usize, Box<dyn Error>> {
+        let analyzed_at = Utc::now();
+        // 월별 이력이 일부만 있는 채널에 새 영상만 더하면 빠진 달이 채워지지 않으므로 전체 분석으로 실행
+        let full = full || !channel.keyword_months_complete;
+        let result = if full {
+            self.rebuild_channel_keywords(&channel, analyzed_at).await
+        } else {
+            self.merge_new_upload_keywords(&channel, analyzed_at).await
+        }.map_err(|e| e.to_string());
+        
+        self.youtube_channel_repository
+            .finish_reanalysis(channel.id, result.as_ref().ok().map(|_| analyzed_at))
+            .await?;
+        
+        Ok(result?)
+    }
+    
+    async fn rebuild_channel_keywords(&self, channel: &YoutubeChannel, analyzed_at: DateTime<Utc>) -> Result<usize, Box<dyn Error>> {
+        let channel_details = self.youtube_data_api_client
+            .get_channel_details_by_handle(&channel.channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel.channel_handle.clone()))?;
+        
+        self.replace_channel_keywords(channel.id, &channel_details, &ChannelAnalysisOptions::from(channel), analyzed_at).await
+    }
+    
+    async fn merge_new_upload_keywords(&self, channel: &YoutubeChannel, analyzed_at: DateTime<Utc>) -> Result<usize, Box<dyn Error>> {
+        let channel_details = self.youtube_data_api_client
+            .get_channel_details_by_handle(&channel.channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel.channel_handle.clone()))?;
+        let upload_playlist_id = &channel_details.content_details.related_playlists.uploads;
+        let last_analyzed_at = channel.analyzed_at.unwrap_or(channel.created_at);
+        let analysis_options = ChannelAnalysisOptions::from(channel);
+        
+        let videos = self.collect_channel_videos(upload_playlist_id, &analysis_options, Some(last_analyzed_at)).await?;
+        
+        // 분석 시각은 업로드 목록을 읽기 전에 정해지므로 직전 분석 도중 올라온 영상이 다시 잡힐 수 있음
+        // 이미 저장된 영상은 빼서 조회수가 두 번 누적되지 않게 함
+        let stored_videos = self.youtube_channel_repository.find_videos_by_channel_id(channel.id).await?;
+        let stored_video_ids: HashSet<&str> = stored_videos.iter().map(|video| video.video_id.as_str()).collect();
+        let videos: Vec<VideoItem> = videos
+            .into_iter()
+            .filter(|video| !stored_video_ids.contains(video.id.as_str()))
+            .collect();
+        if videos.is_empty() {
+            return Ok(0);
         }
         
-        let mut final_keywords_map = HashMap::new();
+        let video_keywords = self.extract_video_keywords(channel.id, &videos).await?;
+        
+        // 최대 영상 수는 채널 전체 기준, 저장된 영상과 합쳐 넘으면 오래된 영상을 빼고 남은 영상으로 결과를 다시 계산해 교체
+        // 영상별 결과를 저장하기 전에 분석한 채널은 뺄 영상을 알 수 없어 그대로 누적
+        if let Some(max_videos) = analysis_options.max_videos {
+            if !stored_videos.is_empty() && stored_videos.len() + video_keywords.len() > max_videos as usize {
+                let stored_video_keywords = self.youtube_channel_repository.find_video_keywords_by_channel_id(channel.id).await?;
+                let latest_video_keywords = keep_latest_videos(video_keywords, stored_videos, stored_video_keywords, max_videos as usize);
+                self.youtube_channel_repository
+                    .replace_channel_analysis(&channel_analysis_result(channel.id, &latest_video_keywords, analyzed_at))
+                    .await?;
+                
+                return Ok(videos.len());
+            }
+        }
+        
+        // 병합 전 키워드를 스냅샷으로 남겨 변화 비교에 사용, 누적과 영상 저장, 분석 시각 갱신은 한 번에 반영
+        self.youtube_channel_repository
+            .merge_channel_analysis(&channel_analysis_result(channel.id, &video_keywords, analyzed_at))
+            .await?;
+        
+        Ok(videos.len())
+    }
+    
+    // 업로드 재생목록(최신순)을 페이지 단위로 읽으면서 분석 범위에 맞는 영상만 모음
+    // 최대 개수를 채우거나 기준 시각 이전 영상이 나오면 더 이상 조회하지 않음
+    // playlistItems API 는 날짜 조건이 없어 published_before 이후 페이지도 목록은 읽지만(페이지당 1 unit) 상세 조회와 키워드 추출은 하지 않음
+    async fn collect_channel_videos(
+        &self,
+        upload_playlist_id: &str,
+        analysis_options: &ChannelAnalysisOptions,
+        since: Option<DateTime<Utc>>
+    ) -> Result<Vec<VideoItem>, Box<dyn Error>> {
+        let published_after = match (analysis_options.published_after, since) {
+            (Some(published_after), Some(since)) => Some(published_after.max(since)),
+            (published_after, since) => published_after.or(since),
+        };
+        let max_videos = analysis_options.max_videos.map(|max_videos| max_videos as usize);
+        
+        // 마지막 분석 시각이 분석 종료 시각 이후면 새로 들어올 영상이 없음
+        if let (Some(after), Some(before)) = (published_after, analysis_options.published_before) {
+            if after >= before {
+                return Ok(Vec::new());
+            }
+        }
+        
+        let mut videos = Vec::new();
+        let mut page_token = None;
+        loop {
+            let page = self.youtube_data_api_client
+                .get_playlist_items_page(upload_playlist_id, page_token.take())
+                .await?;
+            
+            let (video_ids, reached_older) = select_playlist_video_ids(page.items, published_after, analysis_options.published_before);
+            
+            let details = self.youtube_data_api_client.get_videos_details(&video_ids).await?;
+            videos.extend(
+                details
+                    .into_iter()
+                    .filter(|video| analysis_options.video_format.matches(video.content_details.as_seconds()))
+            );
+            
+            if let Some(max_videos) = max_videos {
+                if videos.len() >= max_videos {
+                    videos.truncate(max_videos);
+                    break;
+                }
+            }
+            
+            match page.next_page_token {
+                Some(token) if !reached_older => page_token = Some(token),
+                _ => break,
+            }
+        }
+        
+        Ok(videos)
+    }
+    
+    // 영상별로 Gemini 가 추출한 키워드를 함께 반환, 키워드가 없는 영상도 영상 목록과 벤치마크에 쓰이도록 빈 키워드로 포함
+    async fn extract_video_keywords(&self, youtube_channel_id: i64, detailed_videos: &[VideoItem]) -> Result<Vec<(YoutubeChannelVideo, Vec<String>)>, Box<dyn Error>> {
+        let mut video_keywords = Vec::new();
         let mut count = 0;
         for video_chunk in detailed_videos.chunks(50) {
             count += 1;
@@ -97,40 +352,92 @@ impl YoutubeChannelService {
                                    .await?;
             
             for video in videos {
-                if let Some(keywords) = keywords_map.get(&video.video_id) {
-                    for keyword in keywords {
-                        *final_keywords_map.entry(keyword.clone()).or_insert(0) += video.view_count;
-                    }
-                }
+                let keywords = keywords_map.get(&video.video_id).cloned().unwrap_or_default();
+                video_keywords.push((YoutubeChannelVideo::from_video(youtube_channel_id, &video), keywords));
             }
         }
         
-        let channel_keywords: Vec<YoutubeChannelKeyword> = final_keywords_map
-            .into_iter()
-            .map(|(k, v)| {
-                YoutubeChannelKeyword {
-                    id: Default::default(),
-                    youtube_channel_id: youtube_channel_id,
-                    keyword_text: k,
-                    view_count: v,
-                }
-            })
-            .collect();
+        Ok(video_keywords)
+    }
+    
+    
+    // 분석이 끝난 채널의 구독자, 조회수, 영상 수를 channels.list(50개당 1 unit)로 갱신하고 이력에 추가
+    pub async fn refresh_channel_statistics(&self) -> Result<ChannelStatsRefreshSummary, Box<dyn Error>> {
+        let channels = self.youtube_channel_repository.find_finished_channels().await?;
+        let mut summary = ChannelStatsRefreshSummary {
+            channel_count: channels.len(),
+            ..Default::default()
+        };
         
-        self.youtube_channel_repository.save_channel_keywords(channel_keywords).await?;
+        for chunk in channels.chunks(50) {
+            let recorded_at = Utc::now();
+            let channel_ids: Vec<String> = chunk.iter().map(|channel| channel.channel_id.clone()).collect();
+            let details = self.youtube_data_api_client.get_channels_details(&channel_ids).await?;
+            let details_map: HashMap<&str, &ChannelItem> = details
+                .iter()
+                .map(|item| (item.id.as_str(), item))
+                .collect();
+            
+            // 응답에 없는 채널(삭제 등)은 이번 갱신에서 제외
+            let refreshed_channels: Vec<YoutubeChannel> = chunk
+                .iter()
+                .filter_map(|channel| {
+                    let latest = YoutubeChannel::from(*details_map.get(channel.channel_id.as_str())?);
+                    Some(YoutubeChannel {
+                        subscriber_count: latest.subscriber_count,
+                        view_count: latest.view_count,
+                        video_count: latest.video_count,
+                        ..channel.clone()
+                    })
+                })
+                .collect();
+            
+            self.youtube_channel_repository.save_channel_stats(&refreshed_channels, recorded_at).await?;
+            summary.refreshed_channel_count += refreshed_channels.len();
+        }
         
-        self.youtube_channel_repository.update_channel_finished_by_id(youtube_channel_id).await?;
+        Ok(summary)
+    }
+    
+    pub async fn get_youtube_channel_growth(&self, channel_handle: &str, days: u32) -> Result<ChannelGrowthResponse, AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle.to_string());
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
         
-        Ok(())
+        let since = Utc::now() - TimeDelta::days(days.clamp(1, 365) as i64);
+        let history: Vec<ChannelStatsPoint> = self.youtube_channel_repository
+            .find_channel_stats_history(&channel.channel_id, since)
+            .await?
+            .iter()
+            .map(ChannelStatsPoint::from)
+            .collect();
+        
+        let growth = |count: fn(&ChannelStatsPoint) -> i64| match (history.first(), history.last()) {
+            (Some(first), Some(last)) => count(last) - count(first),
+            _ => 0,
+        };
+        
+        Ok(ChannelGrowthResponse {
+            subscriber_growth: growth(|point| point.subscriber_count),
+            view_growth: growth(|point| point.view_count),
+            video_growth: growth(|point| point.video_count),
+            channel_handle: channel.channel_handle,
+            history,
+        })
     }
     
-    pub async fn get_youtube_channels(&self, query: PaginationQuery) -> Result<Page<ChannelResponse>, Box<dyn Error>> {
+    pub async fn get_youtube_channels(&self, query: ChannelListQuery) -> Result<Page<ChannelResponse>, Box<dyn Error>> {
         let limit = query.size;
         let offset = query.page * query.size;
+        let filter = query.filter();
         
-        let total_items = self.youtube_channel_repository.count_all_channels().await?;
+        let total_items = self.youtube_channel_repository.count_all_channels(&filter).await?;
         
-        let youtube_channels = self.youtube_channel_repository.find_all_channels(limit, offset).await?;
+        let youtube_channels = self.youtube_channel_repository
+            .find_all_channels(&filter, query.sort, query.direction, limit, offset)
+            .await?;
         let response = youtube_channels
             .iter()
             .map(ChannelResponse::from)
@@ -147,9 +454,67 @@ impl YoutubeChannelService {
         })
     }
     
+    pub async fn get_my_channel_analyses(&self, user_public_id: &str, query: PaginationQuery) -> Result<Page<MyChannelAnalysisResponse>, AppError> {
+        let limit = query.size;
+        let offset = query.page * query.size;
+        
+        let total_items = self.youtube_channel_repository.count_channels_requested_by_user(user_public_id).await?;
+        let analyses = self.youtube_channel_repository
+            .find_channels_requested_by_user(user_public_id, limit, offset)
+            .await?;
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+        
+        Ok(Page {
+            items: analyses.iter().map(MyChannelAnalysisResponse::from).collect(),
+            page: query.page,
+            size: query.size,
+            total_items,
+            total_pages
+        })
+    }
+    
+    pub async fn get_youtube_channel(&self, channel_handle: String) -> Result<ChannelDetailResponse, AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle);
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or(YoutubeChannelError::ChannelNotFound(channel_handle))?;
+        let keyword_count = self.youtube_channel_repository.count_keywords_by_channel_id(channel.id).await?;
+        
+        Ok(ChannelDetailResponse {
+            channel: ChannelResponse::from(&channel),
+            keyword_count,
+            created_at: channel.created_at,
+        })
+    }
+    
+    // 분석 중인 채널은 백그라운드 작업이 키워드를 다시 쓸 수 있으므로 삭제하지 않음
+    pub async fn delete_youtube_channel(&self, channel_handle: String) -> Result<(), AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle);
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
+        
+        if !self.youtube_channel_repository.try_start_reanalysis(channel.id).await? {
+            return Err(YoutubeChannelError::AnalysisInProgress(channel_handle))?;
+        }
+        
+        // 삭제하지 못하면 점유를 풀어 채널이 분석 중으로 남지 않게 함
+        let deleted = self.youtube_channel_repository.delete_channel_by_handle(&channel_handle).await;
+        if !matches!(deleted, Ok(true)) {
+            self.youtube_channel_repository.finish_reanalysis(channel.id, None).await?;
+        }
+        if !deleted? {
+            return Err(YoutubeChannelError::ChannelNotFound(channel_handle))?;
+        }
+        
+        Ok(())
+    }
+    
     pub async fn get_youtube_channel_keywords(&self, channel_handle: &str) -> Result<Vec<ChannelKeywordResponse>, Box<dyn Error>> {
         let youtube_channel_keywords = self.youtube_channel_repository
-            .find_keywords_by_channel_handle_order_by_view_count(channel_handle, 100)
+            .find_keywords_by_channel_handle_order_by_view_count(channel_handle, CHANNEL_KEYWORD_LIMIT)
             .await?;
         let response = youtube_channel_keywords
             .iter()
@@ -159,9 +524,692 @@ impl YoutubeChannelService {
         Ok(response)
     }
     
+    pub async fn compare_youtube_channels(&self, channel_handles: &str) -> Result<ChannelComparisonResponse, AppError> {
+        let mut handles: Vec<String> = Vec::new();
+        for channel_handle in channel_handles.split(',').map(str::trim).filter(|handle| !handle.is_empty()) {
+            let channel_handle = normalize_channel_handle(channel_handle.to_string());
+            if !handles.contains(&channel_handle) {
+                handles.push(channel_handle);
+            }
+        }
+        
+        if !(2..=5).contains(&handles.len()) {
+            return Err(YoutubeChannelError::InvalidComparisonChannels)?;
+        }
+        
+        let mut channels = Vec::with_capacity(handles.len());
+        for channel_handle in handles {
+            let channel = self.youtube_channel_repository
+                .find_channel_by_handle(&channel_handle)
+                .await?
+                .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
+            if !channel.is_finished {
+                return Err(YoutubeChannelError::AnalysisInProgress(channel_handle))?;
+            }
+            
+            // 공통 키워드와 고유 키워드가 같은 기준으로 나뉘도록 채널의 전체 키워드로 비교
+            let keywords = self.youtube_channel_repository
+                .find_keywords_by_channel_id(channel.id)
+                .await?;
+            channels.push((channel, keywords));
+        }
+        
+        Ok(compare_channel_keywords(&channels))
+    }
+    
+    // 마지막 추가 분석 직전 스냅샷과 현재 상위 키워드를 비교
+    pub async fn get_youtube_channel_keyword_diff(&self, channel_handle: &str) -> Result<ChannelKeywordDiffResponse, AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle.to_string());
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
+        
+        let current_keywords = self.youtube_channel_repository
+            .find_keywords_by_channel_handle_order_by_view_count(&channel.channel_handle, CHANNEL_KEYWORD_LIMIT)
+            .await?;
+        let previous_keywords = self.youtube_channel_repository
+            .find_keyword_snapshot_order_by_view_count(channel.id, CHANNEL_KEYWORD_LIMIT)
+            .await?;
+        
+        let (entered_top_keywords, exited_top_keywords) = diff_top_keywords(&current_keywords, &previous_keywords);
+        
+        Ok(ChannelKeywordDiffResponse {
+            channel_handle: channel.channel_handle,
+            snapshot_at: previous_keywords.first().map(|keyword| keyword.snapshot_at),
+            entered_top_keywords,
+            exited_top_keywords,
+        })
+    }
+    
+    pub async fn get_youtube_channel_keyword_timeline(&self, channel_handle: &str) -> Result<ChannelKeywordTimelineResponse, AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle.to_string());
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
+        
+        let keyword_months = self.youtube_channel_repository
+            .find_keyword_months_by_channel_id(channel.id)
+            .await?;
+        let requires_full_reanalysis = !channel.keyword_months_complete
+            && self.youtube_channel_repository.count_keywords_by_channel_id(channel.id).await? > 0;
+        
+        Ok(ChannelKeywordTimelineResponse {
+            channel_handle: channel.channel_handle,
+            months: build_keyword_timeline(&keyword_months),
+            requires_full_reanalysis,
+        })
+    }
+    
+    pub async fn get_youtube_channel_keyword_videos(&self, channel_handle: &str, keyword_text: &str) -> Result<ChannelKeywordVideosResponse, AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle.to_string());
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
+        
+        let videos = self.youtube_channel_repository
+            .find_videos_by_channel_keyword(channel.id, keyword_text, KEYWORD_VIDEO_LIMIT)
+            .await?;
+        
+        Ok(ChannelKeywordVideosResponse {
+            channel_handle: channel.channel_handle,
+            keyword_text: keyword_text.to_string(),
+            videos: videos.iter().map(ChannelVideoResponse::from).collect(),
+        })
+    }
+    
+    pub async fn get_youtube_channel_benchmark(&self, channel_handle: &str) -> Result<ChannelBenchmarkResponse, AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle.to_string());
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
+        
+        // 영상 저장 전에 분석한 채널은 영상이 없어 모든 지표가 0 이 되므로 계산하지 않음
+        let videos = self.youtube_channel_repository.find_videos_by_channel_id(channel.id).await?;
+        if videos.is_empty() {
+            return Err(YoutubeChannelError::BenchmarkDataUnavailable(channel.channel_handle))?;
+        }
+        
+        Ok(build_channel_benchmark(&channel, &videos))
+    }
+    
+    pub async fn get_youtube_channel_keyword_gap(&self, channel_handle: &str) -> Result<ChannelKeywordGapResponse, AppError> {
+        let channel_handle = normalize_channel_handle(channel_handle.to_string());
+        let channel = self.youtube_channel_repository
+            .find_channel_by_handle(&channel_handle)
+            .await?
+            .ok_or_else(|| YoutubeChannelError::ChannelNotFound(channel_handle.clone()))?;
+        if !channel.is_finished {
+            return Err(YoutubeChannelError::AnalysisInProgress(channel.channel_handle))?;
+        }
+        
+        let rankings = self.youtube_video_repository
+            .find_latest_keyword_rankings(RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, GAP_RANKING_LIMIT)
+            .await?;
+        // 다룬 적 있는지는 채널의 전체 키워드로 판단하고, 동시 등장 비교에는 조회수 상위 키워드만 사용
+        let all_keywords = self.youtube_channel_repository
+            .find_keywords_by_channel_id(channel.id)
+            .await?;
+        let covered = covered_keywords(&all_keywords);
+        let channel_keywords = &all_keywords[..all_keywords.len().min(GAP_CHANNEL_KEYWORD_LIMIT)];
+        let Some(ranking_date) = rankings.first().map(|ranking| ranking.ranking_date) else {
+            return Ok(build_keyword_gap(channel.channel_handle, None, &[], &covered, channel_keywords, &[]));
+        };
+        
+        // 채널이 이미 다룬 키워드는 동시 등장 조회에서 제외
+        let trending_keywords: Vec<String> = rankings
+            .iter()
+            .filter(|ranking| !covered.contains_key(&normalize_keyword(&ranking.keyword_text)))
+            .map(|ranking| ranking.keyword_text.clone())
+            .collect();
+        let channel_keyword_texts: Vec<String> = channel_keywords.iter().map(|keyword| keyword.keyword_text.clone()).collect();
+        
+        // 순위 계산과 같은 최근 7일 수집 영상 기준
+        let cooccurrences = self.youtube_channel_repository
+            .find_keyword_cooccurrences(
+                &trending_keywords,
+                &channel_keyword_texts,
+                ranking_date - TimeDelta::days(7),
+                ranking_date + TimeDelta::days(1),
+            )
+            .await?;
+        
+        Ok(build_keyword_gap(channel.channel_handle, Some(ranking_date), &rankings, &covered, channel_keywords, &cooccurrences))
+    }
+    
     pub async fn cleanup_stale_channels(&self) -> Result<(), Box<dyn Error>> {
         self.youtube_channel_repository.delete_channel_not_finished().await?;
         
         Ok(())
     }
+    
+    pub async fn get_youtube_quota_usage(&self) -> Result<YoutubeQuotaUsage, anyhow::Error> {
+        self.youtube_data_api_client.get_quota_usage().await
+    }
+}
+
+fn normalize_channel_handle(channel_handle: String) -> String {
+    if channel_handle.starts_with('@') {
+        channel_handle
+    } else {
+        format!("@{}", channel_handle)
+    }
+}
+
+fn validate_analysis_options(analysis_options: &ChannelAnalysisOptions) -> Result<(), YoutubeChannelError> {
+    if analysis_options.max_videos == Some(0) {
+        return Err(YoutubeChannelError::InvalidAnalysisOptions("max_videos 는 1 이상이어야 합니다.".to_string()));
+    }
+    
+    if let (Some(published_after), Some(published_before)) = (analysis_options.published_after, analysis_options.published_before) {
+        if published_after >= published_before {
+            return Err(YoutubeChannelError::InvalidAnalysisOptions("published_after 는 published_before 보다 이전이어야 합니다.".to_string()));
+        }
+    }
+    
+    Ok(())
+}
+
+// 이미 분석한 채널은 저장된 분석 옵션으로만 추가 분석하므로 다른 옵션을 보내면 거절 (옵션 없이 보내면 저장된 옵션 사용)
+fn check_stored_analysis_options(
+    requested: &ChannelAnalysisOptions,
+    stored: &ChannelAnalysisOptions
+) -> Result<(), YoutubeChannelError> {
+    // DB 에는 초 단위로 저장되므로 초 미만은 버리고 비교
+    let truncate = |value: Option<DateTime<Utc>>| value.map(|value| value.trunc_subsecs(0));
+    let is_same = requested.max_videos.map(|max_videos| max_videos.min(i32::MAX as u32)) == stored.max_videos
+        && truncate(requested.published_after) == truncate(stored.published_after)
+        && truncate(requested.published_before) == truncate(stored.published_before)
+        && requested.video_format == stored.video_format;
+    if *requested == ChannelAnalysisOptions::default() || is_same {
+        return Ok(());
+    }
+    
+    Err(YoutubeChannelError::InvalidAnalysisOptions(
+        "이미 분석한 채널은 저장된 분석 옵션으로만 추가 분석합니다. 옵션을 비우거나 저장된 옵션과 같게 보내주세요.".to_string()
+    ))
+}
+
+fn with_analysis_options(channel: YoutubeChannel, analysis_options: &ChannelAnalysisOptions) -> YoutubeChannel {
+    YoutubeChannel {
+        analysis_max_videos: analysis_options.max_videos.map(|max_videos| max_videos.min(i32::MAX as u32) as i32),
+        analysis_published_after: analysis_options.published_after,
+        analysis_published_before: analysis_options.published_before,
+        analysis_video_format: analysis_options.video_format.as_str().to_string(),
+        ..channel
+    }
+}
+
+// 분석 한도는 KST 기준 매월 1일 0시에 초기화
+fn kst_month_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    let today = now.with_timezone(&Seoul).date_naive();
+    today
+        .with_day(1)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|time| time.and_local_timezone(Seoul).single())
+        .map(|month_start| month_start.with_timezone(&Utc))
+        .unwrap_or(now)
+}
+
+// 영상별 키워드에 해당 영상의 조회수를 합산
+// 키워드별로 어떤 영상이 조회수를 만들었는지 확인할 수 있도록 영상과 키워드 연결도 함께 담는다
+fn channel_analysis_result(youtube_channel_id: i64, video_keywords: &[(YoutubeChannelVideo, Vec<String>)], analyzed_at: DateTime<Utc>) -> ChannelAnalysisResult {
+    ChannelAnalysisResult {
+        youtube_channel_id,
+        keywords: sum_channel_keywords(youtube_channel_id, video_keywords),
+        keyword_months: sum_monthly_channel_keywords(youtube_channel_id, video_keywords),
+        videos: video_keywords
+            .iter()
+            .map(|(video, _)| YoutubeChannelVideo {
+                youtube_channel_id,
+                ..video.clone()
+            })
+            .collect(),
+        video_keywords: video_keywords
+            .iter()
+            .flat_map(|(video, keywords)| {
+                keywords.iter().map(|keyword| YoutubeChannelVideoKeyword {
+                    id: Default::default(),
+                    youtube_channel_id,
+                    video_id: video.video_id.clone(),
+                    keyword_text: keyword.clone(),
+                })
+            })
+            .collect(),
+        analyzed_at,
+    }
+}
+
+fn sum_channel_keywords(youtube_channel_id: i64, video_keywords: &[(YoutubeChannelVideo, Vec<String>)]) -> Vec<YoutubeChannelKeyword> {
+    let mut final_keywords_map = HashMap::new();
+    for (video, keywords) in video_keywords {
+        for keyword in keywords {
+            *final_keywords_map.entry(keyword.clone()).or_insert(0) += video.view_count;
+        }
+    }
+    
+    final_keywords_map
+        .into_iter()
+        .map(|(keyword_text, view_count)| YoutubeChannelKeyword {
+            id: Default::default(),
+            youtube_channel_id,
+            keyword_text,
+            view_count,
+        })
+        .collect()
+}
+
+// 새로 분석한 영상과 저장된 영상 중 최근 업로드 순으로 max_videos 개만 남김 (같은 영상은 새 분석 결과를 사용)
+fn keep_latest_videos(
+    new_video_keywords: Vec<(YoutubeChannelVideo, Vec<String>)>,
+    stored_videos: Vec<YoutubeChannelVideo>,
+    stored_video_keywords: Vec<YoutubeChannelVideoKeyword>,
+    max_videos: usize
+) -> Vec<(YoutubeChannelVideo, Vec<String>)> {
+    let mut stored_keywords_map: HashMap<String, Vec<String>> = HashMap::new();
+    for video_keyword in stored_video_keywords {
+        stored_keywords_map.entry(video_keyword.video_id).or_default().push(video_keyword.keyword_text);
+    }
+    
+    let new_video_ids: HashSet<String> = new_video_keywords.iter().map(|(video, _)| video.video_id.clone()).collect();
+    let mut video_keywords = new_video_keywords;
+    video_keywords.extend(
+        stored_videos
+            .into_iter()
+            .filter(|video| !new_video_ids.contains(&video.video_id))
+            .map(|video| {
+                let keywords = stored_keywords_map.remove(&video.video_id).unwrap_or_default();
+                (video, keywords)
+            })
+    );
+    video_keywords.sort_by(|(a, _), (b, _)| b.published_at.cmp(&a.published_at));
+    video_keywords.truncate(max_videos);
+    
+    video_keywords
+}
+
+// 업로드 월(KST)별로 나눠 키워드 조회수를 합산
+fn sum_monthly_channel_keywords(youtube_channel_id: i64, video_keywords: &[(YoutubeChannelVideo, Vec<String>)]) -> Vec<YoutubeChannelKeywordMonth> {
+    let mut monthly_keywords_map: HashMap<(NaiveDate, String), i64> = HashMap::new();
+    for (video, keywords) in video_keywords {
+        let upload_date = video.published_at.with_timezone(&Seoul).date_naive();
+        let Some(month) = upload_date.with_day(1) else { continue };
+        for keyword in keywords {
+            *monthly_keywords_map.entry((month, keyword.clone())).or_insert(0) += video.view_count;
+        }
+    }
+    
+    monthly_keywords_map
+        .into_iter()
+        .map(|((month, keyword_text), view_count)| YoutubeChannelKeywordMonth {
+            id: Default::default(),
+            youtube_channel_id,
+            month,
+            keyword_text,
+            view_count,
+        })
+        .collect()
+}
+
+// 현재 상위 키워드와 직전 분석 스냅샷의 상위 키워드를 비교해 (새로 들어온 키워드, 밀려난 키워드) 반환
+// 스냅샷이 없으면 비교 대상이 없으므로 빈 결과
+fn diff_top_keywords(
+    current_keywords: &[YoutubeChannelKeyword],
+    previous_keywords: &[YoutubeChannelKeywordSnapshot]
+) -> (Vec<ChannelKeywordResponse>, Vec<ChannelKeywordResponse>) {
+    if previous_keywords.is_empty() {
+        return (Vec::new(), Vec::new());
+    }
+    
+    let current_texts: HashSet<&str> = current_keywords.iter().map(|keyword| keyword.keyword_text.as_str()).collect();
+    let previous_texts: HashSet<&str> = previous_keywords.iter().map(|keyword| keyword.keyword_text.as_str()).collect();
+    
+    let entered = current_keywords
+        .iter()
+        .filter(|keyword| !previous_texts.contains(keyword.keyword_text.as_str()))
+        .map(ChannelKeywordResponse::from)
+        .collect();
+    let exited = previous_keywords
+        .iter()
+        .filter(|keyword| !current_texts.contains(keyword.keyword_text.as_str()))
+        .map(ChannelKeywordResponse::from)
+        .collect();
+    
+    (entered, exited)
+}
+
+// 월별 상위 키워드를 직전 업로드 월과 비교해 새로 등장하거나 사라진 주제를 표시
+fn build_keyword_timeline(keyword_months: &[YoutubeChannelKeywordMonth]) -> Vec<ChannelKeywordMonthResponse> {
+    let mut months: Vec<(NaiveDate, Vec<&YoutubeChannelKeywordMonth>)> = Vec::new();
+    for keyword_month in keyword_months {
+        match months.last_mut() {
+            Some((month, keywords)) if *month == keyword_month.month => keywords.push(keyword_month),
+            _ => months.push((keyword_month.month, vec![keyword_month])),
+        }
+    }
+    
+    let mut timeline = Vec::with_capacity(months.len());
+    let mut previous_top: Option<HashSet<&str>> = None;
+    for (month, mut keywords) in months {
+        keywords.sort_by(|a, b| b.view_count.cmp(&a.view_count).then_with(|| a.keyword_text.cmp(&b.keyword_text)));
+        let total_view_count = keywords.iter().map(|keyword| keyword.view_count).sum();
+        keywords.truncate(TIMELINE_KEYWORD_LIMIT);
+        
+        let top: HashSet<&str> = keywords.iter().map(|keyword| keyword.keyword_text.as_str()).collect();
+        let mut entered_keywords = Vec::new();
+        let mut exited_keywords = Vec::new();
+        if let Some(previous) = &previous_top {
+            entered_keywords = keywords
+                .iter()
+                .map(|keyword| keyword.keyword_text.to_string())
+                .filter(|keyword_text| !previous.contains(keyword_text.as_str()))
+                .collect();
+            exited_keywords = previous
+                .iter()
+                .filter(|keyword_text| !top.contains(*keyword_text))
+                .map(|keyword_text| keyword_text.to_string())
+                .collect();
+            exited_keywords.sort();
+        }
+        
+        timeline.push(ChannelKeywordMonthResponse {
+            month: month.format("%Y-%m").to_string(),
+            total_view_count,
+            keywords: keywords
+                .iter()
+                .map(|keyword| ChannelKeywordResponse {
+                    keyword_text: keyword.keyword_text.clone(),
+                    view_count: keyword.view_count,
+                })
+                .collect(),
+            entered_keywords,
+            exited_keywords,
+        });
+        previous_top = Some(top);
+    }
+    
+    timeline
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::{test_channel, test_gemini_api_client, test_youtube_data_api_client};
+    use crate::youtube::youtube_channel::youtube_channel_repository::MockYoutubeChannelRepository;
+    use crate::youtube::youtube_channel::youtube_channel_model::VideoFormat;
+    use crate::youtube::youtube_video::youtube_video_repository::MockYoutubeVideoRepository;
+    
+    fn video(video_id: &str, published_at: &str, view_count: i64) -> YoutubeChannelVideo {
+        YoutubeChannelVideo {
+            id: 0,
+            youtube_channel_id: 7,
+            video_id: video_id.to_string(),
+            title: video_id.to_string(),
+            published_at: published_at.parse().unwrap(),
+            duration: 30,
+            view_count,
+            like_count: 0,
+            comment_count: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+    
+    fn keyword_month(month: &str, keyword_text: &str, view_count: i64) -> YoutubeChannelKeywordMonth {
+        YoutubeChannelKeywordMonth {
+            id: 0,
+            youtube_channel_id: 1,
+            month: month.parse().unwrap(),
+            keyword_text: keyword_text.to_string(),
+            view_count,
+        }
+    }
+    
+    #[test]
+    fn build_keyword_timeline_marks_entered_and_exited_keywords() {
+        let keyword_months = vec![
+            keyword_month("2026-01-01", "먹방", 500),
+            keyword_month("2026-01-01", "브이로그", 100),
+            keyword_month("2026-03-01", "먹방", 300),
+            keyword_month("2026-03-01", "캠핑", 700),
+        ];
+        
+        let timeline = build_keyword_timeline(&keyword_months);
+        
+        assert_eq!(timeline.len(), 2);
+        assert_eq!(timeline[0].month, "2026-01");
+        assert!(timeline[0].entered_keywords.is_empty());
+        assert_eq!(timeline[1].month, "2026-03");
+        assert_eq!(timeline[1].total_view_count, 1000);
+        assert_eq!(timeline[1].keywords[0].keyword_text, "캠핑");
+        assert_eq!(timeline[1].entered_keywords, vec!["캠핑"]);
+        assert_eq!(timeline[1].exited_keywords, vec!["브이로그"]);
+    }
+    
+    #[test]
+    fn channel_analysis_result_sums_only_new_uploads() {
+        // 2월 28일 16시(UTC)는 KST 기준 3월 1일
+        let video_keywords = vec![
+            (video("a", "2026-02-10T00:00:00Z", 100), vec!["먹방".to_string(), "캠핑".to_string()]),
+            (video("b", "2026-02-28T16:00:00Z", 50), vec!["먹방".to_string()]),
+            // 키워드가 없는 영상도 영상으로는 저장
+            (video("c", "2026-03-02T00:00:00Z", 30), Vec::new()),
+        ];
+        
+        let analysis = channel_analysis_result(7, &video_keywords, Utc::now());
+        
+        let mut keywords: Vec<(String, i64)> = analysis.keywords
+            .iter()
+            .map(|keyword| (keyword.keyword_text.clone(), keyword.view_count))
+            .collect();
+        keywords.sort();
+        assert_eq!(keywords, vec![("먹방".to_string(), 150), ("캠핑".to_string(), 100)]);
+        
+        let mut keyword_months: Vec<(String, String, i64)> = analysis.keyword_months
+            .iter()
+            .map(|keyword_month| (keyword_month.month.to_string(), keyword_month.keyword_text.clone(), keyword_month.view_count))
+            .collect();
+        keyword_months.sort();
+        assert_eq!(keyword_months, vec![
+            ("2026-02-01".to_string(), "먹방".to_string(), 100),
+            ("2026-02-01".to_string(), "캠핑".to_string(), 100),
+            ("2026-03-01".to_string(), "먹방".to_string(), 50),
+        ]);
+        assert_eq!(analysis.videos.len(), 3);
+        assert_eq!(analysis.video_keywords.len(), 3);
+        assert!(analysis.keywords.iter().all(|keyword| keyword.youtube_channel_id == 7));
+    }
+    
+    #[test]
+    fn keep_latest_videos_drops_oldest_stored_videos() {
+        let new_video_keywords = vec![(video("new", "2026-03-01T00:00:00Z", 10), vec!["캠핑".to_string()])];
+        let stored_videos = vec![
+            video("oldest", "2026-01-01T00:00:00Z", 100),
+            video("older", "2026-02-01T00:00:00Z", 50),
+        ];
+        let stored_video_keywords = vec![
+            YoutubeChannelVideoKeyword { id: 0, youtube_channel_id: 7, video_id: "oldest".to_string(), keyword_text: "먹방".to_string() },
+            YoutubeChannelVideoKeyword { id: 0, youtube_channel_id: 7, video_id: "older".to_string(), keyword_text: "먹방".to_string() },
+        ];
+        
+        let video_keywords = keep_latest_videos(new_video_keywords, stored_videos, stored_video_keywords, 2);
+        
+        let video_ids: Vec<&str> = video_keywords.iter().map(|(video, _)| video.video_id.as_str()).collect();
+        assert_eq!(video_ids, vec!["new", "older"]);
+        let analysis = channel_analysis_result(7, &video_keywords, Utc::now());
+        let mut keywords: Vec<(String, i64)> = analysis.keywords
+            .iter()
+            .map(|keyword| (keyword.keyword_text.clone(), keyword.view_count))
+            .collect();
+        keywords.sort();
+        assert_eq!(keywords, vec![("먹방".to_string(), 50), ("캠핑".to_string(), 10)]);
+    }
+    
+    #[test]
+    fn diff_top_keywords_compares_with_snapshot() {
+        let keyword = |keyword_text: &str, view_count: i64| YoutubeChannelKeyword {
+            id: 0,
+            youtube_channel_id: 1,
+            keyword_text: keyword_text.to_string(),
+            view_count,
+        };
+        let snapshot = |keyword_text: &str, view_count: i64| YoutubeChannelKeywordSnapshot {
+            id: 0,
+            youtube_channel_id: 1,
+            keyword_text: keyword_text.to_string(),
+            view_count,
+            snapshot_at: Utc::now(),
+        };
+        let current = vec![keyword("먹방", 900), keyword("캠핑", 500)];
+        
+        let (entered, exited) = diff_top_keywords(&current, &[snapshot("먹방", 600), snapshot("브이로그", 400)]);
+        assert_eq!(entered.iter().map(|keyword| keyword.keyword_text.as_str()).collect::<Vec<_>>(), vec!["캠핑"]);
+        assert_eq!(exited.iter().map(|keyword| keyword.keyword_text.as_str()).collect::<Vec<_>>(), vec!["브이로그"]);
+        
+        let (entered, exited) = diff_top_keywords(&current, &[]);
+        assert!(entered.is_empty() && exited.is_empty());
+    }
+    
+    #[test]
+    fn check_stored_analysis_options_rejects_conflicting_options() {
+        let stored = ChannelAnalysisOptions {
+            max_videos: Some(50),
+            published_after: "2026-01-01T00:00:00Z".parse().ok(),
+            published_before: None,
+            video_format: VideoFormat::Shorts,
+        };
+        
+        assert!(check_stored_analysis_options(&ChannelAnalysisOptions::default(), &stored).is_ok());
+        assert!(check_stored_analysis_options(&ChannelAnalysisOptions {
+            published_after: "2026-01-01T00:00:00.250Z".parse().ok(),
+            ..stored.clone()
+        }, &stored).is_ok());
+        assert!(check_stored_analysis_options(&ChannelAnalysisOptions {
+            video_format: VideoFormat::LongForm,
+            ..stored.clone()
+        }, &stored).is_err());
+        assert!(check_stored_analysis_options(&ChannelAnalysisOptions {
+            max_videos: Some(10),
+            ..ChannelAnalysisOptions::default()
+        }, &stored).is_err());
+    }
+    
+    fn service(youtube_channel_repository: MockYoutubeChannelRepository) -> YoutubeChannelService {
+        YoutubeChannelService::new(
+            Arc::new(youtube_channel_repository),
+            Arc::new(MockYoutubeVideoRepository::new()),
+            test_youtube_data_api_client(),
+            test_gemini_api_client(),
+            10,
+        )
+    }
+    
+    #[tokio::test]
+    async fn request_over_quota_releases_reanalysis() {
+        let mut youtube_channel_repository = MockYoutubeChannelRepository::new();
+        youtube_channel_repository.expect_find_channel_by_handle()
+            .returning(|_| Ok(Some(YoutubeChannel { id: 7, ..test_channel("@first") })));
+        youtube_channel_repository.expect_try_start_reanalysis().returning(|_| Ok(true));
+        youtube_channel_repository.expect_reserve_analysis_request().returning(|_, _, _, _| Ok(None));
+        youtube_channel_repository.expect_finish_reanalysis()
+            .withf(|id, analyzed_at| *id == 7 && analyzed_at.is_none())
+            .times(1)
+            .returning(|_, _| Ok(()));
+        
+        let result = service(youtube_channel_repository)
+            .request_analyze_youtube_channel_keywords("user", "@first".to_string(), ChannelAnalysisOptions::default())
+            .await;
+        
+        assert!(matches!(result, Err(AppError::YoutubeChannel(YoutubeChannelError::AnalysisQuotaExceeded(10)))));
+    }
+    
+    #[tokio::test]
+    async fn failed_delete_releases_reanalysis() {
+        let mut youtube_channel_repository = MockYoutubeChannelRepository::new();
+        youtube_channel_repository.expect_find_channel_by_handle()
+            .returning(|_| Ok(Some(YoutubeChannel { id: 7, ..test_channel("@first") })));
+        youtube_channel_repository.expect_try_start_reanalysis().returning(|_| Ok(true));
+        youtube_channel_repository.expect_delete_channel_by_handle().returning(|_| Err(sqlx::Error::PoolTimedOut));
+        youtube_channel_repository.expect_finish_reanalysis()
+            .withf(|id, analyzed_at| *id == 7 && analyzed_at.is_none())
+            .times(1)
+            .returning(|_, _| Ok(()));
+        
+        let result = service(youtube_channel_repository)
+            .delete_youtube_channel("@first".to_string())
+            .await;
+        
+        assert!(matches!(result, Err(AppError::Database(_))));
+    }
+    
+    #[tokio::test]
+    async fn request_with_conflicting_options_is_not_charged() {
+        let mut youtube_channel_repository = MockYoutubeChannelRepository::new();
+        youtube_channel_repository.expect_find_channel_by_handle()
+            .returning(|_| Ok(Some(test_channel("@first"))));
+        youtube_channel_repository.expect_try_start_reanalysis().never();
+        youtube_channel_repository.expect_reserve_analysis_request().never();
+        let analysis_options = ChannelAnalysisOptions {
+            video_format: VideoFormat::Shorts,
+            ..ChannelAnalysisOptions::default()
+        };
+        
+        let result = service(youtube_channel_repository)
+            .request_analyze_youtube_channel_keywords("user", "@first".to_string(), analysis_options)
+            .await;
+        
+        assert!(matches!(result, Err(AppError::YoutubeChannel(YoutubeChannelError::InvalidAnalysisOptions(_)))));
+    }
+    
+    #[tokio::test]
+    async fn timeline_flags_channels_analyzed_before_keyword_months() {
+        let mut youtube_channel_repository = MockYoutubeChannelRepository::new();
+        youtube_channel_repository.expect_find_channel_by_handle()
+            .returning(|_| Ok(Some(YoutubeChannel { keyword_months_complete: false, ..test_channel("@first") })));
+        youtube_channel_repository.expect_find_keyword_months_by_channel_id().returning(|_| Ok(Vec::new()));
+        youtube_channel_repository.expect_count_keywords_by_channel_id().returning(|_| Ok(12));
+        
+        let timeline = service(youtube_channel_repository)
+            .get_youtube_channel_keyword_timeline("@first")
+            .await
+            .unwrap();
+        
+        assert!(timeline.months.is_empty());
+        assert!(timeline.requires_full_reanalysis);
+    }
+    
+    #[tokio::test]
+    async fn timeline_flags_partial_keyword_months_until_full_reanalysis() {
+        let mut youtube_channel_repository = MockYoutubeChannelRepository::new();
+        youtube_channel_repository.expect_find_channel_by_handle()
+            .returning(|_| Ok(Some(YoutubeChannel { keyword_months_complete: false, ..test_channel("@first") })));
+        youtube_channel_repository.expect_find_keyword_months_by_channel_id()
+            .returning(|_| Ok(vec![keyword_month("2026-03-01", "캠핑", 700)]));
+        youtube_channel_repository.expect_count_keywords_by_channel_id().returning(|_| Ok(12));
+        
+        let timeline = service(youtube_channel_repository)
+            .get_youtube_channel_keyword_timeline("@first")
+            .await
+            .unwrap();
+        
+        assert_eq!(timeline.months.len(), 1);
+        assert!(timeline.requires_full_reanalysis);
+    }
+    
+    #[tokio::test]
+    async fn benchmark_without_stored_videos_is_rejected() {
+        let mut youtube_channel_repository = MockYoutubeChannelRepository::new();
+        youtube_channel_repository.expect_find_channel_by_handle()
+            .returning(|_| Ok(Some(test_channel("@first"))));
+        youtube_channel_repository.expect_find_videos_by_channel_id().returning(|_| Ok(Vec::new()));
+        
+        let result = service(youtube_channel_repository)
+            .get_youtube_channel_benchmark("@first")
+            .await;
+        
+        assert!(matches!(result, Err(AppError::YoutubeChannel(YoutubeChannelError::BenchmarkDataUnavailable(_)))));
+    }
 }
\ No newline at end of file
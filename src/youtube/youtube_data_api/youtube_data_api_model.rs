@@ -1,5 +1,9 @@
+use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 
+// 프로젝트 기본 일일 할당량
+pub const YOUTUBE_DAILY_QUOTA_UNITS: i64 = 10_000;
+
 // 'search.list' API 구조체
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -172,6 +176,59 @@ pub struct PlaylistItem {
 #[serde(rename_all = "camelCase")]
 pub struct PlaylistItemContentDetails {
     pub video_id: String,
+    #[serde(default)]
+    pub video_published_at: Option<String>,
+}
+
+// 'commentThreads.list' API 구조체
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThreadListResponse {
+    pub items: Vec<CommentThreadItem>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentThreadItem {
+    pub id: String,
+    pub snippet: CommentThreadSnippet,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentThreadSnippet {
+    pub video_id: String,
+    pub top_level_comment: Comment,
+    #[serde(default)]
+    pub total_reply_count: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    pub snippet: CommentSnippet,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentSnippet {
+    pub text_display: String,
+    #[serde(default)]
+    pub author_display_name: String,
+    #[serde(default)]
+    pub like_count: i64,
+    pub published_at: String,
+}
+
+// 할당량 사용량
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct YoutubeQuotaUsage {
+    pub api_key_label: String,
+    pub quota_date: NaiveDate,
+    pub used_units: i64,
+    pub daily_limit: i64,
+    pub remaining_units: i64,
 }
 
 impl ContentDetails {
@@ -1,21 +1,58 @@
+use std::sync::Arc;
 use chrono::{DateTime, Utc};
+use chrono_tz::America::Los_Angeles;
+use crate::redis::redis_repository::RedisRepository;
 use crate::youtube::youtube_data_api::youtube_data_api_error::YoutubeDataAPIError;
-use crate::youtube::youtube_data_api::youtube_data_api_model::{ChannelItem, ChannelListResponse, ChannelSearchResponse, PlaylistItemListResponse, SearchListResponse, VideoItem, VideoListResponse};
+use crate::youtube::youtube_data_api::youtube_data_api_model::{ChannelItem, CommentThreadItem, CommentThreadListResponse, ChannelListResponse, ChannelSearchResponse, PlaylistItem, PlaylistItemListResponse, SearchListResponse, VideoItem, VideoListResponse, YoutubeQuotaUsage, YOUTUBE_DAILY_QUOTA_UNITS};
+
+// API별 할당량 소모 단위
+const SEARCH_QUOTA_COST: i64 = 100;
+const LIST_QUOTA_COST: i64 = 1;
 
 #[derive(Clone)]
 pub struct YoutubeDataAPIClient {
     api_key: String,
+    quota_label: String,
     http_client: reqwest::Client,
+    redis_repository: Arc<RedisRepository>,
 }
 
 impl YoutubeDataAPIClient {
-    pub fn new(api_key: &str) -> Self {
+    pub fn new(api_key: &str, quota_label: &str, redis_repository: Arc<RedisRepository>) -> Self {
         Self {
             api_key: api_key.to_string(),
+            quota_label: quota_label.to_string(),
             http_client: reqwest::Client::new(),
+            redis_repository,
         }
     }
-
+    
+    pub async fn get_quota_usage(&self) -> Result<YoutubeQuotaUsage, anyhow::Error> {
+        // YouTube 할당량은 태평양 시간 자정에 초기화
+        let quota_date = Utc::now().with_timezone(&Los_Angeles).date_naive();
+        let used_units = self.redis_repository
+            .get_youtube_quota_usage(&self.quota_label, quota_date)
+            .await?;
+        
+        Ok(YoutubeQuotaUsage {
+            api_key_label: self.quota_label.clone(),
+            quota_date,
+            used_units,
+            daily_limit: YOUTUBE_DAILY_QUOTA_UNITS,
+            remaining_units: (YOUTUBE_DAILY_QUOTA_UNITS - used_units).max(0),
+        })
+    }
+    
+    async fn record_quota_usage(&self, units: i64) {
+        let quota_date = Utc::now().with_timezone(&Los_Angeles).date_naive();
+        if let Err(e) = self.redis_repository
+            .increment_youtube_quota_usage(&self.quota_label, quota_date, units)
+            .await
+        {
+            eprintln!("[Quota] 할당량 기록 실패: {}", e);
+        }
+    }
+    
     pub async fn search_popular_shorts_ids(
         &self,
         query: &str,
@@ -40,11 +77,12 @@ impl YoutubeDataAPIClient {
         query_params.push(("regionCode", "KR".to_string()));
         query_params.push(("relevanceLanguage", "ko".to_string()));
         query_params.push(("key", self.api_key.clone()));
-
+        
         if let Some(token) = page_token {
             query_params.push(("pageToken", token.to_string()));
         }
-
+        
+        self.record_quota_usage(SEARCH_QUOTA_COST).await;
         let response = self.http_client
             .get(url)
             .query(&query_params)
@@ -55,7 +93,7 @@ impl YoutubeDataAPIClient {
             .await?;
         Ok(response)
     }
-
+    
     pub async fn get_videos_details(
         &self,
         video_ids: &[String],
@@ -63,7 +101,7 @@ impl YoutubeDataAPIClient {
         if video_ids.is_empty() {
             return Ok(vec![]);
         }
-
+        
         let url = "https://www.googleapis.com/youtube/v3/videos";
         let ids_str = video_ids.join(",");
         
@@ -71,7 +109,8 @@ impl YoutubeDataAPIClient {
         query_params.push(("part", "snippet,contentDetails,statistics,player,topicDetails".to_string()));
         query_params.push(("id", ids_str));
         query_params.push(("key", self.api_key.clone()));
-
+        
+        self.record_quota_usage(LIST_QUOTA_COST).await;
         let response = self.http_client
             .get(url)
             .query(&query_params)
@@ -79,7 +118,7 @@ impl YoutubeDataAPIClient {
             .await?
             .json::<VideoListResponse>()
             .await?;
-
+        
         Ok(response.items)
     }
     
@@ -93,6 +132,7 @@ impl YoutubeDataAPIClient {
         query_params.push(("maxResults", "1".to_string()));
         query_params.push(("key", self.api_key.clone()));
         println!("시작");
+        self.record_quota_usage(SEARCH_QUOTA_COST).await;
         let response = self.http_client
             .get(url)
             .query(&query_params)
@@ -124,6 +164,7 @@ impl YoutubeDataAPIClient {
         query_params.push(("forHandle", channel_handle.to_string()));
         query_params.push(("key", self.api_key.clone()));
         
+        self.record_quota_usage(LIST_QUOTA_COST).await;
         let response = self.http_client
             .get(url)
             .query(&query_params)
@@ -140,43 +181,192 @@ impl YoutubeDataAPIClient {
         Ok(channel_item)
     }
     
+    pub async fn get_channels_details(&self, channel_ids: &[String]) -> Result<Vec<ChannelItem>, YoutubeDataAPIError> {
+        if channel_ids.is_empty() {
+            return Ok(vec![]);
+        }
+        
+        let url = "https://www.googleapis.com/youtube/v3/channels";
+        let mut query_params: Vec<(&str, String)> = Vec::new();
+        query_params.push(("part", "snippet,contentDetails,statistics".to_string()));
+        query_params.push(("id", channel_ids.join(",")));
+        query_params.push(("key", self.api_key.clone()));
+        
+        self.record_quota_usage(LIST_QUOTA_COST).await;
+        let response = self.http_client
+            .get(url)
+            .query(&query_params)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<ChannelListResponse>()
+            .await?;
+        
+        Ok(response.items)
+    }
+    
     pub async fn get_video_ids_from_playlist(&self, playlist_id: &str) -> Result<Vec<String>, YoutubeDataAPIError> {
-        let url = "https://www.googleapis.com/youtube/v3/playlistItems";
+        self.fetch_playlist_video_ids(playlist_id, None).await
+    }
+    
+    // 업로드 재생목록은 최신순이므로 기준 시각 이전 영상이 나오면 다음 페이지는 조회하지 않음
+    pub async fn get_video_ids_from_playlist_published_after(
+        &self,
+        playlist_id: &str,
+        published_after: DateTime<Utc>,
+    ) -> Result<Vec<String>, YoutubeDataAPIError> {
+        self.fetch_playlist_video_ids(playlist_id, Some(published_after)).await
+    }
+    
+    async fn fetch_playlist_video_ids(
+        &self,
+        playlist_id: &str,
+        published_after: Option<DateTime<Utc>>,
+    ) -> Result<Vec<String>, YoutubeDataAPIError> {
         let mut video_ids = Vec::new();
         let mut next_page_token: Option<String> = None;
         
         loop {
-            let mut query_params: Vec<(&str, String)> = Vec::new();
-            query_params.push(("part", "contentDetails".to_string()));
-            query_params.push(("playlistId", playlist_id.to_string()));
-            query_params.push(("maxResults", "50".to_string()));
-            query_params.push(("key", self.api_key.clone()));
-            
-            if let Some(token) = &next_page_token {
-                query_params.push(("pageToken", token.to_string()));
-            }
+            let response = self.get_playlist_items_page(playlist_id, next_page_token.take()).await?;
             
-            let response = self.http_client
-                .get(url)
-                .query(&query_params)
-                .send()
-                .await?
-                .error_for_status()?
-                .json::<PlaylistItemListResponse>()
-                .await?;
-            
-            let ids = response.items
-                .into_iter()
-                .map(|item| item.content_details.video_id);
+            let (ids, reached_older) = select_playlist_video_ids(response.items, published_after, None);
             video_ids.extend(ids);
             
-            if let Some(token) = response.next_page_token {
-                next_page_token = Some(token);
-            } else {
-                break;
+            match response.next_page_token {
+                Some(token) if !reached_older => next_page_token = Some(token),
+                _ => break,
             }
         }
         
         Ok(video_ids)
     }
+    
+    // 관련성 순 상위 댓글 (댓글이 막힌 영상은 403 응답)
+    pub async fn get_top_comment_threads(
+        &self,
+        video_id: &str,
+        max_results: u32,
+    ) -> Result<Vec<CommentThreadItem>, YoutubeDataAPIError> {
+        let url = "https://www.googleapis.com/youtube/v3/commentThreads";
+        let mut query_params: Vec<(&str, String)> = Vec::new();
+        query_params.push(("part", "snippet".to_string()));
+        query_params.push(("videoId", video_id.to_string()));
+        query_params.push(("order", "relevance".to_string()));
+        query_params.push(("textFormat", "plainText".to_string()));
+        query_params.push(("maxResults", max_results.min(100).to_string()));
+        query_params.push(("key", self.api_key.clone()));
+        
+        self.record_quota_usage(LIST_QUOTA_COST).await;
+        let response = self.http_client
+            .get(url)
+            .query(&query_params)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<CommentThreadListResponse>()
+            .await?;
+        
+        Ok(response.items)
+    }
+    
+    pub async fn get_playlist_items_page(
+        &self,
+        playlist_id: &str,
+        page_token: Option<String>,
+    ) -> Result<PlaylistItemListResponse, YoutubeDataAPIError> {
+        let url = "https://www.googleapis.com/youtube/v3/playlistItems";
+        let mut query_params: Vec<(&str, String)> = Vec::new();
+        query_params.push(("part", "contentDetails".to_string()));
+        query_params.push(("playlistId", playlist_id.to_string()));
+        query_params.push(("maxResults", "50".to_string()));
+        query_params.push(("key", self.api_key.clone()));
+        
+        if let Some(token) = page_token {
+            query_params.push(("pageToken", token));
+        }
+        
+        self.record_quota_usage(LIST_QUOTA_COST).await;
+        let response = self.http_client
+            .get(url)
+            .query(&query_params)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<PlaylistItemListResponse>()
+            .await?;
+        
+        Ok(response)
+    }
+}
+
+// 재생목록 한 페이지에서 게시 시각이 (published_after, published_before) 범위인 영상 ID 만 고름
+// published_after 이전 영상이 나왔는지 함께 반환해 최신순 재생목록의 다음 페이지 조회 여부를 정함
+pub fn select_playlist_video_ids(
+    items: Vec<PlaylistItem>,
+    published_after: Option<DateTime<Utc>>,
+    published_before: Option<DateTime<Utc>>,
+) -> (Vec<String>, bool) {
+    let mut reached_older = false;
+    let mut video_ids = Vec::new();
+    for item in items {
+        let published_at = item.content_details.video_published_at
+            .as_deref()
+            .and_then(|published_at| published_at.parse::<DateTime<Utc>>().ok());
+        if let (Some(after), Some(published_at)) = (published_after, published_at) {
+            if published_at <= after {
+                reached_older = true;
+                continue;
+            }
+        }
+        if let (Some(before), Some(published_at)) = (published_before, published_at) {
+            if published_at >= before {
+                continue;
+            }
+        }
+        video_ids.push(item.content_details.video_id);
+    }
+    
+    (video_ids, reached_older)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::youtube::youtube_data_api::youtube_data_api_model::PlaylistItemContentDetails;
+    
+    fn item(video_id: &str, video_published_at: Option<&str>) -> PlaylistItem {
+        PlaylistItem {
+            content_details: PlaylistItemContentDetails {
+                video_id: video_id.to_string(),
+                video_published_at: video_published_at.map(str::to_string),
+            },
+        }
+    }
+    
+    #[test]
+    fn select_playlist_video_ids_keeps_only_window() {
+        let items = vec![
+            item("newest", Some("2026-03-10T00:00:00Z")),
+            item("in_window", Some("2026-02-10T00:00:00Z")),
+            item("unknown", None),
+            item("older", Some("2026-01-01T00:00:00Z")),
+        ];
+        let published_after = "2026-01-15T00:00:00Z".parse().ok();
+        let published_before = "2026-03-01T00:00:00Z".parse().ok();
+        
+        let (video_ids, reached_older) = select_playlist_video_ids(items, published_after, published_before);
+        
+        assert_eq!(video_ids, vec!["in_window".to_string(), "unknown".to_string()]);
+        assert!(reached_older);
+    }
+    
+    #[test]
+    fn select_playlist_video_ids_without_window_keeps_all() {
+        let items = vec![item("a", Some("2026-03-10T00:00:00Z")), item("b", None)];
+        
+        let (video_ids, reached_older) = select_playlist_video_ids(items, None, None);
+        
+        assert_eq!(video_ids, vec!["a".to_string(), "b".to_string()]);
+        assert!(!reached_older);
+    }
 }
\ No newline at end of file
@@ -3,3 +3,12 @@ pub mod youtube_video_repository;
 pub mod youtube_video_service;
 pub mod youtube_video_model;
 pub mod youtube_video_scheduler;
+pub mod youtube_raw_video_archive_service;
+pub mod youtube_video_error;
+pub mod youtube_video_analytics;
+pub mod youtube_video_comment_repository;
+pub mod youtube_video_comment_service;
+pub mod youtube_keyword_idea_service;
+pub mod youtube_keyword_alert;
+pub mod youtube_keyword_watchlist_repository;
+pub mod youtube_keyword_watchlist_service;
\ No newline at end of file
@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+use crate::youtube::youtube_video::youtube_video_model::{KeywordAlertType, NewKeywordAlert, YoutubeKeywordRanking, YoutubeKeywordWatchlist};
+
+// 직전 순위표와 비교해 관심 키워드별로 걸린 알림 조건을 모두 반환
+// 순위표에 새로 들어온 키워드는 상승 폭이 없으므로 top N 조건으로만 잡힌다
+pub fn evaluate_watchlist_alerts(
+    watchlists: &[YoutubeKeywordWatchlist],
+    today_rankings: &[YoutubeKeywordRanking],
+    previous_rankings: &[YoutubeKeywordRanking],
+) -> Vec<NewKeywordAlert> {
+    let today_map: HashMap<&str, i32> = today_rankings
+        .iter()
+        .map(|ranking| (ranking.keyword_text.as_str(), ranking.ranking))
+        .collect();
+    let previous_map: HashMap<&str, i32> = previous_rankings
+        .iter()
+        .map(|ranking| (ranking.keyword_text.as_str(), ranking.ranking))
+        .collect();
+    
+    let mut alerts = Vec::new();
+    for watchlist in watchlists {
+        let current = today_map.get(watchlist.keyword_text.as_str()).copied();
+        let previous = previous_map.get(watchlist.keyword_text.as_str()).copied();
+        let mut push = |alert_type: KeywordAlertType| alerts.push(NewKeywordAlert {
+            watchlist_id: watchlist.id,
+            user_public_id: watchlist.user_public_id.clone(),
+            keyword_text: watchlist.keyword_text.clone(),
+            alert_type,
+            previous_ranking: previous,
+            current_ranking: current,
+        });
+        
+        if let (Some(top_n), Some(current)) = (watchlist.enter_top_n, current) {
+            if current <= top_n && previous.map_or(true, |previous| previous > top_n) {
+                push(KeywordAlertType::EnterTopN);
+            }
+        }
+        if let (Some(threshold), Some(current), Some(previous)) = (watchlist.climb_threshold, current, previous) {
+            if previous - current > threshold {
+                push(KeywordAlertType::Climb);
+            }
+        }
+        if watchlist.alert_on_drop_off && previous.is_some() && current.is_none() {
+            push(KeywordAlertType::DropOff);
+        }
+    }
+    
+    alerts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, Utc};
+    use crate::common::test_support::test_keyword_ranking;
+    
+    fn watchlist(id: i64, keyword_text: &str, enter_top_n: Option<i32>, climb_threshold: Option<i32>, alert_on_drop_off: bool) -> YoutubeKeywordWatchlist {
+        YoutubeKeywordWatchlist {
+            id,
+            user_public_id: "user".to_string(),
+            keyword_text: keyword_text.to_string(),
+            enter_top_n,
+            climb_threshold,
+            alert_on_drop_off,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+    
+    #[test]
+    fn evaluate_watchlist_alerts_matches_each_rule() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let previous = vec![
+            test_keyword_ranking(date, 15, "먹방", 0),
+            test_keyword_ranking(date, 3, "브이로그", 0),
+            test_keyword_ranking(date, 8, "축구", 0),
+            test_keyword_ranking(date, 20, "편의점", 0),
+        ];
+        let today = vec![
+            test_keyword_ranking(date, 2, "먹방", 0),
+            test_keyword_ranking(date, 5, "브이로그", 0),
+            test_keyword_ranking(date, 1, "두바이 초콜릿", 0),
+            test_keyword_ranking(date, 18, "편의점", 0),
+        ];
+        let watchlists = vec![
+            watchlist(1, "먹방", Some(10), Some(10), false),
+            watchlist(2, "브이로그", Some(10), Some(1), true),
+            watchlist(3, "축구", Some(10), None, true),
+            watchlist(4, "두바이 초콜릿", Some(3), Some(1), false),
+            watchlist(5, "편의점", None, Some(2), false),
+        ];
+        
+        let alerts: Vec<(i64, KeywordAlertType)> = evaluate_watchlist_alerts(&watchlists, &today, &previous)
+            .into_iter()
+            .map(|alert| (alert.watchlist_id, alert.alert_type))
+            .collect();
+        
+        assert_eq!(alerts, vec![
+            (1, KeywordAlertType::EnterTopN),
+            (1, KeywordAlertType::Climb),
+            (3, KeywordAlertType::DropOff),
+            (4, KeywordAlertType::EnterTopN),
+        ]);
+    }
+}
\ No newline at end of file
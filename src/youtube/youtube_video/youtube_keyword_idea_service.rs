@@ -0,0 +1,227 @@
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{NaiveDate, TimeDelta, Utc};
+use chrono_tz::Asia::Seoul;
+use uuid::Uuid;
+use crate::errors::AppError;
+use crate::gemini::gemini_api_model::GeminiPromptIdeaVideoData;
+use crate::gemini::gemini_api_util::GeminiAPIClient;
+use crate::redis::redis_repository::RedisRepository;
+use crate::youtube::youtube_video::youtube_video_error::YoutubeVideoError;
+use crate::youtube::youtube_video::youtube_video_model::{ContentIdeaResponse, KeywordIdeaVideo, KeywordIdeasResponse};
+use crate::youtube::youtube_video::youtube_video_repository::YoutubeVideoRepository;
+
+// 순위 기간(최근 7일) 안의 조회수 상위 영상만 참고
+const IDEA_VIDEO_LIMIT: u32 = 10;
+const IDEA_DESCRIPTION_MAX_CHARS: usize = 300;
+// 캐시되지 않은 키워드의 기획안 생성(Gemini 호출)만 사용자별 하루 횟수 제한
+const IDEA_DAILY_LIMIT_PER_USER: i64 = 20;
+const IDEA_LOCK_TTL_MILLIS: u64 = 60_000;
+const IDEA_LOCK_WAIT_INTERVAL: Duration = Duration::from_millis(500);
+const IDEA_LOCK_WAIT_RETRIES: usize = 20;
+
+#[derive(Clone)]
+pub struct KeywordIdeaService {
+    youtube_video_repository: Arc<dyn YoutubeVideoRepository>,
+    gemini_api_client: Arc<GeminiAPIClient>,
+    redis_repository: Arc<RedisRepository>,
+}
+
+impl KeywordIdeaService {
+    pub fn new(
+        youtube_video_repository: Arc<dyn YoutubeVideoRepository>,
+        gemini_api_client: Arc<GeminiAPIClient>,
+        redis_repository: Arc<RedisRepository>,
+    ) -> Self {
+        Self {
+            youtube_video_repository,
+            gemini_api_client,
+            redis_repository,
+        }
+    }
+    
+    // 같은 날 같은 키워드는 Redis 에 저장된 기획안을 그대로 반환
+    // 동시에 들어온 같은 키워드 요청은 한 요청만 Gemini 를 호출하고 나머지는 저장된 결과를 기다림
+    pub async fn get_keyword_ideas(&self, user_public_id: &str, keyword_text: String) -> Result<KeywordIdeasResponse, AppError> {
+        let keyword_text = keyword_text.trim().to_string();
+        let today = Utc::now().with_timezone(&Seoul).date_naive();
+        
+        if let Some(response) = self.find_cached_ideas(&keyword_text, today).await {
+            return Ok(response);
+        }
+        
+        let videos = self.youtube_video_repository
+            .find_top_videos_by_keyword(&keyword_text, today - TimeDelta::days(7), today + TimeDelta::days(1), IDEA_VIDEO_LIMIT)
+            .await?;
+        if videos.is_empty() {
+            return Err(YoutubeVideoError::KeywordNotFound(keyword_text))?;
+        }
+        
+        // Redis 장애 시에는 락 없이 생성
+        let lock_name = format!("keyword_ideas:{}:{}", today, keyword_text);
+        let lock_value = Uuid::new_v4().to_string();
+        let locked = match self.redis_repository.acquire_lock(&lock_name, &lock_value, IDEA_LOCK_TTL_MILLIS).await {
+            Ok(locked) => locked,
+            Err(e) => {
+                eprintln!("[Ideas] '{}' 락 획득 실패: {}", keyword_text, e);
+                true
+            }
+        };
+        if !locked {
+            return self.wait_for_cached_ideas(keyword_text, today).await;
+        }
+        
+        let result = self.generate_keyword_ideas(user_public_id, keyword_text.clone(), today, &videos).await;
+        if let Err(e) = self.redis_repository.release_lock(&lock_name, &lock_value).await {
+            eprintln!("[Ideas] '{}' 락 해제 실패: {}", keyword_text, e);
+        }
+        
+        result
+    }
+    
+    async fn generate_keyword_ideas(
+        &self,
+        user_public_id: &str,
+        keyword_text: String,
+        today: NaiveDate,
+        videos: &[KeywordIdeaVideo],
+    ) -> Result<KeywordIdeasResponse, AppError> {
+        // 락을 잡기 전에 다른 요청이 생성을 마쳤을 수 있음
+        if let Some(response) = self.find_cached_ideas(&keyword_text, today).await {
+            return Ok(response);
+        }
+        
+        // 캐시된 결과는 제한 없이 조회하고 Gemini 호출만 사용자별 하루 횟수를 제한
+        // 한도 확인과 기록을 한 번에 하도록 먼저 세고, 기획안을 받지 못하면 되돌림
+        let counted = match self.redis_repository.increment_keyword_idea_requests(user_public_id, today).await {
+            Ok(used_count) => {
+                check_idea_rate_limit(used_count)?;
+                true
+            }
+            Err(e) => {
+                eprintln!("[Ideas] '{}' 요청 횟수 기록 실패: {}", user_public_id, e);
+                false
+            }
+        };
+        
+        let prompt_data: Vec<GeminiPromptIdeaVideoData> = videos
+            .iter()
+            .map(|video| GeminiPromptIdeaVideoData {
+                title: &video.title,
+                description: match video.description.char_indices().nth(IDEA_DESCRIPTION_MAX_CHARS) {
+                    Some((index, _)) => &video.description[..index],
+                    None => &video.description,
+                },
+                view_count: video.view_count,
+            })
+            .collect();
+        let ideas = self.gemini_api_client
+            .generate_content_ideas_with_gemini(&keyword_text, &prompt_data)
+            .await
+            .map_err(|e| YoutubeVideoError::IdeaGenerationFailed(e.to_string()));
+        let ideas = match ideas {
+            Ok(ideas) if !ideas.is_empty() => ideas,
+            result => {
+                if counted {
+                    self.refund_idea_request(user_public_id, today).await;
+                }
+                result?
+            }
+        };
+        
+        let response = KeywordIdeasResponse {
+            keyword_text,
+            generated_date: today,
+            reference_video_count: videos.len(),
+            ideas: ideas.into_iter().map(ContentIdeaResponse::from).collect(),
+        };
+        
+        // 빈 결과를 캐시하면 그날 하루 동안 다시 생성할 수 없으므로 저장하지 않음
+        if response.ideas.is_empty() {
+            return Ok(response);
+        }
+        match serde_json::to_string(&response) {
+            Ok(value) => {
+                if let Err(e) = self.redis_repository.set_keyword_ideas(&response.keyword_text, today, &value).await {
+                    eprintln!("[Ideas] '{}' 캐시 저장 실패: {}", response.keyword_text, e);
+                }
+            }
+            Err(e) => eprintln!("[Ideas] '{}' 캐시 직렬화 실패: {}", response.keyword_text, e),
+        }
+        
+        Ok(response)
+    }
+    
+    async fn refund_idea_request(&self, user_public_id: &str, today: NaiveDate) {
+        if let Err(e) = self.redis_repository.decrement_keyword_idea_requests(user_public_id, today).await {
+            eprintln!("[Ideas] '{}' 요청 횟수 환불 실패: {}", user_public_id, e);
+        }
+    }
+    
+    async fn find_cached_ideas(&self, keyword_text: &str, today: NaiveDate) -> Option<KeywordIdeasResponse> {
+        match self.redis_repository.get_keyword_ideas(keyword_text, today).await {
+            Ok(Some(cached)) => match serde_json::from_str::<KeywordIdeasResponse>(&cached) {
+                Ok(response) => return Some(response),
+                Err(e) => eprintln!("[Ideas] '{}' 캐시 파싱 실패: {}", keyword_text, e),
+            },
+            Ok(None) => {}
+            Err(e) => eprintln!("[Ideas] '{}' 캐시 조회 실패: {}", keyword_text, e),
+        }
+        
+        None
+    }
+    
+    async fn wait_for_cached_ideas(&self, keyword_text: String, today: NaiveDate) -> Result<KeywordIdeasResponse, AppError> {
+        for _ in 0..IDEA_LOCK_WAIT_RETRIES {
+            tokio::time::sleep(IDEA_LOCK_WAIT_INTERVAL).await;
+            if let Some(response) = self.find_cached_ideas(&keyword_text, today).await {
+                return Ok(response);
+            }
+        }
+        
+        Err(YoutubeVideoError::IdeaGenerationInProgress(keyword_text))?
+    }
+}
+
+fn check_idea_rate_limit(used_count: i64) -> Result<(), YoutubeVideoError> {
+    if used_count > IDEA_DAILY_LIMIT_PER_USER {
+        return Err(YoutubeVideoError::IdeaRateLimitExceeded(IDEA_DAILY_LIMIT_PER_USER));
+    }
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::{test_gemini_api_client, test_redis_repository};
+    use crate::youtube::youtube_video::youtube_video_repository::MockYoutubeVideoRepository;
+    
+    #[test]
+    fn check_idea_rate_limit_allows_up_to_daily_limit() {
+        assert!(check_idea_rate_limit(1).is_ok());
+        assert!(check_idea_rate_limit(IDEA_DAILY_LIMIT_PER_USER).is_ok());
+        assert!(matches!(
+            check_idea_rate_limit(IDEA_DAILY_LIMIT_PER_USER + 1),
+            Err(YoutubeVideoError::IdeaRateLimitExceeded(IDEA_DAILY_LIMIT_PER_USER))
+        ));
+    }
+    
+    #[tokio::test]
+    async fn get_keyword_ideas_rejects_keyword_without_videos_before_generation() {
+        let mut youtube_video_repository = MockYoutubeVideoRepository::new();
+        youtube_video_repository
+            .expect_find_top_videos_by_keyword()
+            .times(1)
+            .returning(|_, _, _, _| Ok(Vec::new()));
+        let service = KeywordIdeaService::new(
+            Arc::new(youtube_video_repository),
+            test_gemini_api_client(),
+            test_redis_repository(),
+        );
+        
+        let result = service.get_keyword_ideas("user", " 먹방 ".to_string()).await;
+        
+        assert!(matches!(result, Err(AppError::YoutubeVideo(YoutubeVideoError::KeywordNotFound(keyword))) if keyword == "먹방"));
+    }
+}
\ No newline at end of file
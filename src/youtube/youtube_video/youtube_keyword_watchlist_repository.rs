@@ -0,0 +1,341 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use sqlx::{Error, MySqlPool};
+use crate::youtube::youtube_video::youtube_video_model::{KeywordWatchlistRequest, NewKeywordAlert, YoutubeKeywordAlert, YoutubeKeywordWatchlist};
+
+#[automock]
+#[async_trait]
+pub trait YoutubeKeywordWatchlistRepository: Send + Sync {
+    async fn upsert_watchlist(&self, user_public_id: &str, request: &KeywordWatchlistRequest) -> Result<YoutubeKeywordWatchlist, Error>;
+    
+    async fn find_watchlists_by_user(&self, user_public_id: &str) -> Result<Vec<YoutubeKeywordWatchlist>, Error>;
+    
+    async fn delete_watchlist(&self, user_public_id: &str, watchlist_id: i64) -> Result<u64, Error>;
+    
+    async fn find_watchlists_by_keywords(&self, keyword_texts: &[String]) -> Result<Vec<YoutubeKeywordWatchlist>, Error>;
+    
+    async fn replace_keyword_alerts(&self, ranking_date: NaiveDate, alerts: &[NewKeywordAlert]) -> Result<(), Error>;
+    
+    async fn find_alerts_by_user(&self, user_public_id: &str, limit: u32, offset: u32) -> Result<Vec<YoutubeKeywordAlert>, Error>;
+    
+    async fn count_alerts_by_user(&self, user_public_id: &str) -> Result<i64, Error>;
+}
+
+#[derive(Clone)]
+pub struct YoutubeKeywordWatchlistSqlxRepository {
+    pub db_pool: MySqlPool,
+}
+
+impl YoutubeKeywordWatchlistSqlxRepository {
+    pub fn new(db_pool: MySqlPool) -> Self { Self { db_pool } }
+}
+
+#[async_trait]
+impl YoutubeKeywordWatchlistRepository for YoutubeKeywordWatchlistSqlxRepository {
+    // 같은 키워드를 다시 등록하면 알림 조건만 갱신
+    async fn upsert_watchlist(&self, user_public_id: &str, request: &KeywordWatchlistRequest) -> Result<YoutubeKeywordWatchlist, Error> {
+        sqlx::query!(
+            r#"
+                INSERT INTO youtube_keyword_watchlists (user_public_id, keyword_text, enter_top_n, climb_threshold, alert_on_drop_off)
+                VALUES (?, ?, ?, ?, ?)
+                ON DUPLICATE KEY UPDATE
+                    enter_top_n = VALUES(enter_top_n),
+                    climb_threshold = VALUES(climb_threshold),
+                    alert_on_drop_off = VALUES(alert_on_drop_off)
+            "#,
+            user_public_id,
+            request.keyword_text,
+            request.enter_top_n,
+            request.climb_threshold,
+            request.alert_on_drop_off
+        )
+            .execute(&self.db_pool)
+            .await?;
+        
+        let watchlist = sqlx::query_as!(
+            YoutubeKeywordWatchlist,
+            r#"
+                SELECT id, user_public_id, keyword_text, enter_top_n, climb_threshold,
+                       CAST(alert_on_drop_off AS UNSIGNED) AS "alert_on_drop_off: bool", created_at, updated_at
+                FROM youtube_keyword_watchlists
+                WHERE user_public_id = ? AND keyword_text = ?
+            "#,
+            user_public_id,
+            request.keyword_text
+        )
+            .fetch_one(&self.db_pool)
+            .await?;
+        
+        Ok(watchlist)
+    }
+    
+    async fn find_watchlists_by_user(&self, user_public_id: &str) -> Result<Vec<YoutubeKeywordWatchlist>, Error> {
+        let watchlists = sqlx::query_as!(
+            YoutubeKeywordWatchlist,
+            r#"
+                SELECT id, user_public_id, keyword_text, enter_top_n, climb_threshold,
+                       CAST(alert_on_drop_off AS UNSIGNED) AS "alert_on_drop_off: bool", created_at, updated_at
+                FROM youtube_keyword_watchlists
+                WHERE user_public_id = ?
+                ORDER BY created_at DESC, id DESC
+            "#,
+            user_public_id
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(watchlists)
+    }
+    
+    // 관심 키워드를 지우면 그 키워드로 받은 알림도 함께 삭제
+    async fn delete_watchlist(&self, user_public_id: &str, watchlist_id: i64) -> Result<u64, Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE
+                FROM youtube_keyword_alerts
+                WHERE watchlist_id = ? AND user_public_id = ?
+            "#,
+            watchlist_id,
+            user_public_id
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        let result = sqlx::query!(
+            r#"
+                DELETE
+                FROM youtube_keyword_watchlists
+                WHERE id = ? AND user_public_id = ?
+            "#,
+            watchlist_id,
+            user_public_id
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        tx.commit().await?;
+        
+        Ok(result.rows_affected())
+    }
+    
+    async fn find_watchlists_by_keywords(&self, keyword_texts: &[String]) -> Result<Vec<YoutubeKeywordWatchlist>, Error> {
+        if keyword_texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let query_str = format!(
+            "SELECT id, user_public_id, keyword_text, enter_top_n, climb_threshold, alert_on_drop_off, created_at, updated_at \
+             FROM youtube_keyword_watchlists \
+             WHERE keyword_text IN ({}) \
+             ORDER BY id",
+            vec!["?"; keyword_texts.len()].join(", ")
+        );
+        
+        let mut query = sqlx::query_as::<_, YoutubeKeywordWatchlist>(&query_str);
+        for keyword_text in keyword_texts {
+            query = query.bind(keyword_text);
+        }
+        
+        let watchlists = query.fetch_all(&self.db_pool).await?;
+        
+        Ok(watchlists)
+    }
+    
+    // 같은 날짜를 다시 평가하면 이전 평가 결과를 지우고 새 결과로 교체
+    async fn replace_keyword_alerts(&self, ranking_date: NaiveDate, alerts: &[NewKeywordAlert]) -> Result<(), Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE
+                FROM youtube_keyword_alerts
+                WHERE ranking_date = ?
+            "#,
+            ranking_date
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        if alerts.is_empty() {
+            tx.commit().await?;
+            return Ok(());
+        }
+        
+        let mut query_builder = String::from(
+            "INSERT INTO youtube_keyword_alerts \
+             (watchlist_id, user_public_id, keyword_text, alert_type, ranking_date, previous_ranking, current_ranking) VALUES "
+        );
+        query_builder.push_str(&vec!["(?, ?, ?, ?, ?, ?, ?)"; alerts.len()].join(", "));
+        
+        let mut query = sqlx::query(&query_builder);
+        for alert in alerts {
+            query = query
+                .bind(alert.watchlist_id)
+                .bind(&alert.user_public_id)
+                .bind(&alert.keyword_text)
+                .bind(alert.alert_type.as_str())
+                .bind(ranking_date)
+                .bind(alert.previous_ranking)
+                .bind(alert.current_ranking);
+        }
+        
+        query.execute(&mut *tx).await?;
+        
+        tx.commit().await?;
+        
+        Ok(())
+    }
+    
+    async fn find_alerts_by_user(&self, user_public_id: &str, limit: u32, offset: u32) -> Result<Vec<YoutubeKeywordAlert>, Error> {
+        let alerts = sqlx::query_as!(
+            YoutubeKeywordAlert,
+            r#"
+                SELECT id, watchlist_id, keyword_text, alert_type, ranking_date, previous_ranking, current_ranking, created_at
+                FROM youtube_keyword_alerts
+                WHERE user_public_id = ?
+                ORDER BY ranking_date DESC, id DESC
+                LIMIT ? OFFSET ?
+            "#,
+            user_public_id,
+            limit,
+            offset
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(alerts)
+    }
+    
+    async fn count_alerts_by_user(&self, user_public_id: &str) -> Result<i64, Error> {
+        let row = sqlx::query!(
+            r#"
+                SELECT COUNT(*) as count
+                FROM youtube_keyword_alerts
+                WHERE user_public_id = ?
+            "#,
+            user_public_id
+        )
+            .fetch_one(&self.db_pool)
+            .await?;
+        
+        Ok(row.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::youtube::youtube_video::youtube_video_model::KeywordAlertType;
+    
+    async fn init_schema(pool: &MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_keyword_watchlists (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                user_public_id VARCHAR(255) NOT NULL,
+                keyword_text VARCHAR(255) NOT NULL,
+                enter_top_n INT NULL,
+                climb_threshold INT NULL,
+                alert_on_drop_off BOOLEAN NOT NULL DEFAULT FALSE,
+                created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+                UNIQUE KEY uk_keyword_watchlists_user_keyword (user_public_id, keyword_text)
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+        
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_keyword_alerts (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                watchlist_id BIGINT NOT NULL,
+                user_public_id VARCHAR(255) NOT NULL,
+                keyword_text VARCHAR(255) NOT NULL,
+                alert_type VARCHAR(20) NOT NULL,
+                ranking_date DATE NOT NULL,
+                previous_ranking INT NULL,
+                current_ranking INT NULL,
+                created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                UNIQUE KEY uk_keyword_alerts_watchlist_date_type (watchlist_id, ranking_date, alert_type)
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    
+    fn alert(watchlist_id: i64, alert_type: KeywordAlertType, previous_ranking: Option<i32>, current_ranking: Option<i32>) -> NewKeywordAlert {
+        NewKeywordAlert {
+            watchlist_id,
+            user_public_id: "user".to_string(),
+            keyword_text: "먹방".to_string(),
+            alert_type,
+            previous_ranking,
+            current_ranking,
+        }
+    }
+    
+    fn watchlist_request(keyword_text: &str) -> KeywordWatchlistRequest {
+        KeywordWatchlistRequest {
+            keyword_text: keyword_text.to_string(),
+            enter_top_n: Some(10),
+            climb_threshold: None,
+            alert_on_drop_off: true,
+        }
+    }
+    
+    #[sqlx::test]
+    async fn replace_keyword_alerts_replaces_previous_evaluation_of_the_date(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_keyword_watchlist_repository = YoutubeKeywordWatchlistSqlxRepository::new(pool);
+        let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        let previous_date = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        
+        youtube_keyword_watchlist_repository
+            .replace_keyword_alerts(previous_date, &[alert(1, KeywordAlertType::DropOff, Some(3), None)])
+            .await
+            .unwrap();
+        youtube_keyword_watchlist_repository
+            .replace_keyword_alerts(date, &[alert(1, KeywordAlertType::EnterTopN, Some(15), Some(9))])
+            .await
+            .unwrap();
+        youtube_keyword_watchlist_repository
+            .replace_keyword_alerts(date, &[alert(1, KeywordAlertType::EnterTopN, Some(15), Some(4))])
+            .await
+            .unwrap();
+        
+        let alerts = youtube_keyword_watchlist_repository.find_alerts_by_user("user", 10, 0).await.unwrap();
+        assert_eq!(alerts.len(), 2);
+        assert_eq!((alerts[0].ranking_date, alerts[0].current_ranking), (date, Some(4)));
+        assert_eq!(alerts[1].ranking_date, previous_date);
+        
+        youtube_keyword_watchlist_repository.replace_keyword_alerts(date, &[]).await.unwrap();
+        assert_eq!(youtube_keyword_watchlist_repository.count_alerts_by_user("user").await.unwrap(), 1);
+    }
+    
+    #[sqlx::test]
+    async fn delete_watchlist_deletes_its_alerts(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_keyword_watchlist_repository = YoutubeKeywordWatchlistSqlxRepository::new(pool);
+        let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        let deleted = youtube_keyword_watchlist_repository.upsert_watchlist("user", &watchlist_request("먹방")).await.unwrap();
+        let kept = youtube_keyword_watchlist_repository.upsert_watchlist("user", &watchlist_request("브이로그")).await.unwrap();
+        youtube_keyword_watchlist_repository
+            .replace_keyword_alerts(date, &[
+                alert(deleted.id, KeywordAlertType::EnterTopN, None, Some(3)),
+                alert(kept.id, KeywordAlertType::DropOff, Some(5), None),
+            ])
+            .await
+            .unwrap();
+        
+        let deleted_count = youtube_keyword_watchlist_repository.delete_watchlist("user", deleted.id).await.unwrap();
+        
+        assert_eq!(deleted_count, 1);
+        let alerts = youtube_keyword_watchlist_repository.find_alerts_by_user("user", 10, 0).await.unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].watchlist_id, kept.id);
+    }
+}
\ No newline at end of file
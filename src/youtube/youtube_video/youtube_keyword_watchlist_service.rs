@@ -0,0 +1,127 @@
+use std::sync::Arc;
+use crate::common::pagination::{Page, PaginationQuery};
+use crate::errors::AppError;
+use crate::youtube::youtube_video::youtube_keyword_watchlist_repository::YoutubeKeywordWatchlistRepository;
+use crate::youtube::youtube_video::youtube_video_error::YoutubeVideoError;
+use crate::youtube::youtube_video::youtube_video_model::{KeywordAlertResponse, KeywordWatchlistRequest, KeywordWatchlistResponse};
+
+const WATCHLIST_LIMIT: usize = 50;
+// 순위표는 100위까지 저장
+const MAX_CHART_RANKING: i32 = 100;
+
+#[derive(Clone)]
+pub struct KeywordWatchlistService {
+    youtube_keyword_watchlist_repository: Arc<dyn YoutubeKeywordWatchlistRepository>,
+}
+
+impl KeywordWatchlistService {
+    pub fn new(youtube_keyword_watchlist_repository: Arc<dyn YoutubeKeywordWatchlistRepository>) -> Self {
+        Self { youtube_keyword_watchlist_repository }
+    }
+    
+    pub async fn get_watchlists(&self, user_public_id: &str) -> Result<Vec<KeywordWatchlistResponse>, AppError> {
+        let watchlists = self.youtube_keyword_watchlist_repository
+            .find_watchlists_by_user(user_public_id)
+            .await?;
+        
+        Ok(watchlists.iter().map(KeywordWatchlistResponse::from).collect())
+    }
+    
+    // 이미 등록된 키워드면 알림 조건만 갱신하므로 개수 제한에 걸리지 않음
+    pub async fn save_watchlist(&self, user_public_id: &str, request: KeywordWatchlistRequest) -> Result<KeywordWatchlistResponse, AppError> {
+        let request = validate_watchlist_request(request)?;
+        
+        let watchlists = self.youtube_keyword_watchlist_repository
+            .find_watchlists_by_user(user_public_id)
+            .await?;
+        let is_registered = watchlists.iter().any(|watchlist| watchlist.keyword_text == request.keyword_text);
+        if !is_registered && watchlists.len() >= WATCHLIST_LIMIT {
+            return Err(YoutubeVideoError::WatchlistLimitExceeded(WATCHLIST_LIMIT as i64))?;
+        }
+        
+        let watchlist = self.youtube_keyword_watchlist_repository
+            .upsert_watchlist(user_public_id, &request)
+            .await?;
+        
+        Ok(KeywordWatchlistResponse::from(&watchlist))
+    }
+    
+    pub async fn delete_watchlist(&self, user_public_id: &str, watchlist_id: i64) -> Result<(), AppError> {
+        let deleted_count = self.youtube_keyword_watchlist_repository
+            .delete_watchlist(user_public_id, watchlist_id)
+            .await?;
+        if deleted_count == 0 {
+            return Err(YoutubeVideoError::WatchlistNotFound(watchlist_id))?;
+        }
+        
+        Ok(())
+    }
+    
+    pub async fn get_alerts(&self, user_public_id: &str, query: PaginationQuery) -> Result<Page<KeywordAlertResponse>, AppError> {
+        let limit = query.size;
+        let offset = query.page * query.size;
+        
+        let total_items = self.youtube_keyword_watchlist_repository.count_alerts_by_user(user_public_id).await?;
+        let alerts = self.youtube_keyword_watchlist_repository
+            .find_alerts_by_user(user_public_id, limit, offset)
+            .await?;
+        let total_pages = (total_items as f64 / limit as f64).ceil() as u32;
+        
+        Ok(Page {
+            items: alerts.into_iter().map(KeywordAlertResponse::from).collect(),
+            page: query.page,
+            size: query.size,
+            total_items,
+            total_pages
+        })
+    }
+}
+
+fn validate_watchlist_request(request: KeywordWatchlistRequest) -> Result<KeywordWatchlistRequest, YoutubeVideoError> {
+    let keyword_text = request.keyword_text.trim().to_string();
+    if keyword_text.is_empty() {
+        return Err(YoutubeVideoError::InvalidWatchlistRule("키워드가 비어 있습니다.".to_string()));
+    }
+    if request.enter_top_n.is_none() && request.climb_threshold.is_none() && !request.alert_on_drop_off {
+        return Err(YoutubeVideoError::InvalidWatchlistRule("알림 조건을 하나 이상 설정해야 합니다.".to_string()));
+    }
+    if let Some(top_n) = request.enter_top_n {
+        if !(1..=MAX_CHART_RANKING).contains(&top_n) {
+            return Err(YoutubeVideoError::InvalidWatchlistRule(format!("enterTopN은 1~{} 사이여야 합니다.", MAX_CHART_RANKING)));
+        }
+    }
+    if let Some(threshold) = request.climb_threshold {
+        if !(1..MAX_CHART_RANKING).contains(&threshold) {
+            return Err(YoutubeVideoError::InvalidWatchlistRule(format!("climbThreshold는 1~{} 사이여야 합니다.", MAX_CHART_RANKING - 1)));
+        }
+    }
+    
+    Ok(KeywordWatchlistRequest { keyword_text, ..request })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn request(keyword_text: &str, enter_top_n: Option<i32>, climb_threshold: Option<i32>, alert_on_drop_off: bool) -> KeywordWatchlistRequest {
+        KeywordWatchlistRequest {
+            keyword_text: keyword_text.to_string(),
+            enter_top_n,
+            climb_threshold,
+            alert_on_drop_off,
+        }
+    }
+    
+    #[test]
+    fn validate_watchlist_request_checks_rules() {
+        let validated = validate_watchlist_request(request("  먹방 ", Some(10), None, false)).unwrap();
+        assert_eq!(validated.keyword_text, "먹방");
+        
+        assert!(validate_watchlist_request(request(" ", Some(10), None, false)).is_err());
+        assert!(validate_watchlist_request(request("먹방", None, None, false)).is_err());
+        assert!(validate_watchlist_request(request("먹방", Some(0), None, false)).is_err());
+        assert!(validate_watchlist_request(request("먹방", Some(101), None, false)).is_err());
+        assert!(validate_watchlist_request(request("먹방", None, Some(100), false)).is_err());
+        assert!(validate_watchlist_request(request("먹방", None, None, true)).is_ok());
+    }
+}
\ No newline at end of file
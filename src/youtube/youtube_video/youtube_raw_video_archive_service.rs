@@ -0,0 +1,253 @@
+use std::error::Error;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+use chrono_tz::Asia::Seoul;
+use flate2::Compression;
+use flate2::read::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use crate::youtube::youtube_video::youtube_raw_video_repository::YoutubeRawVideoRepository;
+use crate::youtube::youtube_video::youtube_video_model::{RawVideoArchiveFile, YoutubeRawVideo};
+
+const RESTORE_BATCH_SIZE: usize = 500;
+
+#[derive(Clone)]
+pub struct RawVideoArchiveService {
+    youtube_raw_video_repository: Arc<dyn YoutubeRawVideoRepository>,
+    archive_dir: PathBuf,
+    retention_days: i64,
+}
+
+impl RawVideoArchiveService {
+    pub fn new(
+        youtube_raw_video_repository: Arc<dyn YoutubeRawVideoRepository>,
+        archive_dir: impl Into<PathBuf>,
+        retention_days: i64,
+    ) -> Self {
+        Self {
+            youtube_raw_video_repository,
+            archive_dir: archive_dir.into(),
+            retention_days,
+        }
+    }
+    
+    // 보관 기간이 지난 원본 데이터를 KST 날짜별 gzip JSONL 파일로 내보낸 뒤 DB 에서 삭제
+    // 아카이브에서 복원한 행은 복원 후 보관 기간 동안 남겨 두었다가 파일에 다시 쓰지 않고 삭제
+    pub async fn archive_expired_raw_videos(&self) -> Result<Vec<RawVideoArchiveFile>, Box<dyn Error>> {
+        let cutoff_date = Utc::now().with_timezone(&Seoul).date_naive() - TimeDelta::days(self.retention_days);
+        let cutoff = kst_start_of_day(cutoff_date)?;
+        let mut archive_files = Vec::new();
+        
+        tokio::fs::create_dir_all(&self.archive_dir).await?;
+        
+        while let Some(oldest_updated_at) = self.youtube_raw_video_repository.find_oldest_archivable_updated_at().await? {
+            if oldest_updated_at >= cutoff {
+                break;
+            }
+            
+            let archive_date = oldest_updated_at.with_timezone(&Seoul).date_naive();
+            let start = kst_start_of_day(archive_date)?;
+            let end = start + TimeDelta::days(1);
+            
+            let raw_videos = self.youtube_raw_video_repository
+                .find_archivable_by_updated_at_between(start, end)
+                .await?;
+            let path = self.archive_path(archive_date);
+            let archived_count = raw_videos.len();
+            let write_path = path.clone();
+            tokio::task::spawn_blocking(move || write_archive(&write_path, &raw_videos)).await??;
+            
+            // 파일 기록이 끝난 뒤에만 삭제
+            let deleted_count = self.youtube_raw_video_repository
+                .delete_archivable_by_updated_at_between(start, end)
+                .await?;
+            println!("[Archive] {} 원본 데이터 {}건 보관, {}건 삭제", archive_date, archived_count, deleted_count);
+            
+            archive_files.push(RawVideoArchiveFile {
+                archive_date,
+                path: path.display().to_string(),
+                archived_count,
+                deleted_count,
+            });
+        }
+        
+        let released_count = self.youtube_raw_video_repository.delete_restored_before(cutoff).await?;
+        if released_count > 0 {
+            println!("[Archive] 복원 후 보관 기간이 지난 원본 데이터 {}건 삭제", released_count);
+        }
+        
+        Ok(archive_files)
+    }
+    
+    // 아카이브 파일을 다시 youtube_raw_videos 로 복원, 파일에서 읽은 행 수 반환
+    // 같은 영상이 DB 에 더 최근 수집본으로 있으면 그 행을 유지
+    pub async fn restore_archive(&self, path: &Path) -> Result<usize, Box<dyn Error>> {
+        let path = path.to_path_buf();
+        let raw_videos = tokio::task::spawn_blocking(move || read_archive(&path)).await??;
+        
+        for batch in raw_videos.chunks(RESTORE_BATCH_SIZE) {
+            self.youtube_raw_video_repository.restore_many(batch).await?;
+        }
+        
+        Ok(raw_videos.len())
+    }
+    
+    fn archive_path(&self, archive_date: NaiveDate) -> PathBuf {
+        self.archive_dir.join(format!("youtube_raw_videos_{}.jsonl.gz", archive_date))
+    }
+}
+
+fn kst_start_of_day(date: NaiveDate) -> Result<DateTime<Utc>, Box<dyn Error>> {
+    Ok(date
+        .and_hms_opt(0, 0, 0)
+        .and_then(|time| time.and_local_timezone(Seoul).single())
+        .ok_or("KST 기준 시각 계산 실패")?
+        .with_timezone(&Utc))
+}
+
+// 같은 날짜 파일이 이미 있으면 gzip 멤버를 이어 붙여 기존 내용을 보존
+fn write_archive(path: &Path, raw_videos: &[YoutubeRawVideo]) -> io::Result<()> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+    
+    for raw_video in raw_videos {
+        serde_json::to_writer(&mut encoder, raw_video)?;
+        encoder.write_all(b"\n")?;
+    }
+    
+    let writer = encoder.finish()?;
+    let file = writer.into_inner().map_err(|e| e.into_error())?;
+    file.sync_all()?;
+    
+    Ok(())
+}
+
+fn read_archive(path: &Path) -> io::Result<Vec<YoutubeRawVideo>> {
+    let reader = BufReader::new(MultiGzDecoder::new(File::open(path)?));
+    let mut raw_videos = Vec::new();
+    
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        raw_videos.push(serde_json::from_str::<YoutubeRawVideo>(&line)?);
+    }
+    
+    Ok(raw_videos)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use serde_json::json;
+    use sqlx::MySqlPool;
+    use super::*;
+    use crate::youtube::youtube_video::youtube_raw_video_repository::YoutubeRawVideoSqlxRepository;
+    
+    async fn init_schema(pool: &MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_raw_videos (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                video_id VARCHAR(255) NOT NULL UNIQUE,
+                raw_metadata JSON NOT NULL,
+                created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+                restored_at TIMESTAMP NULL DEFAULT NULL
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    
+    fn raw_video(video_id: &str) -> YoutubeRawVideo {
+        let collected_at = DateTime::from_timestamp(1_751_328_060, 0).unwrap();
+        YoutubeRawVideo {
+            id: 0,
+            video_id: video_id.to_string(),
+            raw_metadata: json!({ "id": video_id }),
+            created_at: collected_at,
+            updated_at: collected_at,
+        }
+    }
+    
+    #[test]
+    fn write_archive_appends_to_existing_file() {
+        let path = std::env::temp_dir().join(format!("raw_video_archive_{}.jsonl.gz", uuid::Uuid::new_v4()));
+        
+        write_archive(&path, &[raw_video("first")]).unwrap();
+        write_archive(&path, &[raw_video("second"), raw_video("third")]).unwrap();
+        
+        let reader = BufReader::new(MultiGzDecoder::new(File::open(&path).unwrap()));
+        let video_ids = reader
+            .lines()
+            .map(|line| serde_json::from_str::<YoutubeRawVideo>(&line.unwrap()).unwrap().video_id)
+            .collect::<Vec<_>>();
+        fs::remove_file(&path).unwrap();
+        
+        assert_eq!(video_ids, vec!["first", "second", "third"]);
+    }
+    
+    #[sqlx::test]
+    async fn restore_keeps_newer_row(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_raw_video_repository = YoutubeRawVideoSqlxRepository::new(pool.clone());
+        let latest = YoutubeRawVideo {
+            raw_metadata: json!({ "id": "kept", "title": "최신" }),
+            ..raw_video("kept")
+        };
+        youtube_raw_video_repository.save(&latest).await.unwrap();
+        
+        let archived = vec![raw_video("kept"), raw_video("restored")];
+        youtube_raw_video_repository.restore_many(&archived).await.unwrap();
+        
+        let kept = youtube_raw_video_repository.find_by_video_id("kept").await.unwrap();
+        let restored = youtube_raw_video_repository.find_by_video_id("restored").await.unwrap();
+        assert_eq!(kept.raw_metadata, latest.raw_metadata);
+        assert!(kept.updated_at > archived[0].updated_at);
+        assert_eq!(restored.raw_metadata, archived[1].raw_metadata);
+        assert_eq!(restored.updated_at, archived[1].updated_at);
+    }
+    
+    #[sqlx::test]
+    async fn archive_after_restore_does_not_archive_restored_rows_again(pool: MySqlPool) {
+        init_schema(&pool).await;
+        for raw_video in [raw_video("first"), raw_video("second")] {
+            sqlx::query("INSERT INTO youtube_raw_videos (video_id, raw_metadata, created_at, updated_at) VALUES (?, ?, ?, ?)")
+                .bind(&raw_video.video_id)
+                .bind(&raw_video.raw_metadata)
+                .bind(raw_video.created_at)
+                .bind(raw_video.updated_at)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        let archive_dir = std::env::temp_dir().join(format!("raw_video_archive_{}", uuid::Uuid::new_v4()));
+        let archive_service = RawVideoArchiveService::new(
+            Arc::new(YoutubeRawVideoSqlxRepository::new(pool.clone())),
+            &archive_dir,
+            90,
+        );
+        
+        let archive_files = archive_service.archive_expired_raw_videos().await.unwrap();
+        assert_eq!(archive_files.len(), 1);
+        assert_eq!(archive_files[0].deleted_count, 2);
+        let path = PathBuf::from(&archive_files[0].path);
+        
+        assert_eq!(archive_service.restore_archive(&path).await.unwrap(), 2);
+        assert!(archive_service.archive_expired_raw_videos().await.unwrap().is_empty());
+        
+        let row_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM youtube_raw_videos")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let archived_count = read_archive(&path).unwrap().len();
+        fs::remove_dir_all(&archive_dir).unwrap();
+        
+        assert_eq!(row_count, 2);
+        assert_eq!(archived_count, 2);
+    }
+}
\ No newline at end of file
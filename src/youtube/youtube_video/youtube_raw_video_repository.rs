@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use mockall::automock;
+use chrono::{DateTime, Utc};
 use sqlx::{Error, MySqlPool};
 use crate::youtube::youtube_video::youtube_video_model::YoutubeRawVideo;
 
@@ -11,6 +12,18 @@ pub trait YoutubeRawVideoRepository:  Send + Sync {
     async fn save(&self, raw_video: &YoutubeRawVideo) -> Result<i64, Error>;
     
     async fn save_many(&self, raw_videos: &[YoutubeRawVideo]) -> Result<(), Error>;
+    
+    async fn find_by_updated_at_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<YoutubeRawVideo>, Error>;
+    
+    async fn find_archivable_by_updated_at_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<YoutubeRawVideo>, Error>;
+    
+    async fn find_oldest_archivable_updated_at(&self) -> Result<Option<DateTime<Utc>>, Error>;
+    
+    async fn delete_archivable_by_updated_at_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<u64, Error>;
+    
+    async fn delete_restored_before(&self, cutoff: DateTime<Utc>) -> Result<u64, Error>;
+    
+    async fn restore_many(&self, raw_videos: &[YoutubeRawVideo]) -> Result<(), Error>;
 }
 
 #[derive(Clone)]
@@ -48,7 +61,8 @@ impl YoutubeRawVideoRepository for YoutubeRawVideoSqlxRepository {
                 VALUES (?, ?)
                 ON DUPLICATE KEY UPDATE
                                      raw_metadata = VALUES(raw_metadata),
-                                     updated_at = CURRENT_TIMESTAMP
+                                     updated_at = CURRENT_TIMESTAMP,
+                                     restored_at = NULL
             "#,
             raw_video.video_id,
             raw_video.raw_metadata
@@ -74,7 +88,7 @@ impl YoutubeRawVideoRepository for YoutubeRawVideoSqlxRepository {
             query_builder.push_str("(?, ?)");
         }
         query_builder.push_str(r#" ON DUPLICATE KEY UPDATE
-        raw_metadata = VALUES(raw_metadata), updated_at = CURRENT_TIMESTAMP
+        raw_metadata = VALUES(raw_metadata), updated_at = CURRENT_TIMESTAMP, restored_at = NULL
         "#);
         
         let mut query = sqlx::query(&query_builder);
@@ -88,4 +102,124 @@ impl YoutubeRawVideoRepository for YoutubeRawVideoSqlxRepository {
         
         Ok(())
     }
+    
+    async fn find_by_updated_at_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<YoutubeRawVideo>, Error> {
+        let raw_videos = sqlx::query_as!(
+            YoutubeRawVideo,
+            r#"
+                SELECT id, video_id, raw_metadata, created_at, updated_at
+                FROM youtube_raw_videos
+                WHERE updated_at >= ? AND updated_at < ?
+                ORDER BY id
+            "#,
+            start,
+            end
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(raw_videos)
+    }
+    
+    // 복원한 행은 이미 아카이브 파일에 있으므로 아카이브 대상에서 제외
+    async fn find_archivable_by_updated_at_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<Vec<YoutubeRawVideo>, Error> {
+        let raw_videos = sqlx::query_as!(
+            YoutubeRawVideo,
+            r#"
+                SELECT id, video_id, raw_metadata, created_at, updated_at
+                FROM youtube_raw_videos
+                WHERE updated_at >= ? AND updated_at < ?
+                  AND restored_at IS NULL
+                ORDER BY id
+            "#,
+            start,
+            end
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(raw_videos)
+    }
+    
+    async fn find_oldest_archivable_updated_at(&self) -> Result<Option<DateTime<Utc>>, Error> {
+        let oldest_updated_at = sqlx::query_scalar!(
+            r#"
+                SELECT MIN(updated_at) AS "oldest_updated_at: DateTime<Utc>"
+                FROM youtube_raw_videos
+                WHERE restored_at IS NULL
+            "#
+        )
+            .fetch_one(&self.db_pool)
+            .await?;
+        
+        Ok(oldest_updated_at)
+    }
+    
+    async fn delete_archivable_by_updated_at_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<u64, Error> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM youtube_raw_videos
+                WHERE updated_at >= ? AND updated_at < ?
+                  AND restored_at IS NULL
+            "#,
+            start,
+            end
+        )
+            .execute(&self.db_pool)
+            .await?;
+        
+        Ok(result.rows_affected())
+    }
+    
+    // 복원 후 보관 기간이 지난 행은 파일에 다시 쓰지 않고 삭제
+    async fn delete_restored_before(&self, cutoff: DateTime<Utc>) -> Result<u64, Error> {
+        let result = sqlx::query!(
+            r#"
+                DELETE FROM youtube_raw_videos
+                WHERE restored_at < ?
+            "#,
+            cutoff
+        )
+            .execute(&self.db_pool)
+            .await?;
+        
+        Ok(result.rows_affected())
+    }
+    
+    // 아카이브 복원 시 원래 수집 시각을 유지하고, 같은 영상은 더 최근에 수집된 행을 남김
+    // 아카이브 행으로 바뀐 경우에만 복원 표시 (대입은 왼쪽부터 적용되므로 updated_at 을 마지막에 갱신)
+    async fn restore_many(&self, raw_videos: &[YoutubeRawVideo]) -> Result<(), Error> {
+        if raw_videos.is_empty() {
+            return Ok(());
+        }
+        
+        let mut query_builder = String::from(
+            r#"INSERT INTO youtube_raw_videos (video_id, raw_metadata, created_at, updated_at, restored_at) VALUES"#
+        );
+        for (i, _) in raw_videos.iter().enumerate() {
+            if i > 0 {
+                query_builder.push_str(", ");
+            }
+            query_builder.push_str("(?, ?, ?, ?, CURRENT_TIMESTAMP)");
+        }
+        query_builder.push_str(r#" ON DUPLICATE KEY UPDATE
+        raw_metadata = IF(VALUES(updated_at) > updated_at, VALUES(raw_metadata), raw_metadata),
+        restored_at = IF(VALUES(updated_at) > updated_at, VALUES(restored_at), restored_at),
+        created_at = LEAST(created_at, VALUES(created_at)),
+        updated_at = GREATEST(updated_at, VALUES(updated_at))
+        "#);
+        
+        let mut query = sqlx::query(&query_builder);
+        for video in raw_videos {
+            query = query
+                .bind(&video.video_id)
+                .bind(&video.raw_metadata)
+                .bind(video.created_at)
+                .bind(video.updated_at);
+        }
+        
+        query.execute(&self.db_pool).await?;
+        
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,230 @@
+use std::collections::BTreeMap;
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use crate::common::statistics::round4;
+use crate::youtube::youtube_video::youtube_video_error::YoutubeVideoError;
+use crate::youtube::youtube_video::youtube_video_model::{DistributionSummary, DurationBucketResponse, DurationMetricPercentile, PublishHourStats, PublishTimeSlotResponse, DURATION_METRIC_COMMENT_RATE, DURATION_METRIC_LIKE_RATE, DURATION_METRIC_VIEWS};
+
+pub const DEFAULT_ANALYTICS_TIMEZONE: &str = "Asia/Seoul";
+const DEFAULT_ANALYTICS_DAYS: i64 = 30;
+const MAX_ANALYTICS_DAYS: i64 = 180;
+const BEST_SLOT_LIMIT: usize = 5;
+// 영상 수가 너무 적은 슬롯은 추천에서 제외
+const MIN_BEST_SLOT_VIDEO_COUNT: i64 = 5;
+// 10초 단위 구간, 마지막 구간은 60초 이상 전체
+pub const DURATION_BUCKET_SECONDS: i32 = 10;
+const DURATION_BUCKET_MAX_SECONDS: i32 = 60;
+pub const DURATION_LAST_BUCKET: i32 = DURATION_BUCKET_MAX_SECONDS / DURATION_BUCKET_SECONDS;
+
+pub fn parse_timezone(timezone: Option<&str>) -> Result<Tz, YoutubeVideoError> {
+    let timezone = timezone.unwrap_or(DEFAULT_ANALYTICS_TIMEZONE);
+    timezone
+        .parse::<Tz>()
+        .map_err(|_| YoutubeVideoError::InvalidTimezone(timezone.to_string()))
+}
+
+// 요청 시간대 기준 날짜 범위를 UTC 시각 범위 [start, end) 로 변환
+pub fn analytics_window(
+    timezone: Tz,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    now: DateTime<Utc>,
+) -> Result<(NaiveDate, NaiveDate, DateTime<Utc>, DateTime<Utc>), YoutubeVideoError> {
+    let to = to.unwrap_or_else(|| now.with_timezone(&timezone).date_naive());
+    let from = from.unwrap_or(to - TimeDelta::days(DEFAULT_ANALYTICS_DAYS - 1));
+    if from > to || (to - from).num_days() >= MAX_ANALYTICS_DAYS {
+        return Err(YoutubeVideoError::InvalidDateRange(from, to));
+    }
+    
+    let start_of_day = |date: NaiveDate| {
+        date.and_hms_opt(0, 0, 0)
+            .and_then(|time| time.and_local_timezone(timezone).earliest())
+            .map(|time| time.with_timezone(&Utc))
+            .ok_or(YoutubeVideoError::InvalidDateRange(from, to))
+    };
+    
+    Ok((from, to, start_of_day(from)?, start_of_day(to + TimeDelta::days(1))?))
+}
+
+// UTC 15분 구간 합계를 요청 시간대의 요일 x 시각 슬롯으로 다시 묶음
+pub fn build_publish_time_slots(timezone: Tz, stats: &[PublishHourStats]) -> Vec<PublishTimeSlotResponse> {
+    let mut slots: BTreeMap<(u32, u32), (i64, i64, i64, i64)> = BTreeMap::new();
+    for stat in stats {
+        let Some(published_at) = stat.published_date.and_hms_opt(stat.published_hour as u32, stat.published_minute as u32, 0) else { continue };
+        let local = published_at.and_utc().with_timezone(&timezone);
+        let slot = slots
+            .entry((local.weekday().num_days_from_monday(), local.hour()))
+            .or_default();
+        slot.0 += stat.video_count;
+        slot.1 += stat.view_count;
+        slot.2 += stat.like_count;
+        slot.3 += stat.comment_count;
+    }
+    
+    slots
+        .into_iter()
+        .map(|((weekday, hour), (video_count, view_count, like_count, comment_count))| PublishTimeSlotResponse {
+            weekday: Weekday::try_from(weekday as u8).map(|weekday| weekday.to_string()).unwrap_or_default(),
+            hour,
+            video_count,
+            total_view_count: view_count,
+            average_view_count: round4(view_count as f64 / video_count.max(1) as f64),
+            engagement_rate: if view_count > 0 {
+                round4((like_count + comment_count) as f64 / view_count as f64)
+            } else {
+                0.0
+            },
+        })
+        .collect()
+}
+
+pub fn best_publish_time_slots(slots: &[PublishTimeSlotResponse]) -> Vec<PublishTimeSlotResponse> {
+    let mut best_slots: Vec<PublishTimeSlotResponse> = slots
+        .iter()
+        .filter(|slot| slot.video_count >= MIN_BEST_SLOT_VIDEO_COUNT)
+        .cloned()
+        .collect();
+    best_slots.sort_by(|a, b| b.average_view_count.total_cmp(&a.average_view_count));
+    best_slots.truncate(BEST_SLOT_LIMIT);
+    
+    best_slots
+}
+
+// SQL 에서 구간, 지표별로 계산한 중앙값과 p90 을 길이 구간 응답으로 묶음 (영상이 없는 구간은 0)
+pub fn build_duration_buckets(percentiles: &[DurationMetricPercentile]) -> Vec<DurationBucketResponse> {
+    (0..=DURATION_LAST_BUCKET)
+        .map(|index| {
+            let min_seconds = index * DURATION_BUCKET_SECONDS;
+            let max_seconds = (min_seconds < DURATION_BUCKET_MAX_SECONDS).then_some(min_seconds + DURATION_BUCKET_SECONDS);
+            let label = match max_seconds {
+                Some(max_seconds) => format!("{}-{}s", min_seconds, max_seconds),
+                None => format!("{}s+", min_seconds),
+            };
+            let rows: Vec<&DurationMetricPercentile> = percentiles
+                .iter()
+                .filter(|row| row.bucket == index as i64)
+                .collect();
+            
+            DurationBucketResponse {
+                label,
+                min_seconds,
+                max_seconds,
+                video_count: rows
+                    .iter()
+                    .find(|row| row.metric == DURATION_METRIC_VIEWS)
+                    .map_or(0, |row| row.value_count as usize),
+                views: distribution(&rows, DURATION_METRIC_VIEWS),
+                like_rate: distribution(&rows, DURATION_METRIC_LIKE_RATE),
+                comment_rate: distribution(&rows, DURATION_METRIC_COMMENT_RATE),
+            }
+        })
+        .collect()
+}
+
+fn distribution(rows: &[&DurationMetricPercentile], metric: &str) -> DistributionSummary {
+    let value = |percentile: f64| {
+        rows.iter()
+            .find(|row| row.metric == metric && (row.percentile - percentile).abs() < 1e-9)
+            .map_or(0.0, |row| round4(row.value))
+    };
+    
+    DistributionSummary {
+        median: value(0.5),
+        p90: value(0.9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn stat(published_date: &str, published_hour: i64, video_count: i64, view_count: i64) -> PublishHourStats {
+        PublishHourStats {
+            published_date: published_date.parse().unwrap(),
+            published_hour,
+            published_minute: 0,
+            video_count,
+            view_count,
+            like_count: view_count / 10,
+            comment_count: 0,
+        }
+    }
+    
+    #[test]
+    fn build_publish_time_slots_shifts_utc_hours_into_timezone() {
+        let timezone = parse_timezone(Some("Asia/Seoul")).unwrap();
+        // 2026-01-04(일) 15시 UTC 는 KST 2026-01-05(월) 0시
+        let stats = vec![
+            stat("2026-01-04", 15, 2, 1_000),
+            stat("2026-01-11", 15, 1, 500),
+            stat("2026-01-05", 3, 1, 100),
+        ];
+        
+        let slots = build_publish_time_slots(timezone, &stats);
+        
+        assert_eq!(slots.len(), 2);
+        assert_eq!((slots[0].weekday.as_str(), slots[0].hour), ("Mon", 0));
+        assert_eq!(slots[0].video_count, 3);
+        assert_eq!(slots[0].average_view_count, 500.0);
+        assert_eq!(slots[0].engagement_rate, 0.1);
+        assert_eq!((slots[1].weekday.as_str(), slots[1].hour), ("Mon", 12));
+    }
+    
+    #[test]
+    fn build_publish_time_slots_splits_hours_for_half_hour_timezones() {
+        let timezone = parse_timezone(Some("Asia/Kolkata")).unwrap();
+        // 2026-01-05(월) 03:15 UTC 는 IST 08:45, 03:30 UTC 는 IST 09:00
+        let stats = vec![
+            PublishHourStats { published_minute: 15, ..stat("2026-01-05", 3, 1, 100) },
+            PublishHourStats { published_minute: 30, ..stat("2026-01-05", 3, 1, 300) },
+        ];
+        
+        let slots = build_publish_time_slots(timezone, &stats);
+        
+        assert_eq!(slots.len(), 2);
+        assert_eq!((slots[0].weekday.as_str(), slots[0].hour, slots[0].video_count), ("Mon", 8, 1));
+        assert_eq!((slots[1].weekday.as_str(), slots[1].hour, slots[1].total_view_count), ("Mon", 9, 300));
+    }
+    
+    #[test]
+    fn build_duration_buckets_fills_every_bucket() {
+        let row = |metric: &str, bucket: i64, value_count: i64, percentile: f64, value: f64| DurationMetricPercentile {
+            metric: metric.to_string(),
+            bucket,
+            value_count,
+            percentile,
+            value,
+        };
+        let percentiles = vec![
+            row(DURATION_METRIC_VIEWS, 1, 2, 0.5, 2_000.0),
+            row(DURATION_METRIC_VIEWS, 1, 2, 0.9, 2_800.0),
+            row(DURATION_METRIC_LIKE_RATE, 1, 1, 0.5, 0.05),
+            row(DURATION_METRIC_LIKE_RATE, 1, 1, 0.9, 0.05),
+            row(DURATION_METRIC_VIEWS, 6, 2, 0.5, 600.0),
+            row(DURATION_METRIC_VIEWS, 6, 2, 0.9, 680.0),
+        ];
+        
+        let buckets = build_duration_buckets(&percentiles);
+        
+        assert_eq!(buckets.len(), 7);
+        assert_eq!(buckets[1].label, "10-20s");
+        assert_eq!(buckets[1].video_count, 2);
+        assert_eq!(buckets[1].views, DistributionSummary { median: 2_000.0, p90: 2_800.0 });
+        assert_eq!(buckets[1].like_rate.median, 0.05);
+        assert_eq!(buckets[1].comment_rate.median, 0.0);
+        assert_eq!(buckets[6].label, "60s+");
+        assert_eq!(buckets[6].max_seconds, None);
+        assert_eq!(buckets[6].video_count, 2);
+        assert_eq!(buckets[2].video_count, 0);
+    }
+    
+    #[test]
+    fn analytics_window_rejects_reversed_range() {
+        let timezone = parse_timezone(None).unwrap();
+        let from = "2026-02-01".parse().ok();
+        let to = "2026-01-01".parse().ok();
+        
+        assert!(analytics_window(timezone, from, to, Utc::now()).is_err());
+        assert!(parse_timezone(Some("Mars/Olympus")).is_err());
+    }
+}
\ No newline at end of file
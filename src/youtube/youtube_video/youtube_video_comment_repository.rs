@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use mockall::automock;
+use sqlx::{Error, MySqlPool};
+use crate::youtube::youtube_video::youtube_video_model::{CommentKeywordStat, RankedKeywordVideo, YoutubeVideoComment};
+
+#[automock]
+#[async_trait]
+pub trait YoutubeVideoCommentRepository: Send + Sync {
+    async fn find_top_ranked_keyword_videos(
+        &self,
+        ranking_date: NaiveDate,
+        region_code: &str,
+        strategy: &str,
+        videos_per_keyword: u32,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<RankedKeywordVideo>, Error>;
+    
+    async fn save_comments_and_keywords(
+        &self,
+        video_id: &str,
+        comments: &[YoutubeVideoComment],
+        comment_keywords: &[(String, i32)],
+    ) -> Result<(), Error>;
+    
+    async fn find_comment_keywords_by_keyword(
+        &self,
+        keyword_text: &str,
+        since: NaiveDate,
+        until: NaiveDate,
+        limit: u32,
+    ) -> Result<Vec<CommentKeywordStat>, Error>;
+}
+
+#[derive(Clone)]
+pub struct YoutubeVideoCommentSqlxRepository {
+    pub db_pool: MySqlPool,
+}
+
+impl YoutubeVideoCommentSqlxRepository {
+    pub fn new(db_pool: MySqlPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl YoutubeVideoCommentRepository for YoutubeVideoCommentSqlxRepository {
+    // 순위표 키워드마다 순위 기간 내 조회수 상위 영상
+    async fn find_top_ranked_keyword_videos(
+        &self,
+        ranking_date: NaiveDate,
+        region_code: &str,
+        strategy: &str,
+        videos_per_keyword: u32,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<RankedKeywordVideo>, Error> {
+        let videos = sqlx::query_as!(
+            RankedKeywordVideo,
+            r#"
+                SELECT ranked.keyword_text, ranked.video_id, ranked.title
+                FROM (
+                    SELECT ykr.ranking, ykr.keyword_text, yv.video_id, yv.title,
+                           ROW_NUMBER() OVER (PARTITION BY ykr.keyword_id ORDER BY yv.view_count DESC) AS video_rank
+                    FROM youtube_keyword_rankings AS ykr
+                    JOIN youtube_video_keywords AS yvk ON yvk.keyword_id = ykr.keyword_id
+                    JOIN youtube_videos AS yv ON yv.id = yvk.video_id
+                    WHERE ykr.ranking_date = ? AND ykr.region_code = ? AND ykr.strategy = ?
+                      AND yv.updated_at >= ? AND yv.updated_at < ?
+                      AND yv.is_available = TRUE
+                ) AS ranked
+                WHERE ranked.video_rank <= ?
+                ORDER BY ranked.ranking, ranked.video_rank
+            "#,
+            ranking_date,
+            region_code,
+            strategy,
+            since,
+            until,
+            videos_per_keyword
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(videos)
+    }
+    
+    // 댓글은 갱신하고 댓글 키워드는 최신 수집 결과로 교체
+    async fn save_comments_and_keywords(
+        &self,
+        video_id: &str,
+        comments: &[YoutubeVideoComment],
+        comment_keywords: &[(String, i32)],
+    ) -> Result<(), Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        if !comments.is_empty() {
+            let mut query_builder = String::from(
+                "INSERT INTO youtube_video_comments \
+                 (video_id, comment_id, author_display_name, text_display, like_count, reply_count, published_at) VALUES "
+            );
+            query_builder.push_str(&vec!["(?, ?, ?, ?, ?, ?, ?)"; comments.len()].join(", "));
+            query_builder.push_str(
+                " ON DUPLICATE KEY UPDATE text_display = VALUES(text_display), \
+                  like_count = VALUES(like_count), reply_count = VALUES(reply_count)"
+            );
+            
+            let mut query = sqlx::query(&query_builder);
+            for comment in comments {
+                query = query
+                    .bind(&comment.video_id)
+                    .bind(&comment.comment_id)
+                    .bind(&comment.author_display_name)
+                    .bind(&comment.text_display)
+                    .bind(comment.like_count)
+                    .bind(comment.reply_count)
+                    .bind(comment.published_at);
+            }
+            query.execute(&mut *tx).await?;
+        }
+        
+        sqlx::query!(
+            r#"
+                DELETE
+                FROM youtube_video_comment_keywords
+                WHERE video_id = ?
+            "#,
+            video_id
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        if !comment_keywords.is_empty() {
+            let mut query_builder = String::from(
+                "INSERT IGNORE INTO youtube_video_comment_keywords (video_id, keyword_text, mention_count) VALUES "
+            );
+            query_builder.push_str(&vec!["(?, ?, ?)"; comment_keywords.len()].join(", "));
+            
+            let mut query = sqlx::query(&query_builder);
+            for (keyword_text, mention_count) in comment_keywords {
+                query = query
+                    .bind(video_id)
+                    .bind(keyword_text)
+                    .bind(mention_count);
+            }
+            query.execute(&mut *tx).await?;
+        }
+        
+        tx.commit().await?;
+        
+        Ok(())
+    }
+    
+    // 해당 키워드가 붙은 영상들의 댓글 키워드 합계 (키워드 자신은 제외)
+    async fn find_comment_keywords_by_keyword(
+        &self,
+        keyword_text: &str,
+        since: NaiveDate,
+        until: NaiveDate,
+        limit: u32,
+    ) -> Result<Vec<CommentKeywordStat>, Error> {
+        let stats = sqlx::query_as!(
+            CommentKeywordStat,
+            r#"
+                SELECT yvck.keyword_text,
+                       CAST(SUM(yvck.mention_count) AS SIGNED) AS "mention_count!",
+                       COUNT(DISTINCT yvck.video_id) AS video_count
+                FROM youtube_keywords AS yk
+                JOIN youtube_video_keywords AS yvk ON yvk.keyword_id = yk.id
+                JOIN youtube_videos AS yv ON yv.id = yvk.video_id
+                JOIN youtube_video_comment_keywords AS yvck ON yvck.video_id = yv.video_id
+                WHERE yk.keyword_text = ?
+                  AND yv.updated_at >= ? AND yv.updated_at < ?
+                  AND yv.is_available = TRUE
+                  AND yvck.keyword_text <> yk.keyword_text
+                GROUP BY yvck.keyword_text
+                ORDER BY SUM(yvck.mention_count) DESC, video_count DESC, yvck.keyword_text
+                LIMIT ?
+            "#,
+            keyword_text,
+            since,
+            until,
+            limit
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(stats)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,192 @@
+use std::collections::HashSet;
+use std::error::Error;
+use std::sync::Arc;
+use chrono::{TimeDelta, Utc};
+use chrono_tz::Asia::Seoul;
+use crate::common::keyword::normalize_keyword;
+use crate::errors::AppError;
+use crate::gemini::gemini_api_model::GeminiPromptCommentData;
+use crate::gemini::gemini_api_util::GeminiAPIClient;
+use crate::youtube::youtube_data_api::youtube_data_api_util::YoutubeDataAPIClient;
+use crate::youtube::youtube_video::youtube_video_comment_repository::YoutubeVideoCommentRepository;
+use crate::youtube::youtube_video::youtube_video_model::{CommentCollectionSummary, CommentKeywordResponse, KeywordCommentsResponse, RankedKeywordVideo, YoutubeVideoComment, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM};
+
+// 키워드마다 조회수 상위 영상 3개, 영상마다 관련성 상위 댓글 20개 (commentThreads 1 unit/영상)
+const VIDEOS_PER_KEYWORD: u32 = 3;
+const COMMENTS_PER_VIDEO: u32 = 20;
+const GEMINI_BATCH_SIZE: usize = 10;
+const COMMENT_KEYWORD_LIMIT: u32 = 50;
+
+#[derive(Clone)]
+pub struct YoutubeVideoCommentService {
+    youtube_data_api_client: Arc<YoutubeDataAPIClient>,
+    youtube_video_comment_repository: Arc<dyn YoutubeVideoCommentRepository>,
+    gemini_api_client: Arc<GeminiAPIClient>,
+}
+
+impl YoutubeVideoCommentService {
+    pub fn new(
+        youtube_data_api_client: Arc<YoutubeDataAPIClient>,
+        youtube_video_comment_repository: Arc<dyn YoutubeVideoCommentRepository>,
+        gemini_api_client: Arc<GeminiAPIClient>,
+    ) -> Self {
+        Self {
+            youtube_data_api_client,
+            youtube_video_comment_repository,
+            gemini_api_client,
+        }
+    }
+    
+    // 오늘 순위표 키워드의 상위 영상 댓글을 수집하고 댓글 키워드 추출
+    pub async fn collect_trending_video_comments(&self) -> Result<CommentCollectionSummary, Box<dyn Error>> {
+        let today = Utc::now().with_timezone(&Seoul).date_naive();
+        let ranked_videos = self.youtube_video_comment_repository
+            .find_top_ranked_keyword_videos(
+                today,
+                RANKING_REGION_CODE,
+                RANKING_STRATEGY_WEEKLY_VIEW_SUM,
+                VIDEOS_PER_KEYWORD,
+                today - TimeDelta::days(7),
+                today + TimeDelta::days(1),
+            )
+            .await?;
+        
+        // 여러 키워드에 걸친 영상은 한 번만 수집
+        let mut seen_video_ids = HashSet::new();
+        let videos: Vec<RankedKeywordVideo> = ranked_videos
+            .into_iter()
+            .filter(|video| seen_video_ids.insert(video.video_id.clone()))
+            .collect();
+        let mut summary = CommentCollectionSummary {
+            video_count: videos.len(),
+            ..Default::default()
+        };
+        
+        for video_chunk in videos.chunks(GEMINI_BATCH_SIZE) {
+            let mut video_comments = Vec::with_capacity(video_chunk.len());
+            for video in video_chunk {
+                match self.youtube_data_api_client.get_top_comment_threads(&video.video_id, COMMENTS_PER_VIDEO).await {
+                    Ok(threads) => {
+                        // 작성 시각을 읽을 수 없는 댓글은 시각을 지어내지 않고 제외
+                        let comments: Vec<YoutubeVideoComment> = threads
+                            .iter()
+                            .filter_map(|thread| match YoutubeVideoComment::try_from(thread) {
+                                Ok(comment) => Some(comment),
+                                Err(e) => {
+                                    eprintln!("[Comment] '{}' 댓글 작성 시각 파싱 실패: {}", thread.id, e);
+                                    None
+                                }
+                            })
+                            .collect();
+                        video_comments.push((video, comments));
+                    }
+                    Err(e) => {
+                        // 댓글이 막힌 영상은 403 을 반환하므로 건너뜀
+                        summary.failed_video_count += 1;
+                        eprintln!("[Comment] '{}' 댓글 조회 실패: {}", video.video_id, e);
+                    }
+                }
+            }
+            
+            let prompt_data: Vec<GeminiPromptCommentData> = video_comments
+                .iter()
+                .filter(|(_, comments)| !comments.is_empty())
+                .map(|(video, comments)| GeminiPromptCommentData {
+                    video_id: &video.video_id,
+                    title: &video.title,
+                    comments: comments.iter().map(|comment| comment.text_display.as_str()).collect(),
+                })
+                .collect();
+            // Gemini 호출이 실패한 묶음만 건너뛰고 다음 묶음은 계속 수집
+            let keyword_map = match self.gemini_api_client.extract_comment_keywords_with_gemini(&prompt_data).await {
+                Ok(keyword_map) => keyword_map,
+                Err(e) => {
+                    summary.failed_video_count += video_comments.len();
+                    eprintln!("[Comment] 댓글 키워드 추출 실패 (영상 {}개 건너뜀): {}", video_comments.len(), e);
+                    continue;
+                }
+            };
+            
+            for (video, comments) in &video_comments {
+                let keywords = keyword_map.get(video.video_id.as_str()).cloned().unwrap_or_default();
+                let comment_texts: Vec<&str> = comments.iter().map(|comment| comment.text_display.as_str()).collect();
+                let comment_keywords = count_keyword_mentions(&keywords, &comment_texts);
+                
+                if let Err(e) = self.youtube_video_comment_repository
+                    .save_comments_and_keywords(&video.video_id, comments, &comment_keywords)
+                    .await
+                {
+                    summary.failed_video_count += 1;
+                    eprintln!("[Comment] '{}' 댓글 저장 실패: {}", video.video_id, e);
+                    continue;
+                }
+                summary.collected_video_count += 1;
+                summary.comment_count += comments.len();
+            }
+        }
+        
+        Ok(summary)
+    }
+    
+    pub async fn get_keyword_comments(&self, keyword_text: String) -> Result<KeywordCommentsResponse, AppError> {
+        let today = Utc::now().with_timezone(&Seoul).date_naive();
+        let stats = self.youtube_video_comment_repository
+            .find_comment_keywords_by_keyword(
+                &keyword_text,
+                today - TimeDelta::days(7),
+                today + TimeDelta::days(1),
+                COMMENT_KEYWORD_LIMIT,
+            )
+            .await?;
+        
+        Ok(KeywordCommentsResponse {
+            keyword_text,
+            comment_keywords: stats.into_iter().map(CommentKeywordResponse::from).collect(),
+        })
+    }
+}
+
+// 추출된 키워드가 언급된 댓글 수 (공백, 대소문자 무시)
+// 댓글 원문에서 찾을 수 없는 키워드(Gemini 가 표기를 바꾼 경우 포함)는 언급 수를 지어내지 않고 제외
+fn count_keyword_mentions(keywords: &[String], comments: &[&str]) -> Vec<(String, i32)> {
+    let normalized_comments: Vec<String> = comments.iter().map(|comment| normalize_keyword(comment)).collect();
+    let mut seen_keywords = HashSet::new();
+    
+    keywords
+        .iter()
+        .map(|keyword| keyword.trim())
+        .filter(|keyword| !keyword.is_empty() && seen_keywords.insert(normalize_keyword(keyword)))
+        .map(|keyword| {
+            let normalized_keyword = normalize_keyword(keyword);
+            let mention_count = normalized_comments
+                .iter()
+                .filter(|comment| comment.contains(&normalized_keyword))
+                .count();
+            (keyword.to_string(), mention_count as i32)
+        })
+        .filter(|(_, mention_count)| *mention_count > 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn count_keyword_mentions_ignores_spacing_duplicates_and_unmentioned() {
+        let keywords = vec!["두바이 초콜릿".to_string(), "GS25".to_string(), "두바이초콜릿".to_string(), "품절".to_string(), "재입고".to_string()];
+        let comments = vec![
+            "이거 두바이 초콜릿 맛이랑 비슷한가요?",
+            "두바이초콜릿 요즘 품절이던데",
+            "gs25에 아직 있어요",
+        ];
+        
+        let mentions = count_keyword_mentions(&keywords, &comments);
+        
+        assert_eq!(mentions, vec![
+            ("두바이 초콜릿".to_string(), 2),
+            ("GS25".to_string(), 1),
+            ("품절".to_string(), 1),
+        ]);
+    }
+}
\ No newline at end of file
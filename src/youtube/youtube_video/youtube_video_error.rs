@@ -0,0 +1,32 @@
+use chrono::NaiveDate;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum YoutubeVideoError {
+    #[error("알 수 없는 시간대입니다: {0}")]
+    InvalidTimezone(String),
+    
+    #[error("조회 기간이 올바르지 않습니다. ({0} ~ {1})")]
+    InvalidDateRange(NaiveDate, NaiveDate),
+    
+    #[error("최근 수집된 영상이 없는 키워드입니다: {0}")]
+    KeywordNotFound(String),
+    
+    #[error("기획안 생성에 실패했습니다: {0}")]
+    IdeaGenerationFailed(String),
+    
+    #[error("'{0}' 기획안을 생성하고 있습니다. 잠시 후 다시 시도해 주세요.")]
+    IdeaGenerationInProgress(String),
+    
+    #[error("오늘 기획안 생성 한도({0}회)를 모두 사용했습니다.")]
+    IdeaRateLimitExceeded(i64),
+    
+    #[error("관심 키워드 알림 조건이 올바르지 않습니다: {0}")]
+    InvalidWatchlistRule(String),
+    
+    #[error("관심 키워드는 최대 {0}개까지 등록할 수 있습니다.")]
+    WatchlistLimitExceeded(i64),
+    
+    #[error("관심 키워드를 찾을 수 없습니다: {0}")]
+    WatchlistNotFound(i64),
+}
\ No newline at end of file
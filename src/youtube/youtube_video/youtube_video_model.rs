@@ -1,10 +1,14 @@
 use chrono::{DateTime, NaiveDate, Utc};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
-use utoipa::{ToResponse, ToSchema};
-use crate::youtube::youtube_data_api::youtube_data_api_model::VideoItem;
+use utoipa::{IntoParams, ToResponse, ToSchema};
+use crate::gemini::gemini_api_model::GeminiContentIdea;
+use crate::youtube::youtube_data_api::youtube_data_api_model::{CommentThreadItem, VideoItem};
 
-#[derive(Serialize, Debug, Clone, sqlx::FromRow)]
+pub const RANKING_REGION_CODE: &str = "KR";
+pub const RANKING_STRATEGY_WEEKLY_VIEW_SUM: &str = "weekly_view_sum";
+
+#[derive(Serialize, Deserialize, Debug, Clone, sqlx::FromRow)]
 pub struct YoutubeRawVideo {
     pub id: i64,
     pub video_id: String,
@@ -29,6 +33,7 @@ pub struct YoutubeVideo {
     pub comment_count: i64,
     pub embed_html: String,
     pub topic_categories: Option<Vec<String>>,
+    pub category_id: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -43,6 +48,8 @@ pub struct YoutubeKeyword {
 pub struct YoutubeKeywordRanking {
     pub id: i64,
     pub ranking_date: NaiveDate,
+    pub region_code: String,
+    pub strategy: String,
     pub ranking: i32,
     pub keyword_id: i64,
     pub keyword_text: String,
@@ -63,6 +70,9 @@ pub struct KeywordRankingResponse {
     pub keyword_text: String,
     pub score: i64,
     pub rank_change: RankChange,
+    // 감성 분석 결과가 있는 키워드에만 포함
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sentiment: Option<KeywordSentimentResponse>,
 }
 
 #[derive(Serialize, Debug, Clone, ToSchema)]
@@ -73,6 +83,349 @@ pub enum RankChange {
     Same,
     New,
 }
+
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RawVideoReprocessSummary {
+    pub raw_video_count: usize,
+    pub invalid_raw_video_count: usize,
+    pub reprocessed_video_count: usize,
+    pub ranking_dates: Vec<NaiveDate>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoStatistics {
+    pub video_id: String,
+    pub view_count: i64,
+    pub like_count: i64,
+    pub comment_count: i64,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoStatsRefreshSummary {
+    pub tracked_video_count: usize,
+    pub refreshed_video_count: usize,
+    pub unavailable_video_count: u64,
+}
+
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RawVideoArchiveFile {
+    pub archive_date: NaiveDate,
+    pub path: String,
+    pub archived_count: usize,
+    pub deleted_count: u64,
+}
+
+// 영상 분석 API 공통 조건 (게시 시각 범위는 [published_from, published_to))
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VideoAnalyticsFilter {
+    pub published_from: DateTime<Utc>,
+    pub published_to: DateTime<Utc>,
+    pub keyword: Option<String>,
+    pub category_id: Option<String>,
+}
+
+// UTC 기준 게시 날짜, 시각, 15분 구간별 합계 (15분 단위라 30분, 45분 시차가 있는 시간대도 정확히 나눌 수 있음)
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct PublishHourStats {
+    pub published_date: NaiveDate,
+    pub published_hour: i64,
+    pub published_minute: i64,
+    pub video_count: i64,
+    pub view_count: i64,
+    pub like_count: i64,
+    pub comment_count: i64,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct PublishTimeAnalyticsQuery {
+    // IANA 시간대 이름, 기본값 Asia/Seoul
+    pub timezone: Option<String>,
+    pub keyword: Option<String>,
+    pub category_id: Option<String>,
+    // 요청한 시간대 기준 날짜, 기본값 최근 30일
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishTimeSlotResponse {
+    pub weekday: String,
+    pub hour: u32,
+    pub video_count: i64,
+    pub total_view_count: i64,
+    pub average_view_count: f64,
+    // (좋아요 + 댓글) / 조회수
+    pub engagement_rate: f64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishTimeAnalyticsResponse {
+    pub timezone: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub keyword: Option<String>,
+    pub category_id: Option<String>,
+    pub video_count: i64,
+    pub slots: Vec<PublishTimeSlotResponse>,
+    pub best_slots: Vec<PublishTimeSlotResponse>,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeywordIdeaVideo {
+    pub title: String,
+    pub description: String,
+    pub view_count: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentIdeaResponse {
+    pub hook: String,
+    pub format: String,
+    pub suggested_title: String,
+    pub hashtags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordIdeasResponse {
+    pub keyword_text: String,
+    pub generated_date: NaiveDate,
+    pub reference_video_count: usize,
+    pub ideas: Vec<ContentIdeaResponse>,
+}
+
+#[derive(Debug, Clone)]
+pub struct KeywordSentimentSample {
+    pub keyword_text: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct YoutubeKeywordSentiment {
+    pub id: i64,
+    pub sentiment_date: NaiveDate,
+    pub keyword_text: String,
+    pub positive_ratio: f64,
+    pub neutral_ratio: f64,
+    pub negative_ratio: f64,
+    pub sample_count: i32,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordSentimentResponse {
+    // positive, neutral, negative 중 비율이 가장 높은 값
+    pub label: String,
+    pub positive_ratio: f64,
+    pub neutral_ratio: f64,
+    pub negative_ratio: f64,
+    pub sample_count: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct YoutubeVideoComment {
+    pub id: i64,
+    pub video_id: String,
+    pub comment_id: String,
+    pub author_display_name: String,
+    pub text_display: String,
+    pub like_count: i64,
+    pub reply_count: i64,
+    pub published_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct RankedKeywordVideo {
+    pub keyword_text: String,
+    pub video_id: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CommentKeywordStat {
+    pub keyword_text: String,
+    pub mention_count: i64,
+    pub video_count: i64,
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentCollectionSummary {
+    pub video_count: usize,
+    pub collected_video_count: usize,
+    pub comment_count: usize,
+    pub failed_video_count: usize,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct KeywordCommentsQuery {
+    pub keyword_text: String,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentKeywordResponse {
+    pub keyword_text: String,
+    pub mention_count: i64,
+    pub video_count: i64,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordCommentsResponse {
+    pub keyword_text: String,
+    pub comment_keywords: Vec<CommentKeywordResponse>,
+}
+
+pub const DURATION_METRIC_VIEWS: &str = "views";
+pub const DURATION_METRIC_LIKE_RATE: &str = "like_rate";
+pub const DURATION_METRIC_COMMENT_RATE: &str = "comment_rate";
+
+// 길이 구간, 지표별 백분위수 (percentile 은 0.5 또는 0.9), value_count 는 해당 지표를 계산한 영상 수
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DurationMetricPercentile {
+    pub metric: String,
+    pub bucket: i64,
+    pub value_count: i64,
+    pub percentile: f64,
+    pub value: f64,
+}
+
+#[derive(Deserialize, IntoParams)]
+pub struct DurationAnalyticsQuery {
+    // 날짜 범위를 해석할 IANA 시간대 이름, 기본값 Asia/Seoul
+    pub timezone: Option<String>,
+    pub keyword: Option<String>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributionSummary {
+    pub median: f64,
+    pub p90: f64,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DurationBucketResponse {
+    pub label: String,
+    pub min_seconds: i32,
+    // 마지막 구간은 상한 없음
+    pub max_seconds: Option<i32>,
+    pub video_count: usize,
+    pub views: DistributionSummary,
+    pub like_rate: DistributionSummary,
+    pub comment_rate: DistributionSummary,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DurationAnalyticsResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub keyword: Option<String>,
+    pub video_count: usize,
+    pub buckets: Vec<DurationBucketResponse>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct YoutubeKeywordWatchlist {
+    pub id: i64,
+    pub user_public_id: String,
+    pub keyword_text: String,
+    pub enter_top_n: Option<i32>,
+    pub climb_threshold: Option<i32>,
+    pub alert_on_drop_off: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordWatchlistRequest {
+    pub keyword_text: String,
+    // 순위가 N위 안으로 들어오면 알림
+    pub enter_top_n: Option<i32>,
+    // 전날보다 X계단 넘게 오르면 알림
+    pub climb_threshold: Option<i32>,
+    // 전날 순위표에 있다가 빠지면 알림
+    #[serde(default)]
+    pub alert_on_drop_off: bool,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordWatchlistResponse {
+    pub id: i64,
+    pub keyword_text: String,
+    pub enter_top_n: Option<i32>,
+    pub climb_threshold: Option<i32>,
+    pub alert_on_drop_off: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeywordAlertType {
+    EnterTopN,
+    Climb,
+    DropOff,
+}
+
+impl KeywordAlertType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeywordAlertType::EnterTopN => "enter_top_n",
+            KeywordAlertType::Climb => "climb",
+            KeywordAlertType::DropOff => "drop_off",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewKeywordAlert {
+    pub watchlist_id: i64,
+    pub user_public_id: String,
+    pub keyword_text: String,
+    pub alert_type: KeywordAlertType,
+    pub previous_ranking: Option<i32>,
+    pub current_ranking: Option<i32>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct YoutubeKeywordAlert {
+    pub id: i64,
+    pub watchlist_id: i64,
+    pub keyword_text: String,
+    pub alert_type: String,
+    pub ranking_date: NaiveDate,
+    pub previous_ranking: Option<i32>,
+    pub current_ranking: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct KeywordAlertResponse {
+    pub id: i64,
+    pub watchlist_id: i64,
+    pub keyword_text: String,
+    // enter_top_n, climb, drop_off
+    pub alert_type: String,
+    pub ranking_date: NaiveDate,
+    pub previous_ranking: Option<i32>,
+    pub current_ranking: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
 impl From<&VideoItem> for YoutubeRawVideo {
     fn from(item: &VideoItem) -> Self {
         let raw_metadata_json = serde_json::to_value(item)
@@ -88,6 +441,25 @@ impl From<&VideoItem> for YoutubeRawVideo {
     }
 }
 
+impl TryFrom<&YoutubeRawVideo> for VideoItem {
+    type Error = serde_json::Error;
+    
+    fn try_from(raw_video: &YoutubeRawVideo) -> Result<Self, Self::Error> {
+        serde_json::from_value(raw_video.raw_metadata.clone())
+    }
+}
+
+impl From<&VideoItem> for VideoStatistics {
+    fn from(item: &VideoItem) -> Self {
+        Self {
+            video_id: item.id.clone(),
+            view_count: item.statistics.view_count.parse().unwrap_or(0),
+            like_count: item.statistics.like_count.as_ref().map_or(0, |s| s.parse().unwrap_or(0)),
+            comment_count: item.statistics.comment_count.as_ref().map_or(0, |s| s.parse().unwrap_or(0)),
+        }
+    }
+}
+
 impl From<&VideoItem> for YoutubeVideo {
     fn from(item: &VideoItem) -> Self {
         let duration_in_seconds = item.content_details.as_seconds();
@@ -111,6 +483,7 @@ impl From<&VideoItem> for YoutubeVideo {
             comment_count: comment_count,
             embed_html: item.player.embed_html.clone(),
             topic_categories: item.topic_details.as_ref().map(|details| details.topic_categories.clone()),
+            category_id: Some(item.snippet.category_id.clone()),
             created_at: Default::default(),
             updated_at: Default::default(),
         }
@@ -124,6 +497,97 @@ impl From<(YoutubeKeywordRanking, RankChange)> for KeywordRankingResponse {
             keyword_text: ranking_data.keyword_text,
             score: ranking_data.score,
             rank_change: rank_change,
+            sentiment: None,
+        }
+    }
+}
+
+impl TryFrom<&CommentThreadItem> for YoutubeVideoComment {
+    type Error = chrono::ParseError;
+    
+    fn try_from(item: &CommentThreadItem) -> Result<Self, Self::Error> {
+        let comment = &item.snippet.top_level_comment.snippet;
+        
+        Ok(Self {
+            id: 0,
+            video_id: item.snippet.video_id.clone(),
+            comment_id: item.id.clone(),
+            author_display_name: comment.author_display_name.clone(),
+            text_display: comment.text_display.clone(),
+            like_count: comment.like_count,
+            reply_count: item.snippet.total_reply_count,
+            published_at: comment.published_at.parse::<DateTime<Utc>>()?,
+        })
+    }
+}
+
+impl From<CommentKeywordStat> for CommentKeywordResponse {
+    fn from(stat: CommentKeywordStat) -> Self {
+        Self {
+            keyword_text: stat.keyword_text,
+            mention_count: stat.mention_count,
+            video_count: stat.video_count,
+        }
+    }
+}
+
+impl From<&YoutubeKeywordSentiment> for KeywordSentimentResponse {
+    fn from(sentiment: &YoutubeKeywordSentiment) -> Self {
+        // 동률이면 neutral 우선
+        let label = if sentiment.positive_ratio > sentiment.neutral_ratio && sentiment.positive_ratio >= sentiment.negative_ratio {
+            "positive"
+        } else if sentiment.negative_ratio > sentiment.neutral_ratio && sentiment.negative_ratio > sentiment.positive_ratio {
+            "negative"
+        } else {
+            "neutral"
+        };
+        
+        Self {
+            label: label.to_string(),
+            positive_ratio: sentiment.positive_ratio,
+            neutral_ratio: sentiment.neutral_ratio,
+            negative_ratio: sentiment.negative_ratio,
+            sample_count: sentiment.sample_count,
+        }
+    }
+}
+
+impl From<GeminiContentIdea> for ContentIdeaResponse {
+    fn from(idea: GeminiContentIdea) -> Self {
+        Self {
+            hook: idea.hook,
+            format: idea.format,
+            suggested_title: idea.suggested_title,
+            hashtags: idea.hashtags,
+        }
+    }
+}
+
+impl From<&YoutubeKeywordWatchlist> for KeywordWatchlistResponse {
+    fn from(watchlist: &YoutubeKeywordWatchlist) -> Self {
+        Self {
+            id: watchlist.id,
+            keyword_text: watchlist.keyword_text.clone(),
+            enter_top_n: watchlist.enter_top_n,
+            climb_threshold: watchlist.climb_threshold,
+            alert_on_drop_off: watchlist.alert_on_drop_off,
+            created_at: watchlist.created_at,
+            updated_at: watchlist.updated_at,
+        }
+    }
+}
+
+impl From<YoutubeKeywordAlert> for KeywordAlertResponse {
+    fn from(alert: YoutubeKeywordAlert) -> Self {
+        Self {
+            id: alert.id,
+            watchlist_id: alert.watchlist_id,
+            keyword_text: alert.keyword_text,
+            alert_type: alert.alert_type,
+            ranking_date: alert.ranking_date,
+            previous_ranking: alert.previous_ranking,
+            current_ranking: alert.current_ranking,
+            created_at: alert.created_at,
         }
     }
 }
\ No newline at end of file
@@ -1,22 +1,62 @@
 use std::collections::HashMap;
 use async_trait::async_trait;
-use chrono::NaiveDate;
+use chrono::{DateTime, NaiveDate, Utc};
 use mockall::automock;
-use sqlx::{Error, MySqlPool};
-use crate::youtube::youtube_video::youtube_video_model::{KeywordTrend, YoutubeKeyword, YoutubeKeywordRanking, YoutubeVideo};
+use sqlx::mysql::MySqlArguments;
+use sqlx::query::QueryAs;
+use sqlx::{Error, MySql, MySqlPool};
+use crate::youtube::youtube_video::youtube_video_model::{DurationMetricPercentile, KeywordIdeaVideo, KeywordSentimentSample, KeywordTrend, PublishHourStats, VideoAnalyticsFilter, VideoStatistics, YoutubeKeyword, YoutubeKeywordRanking, YoutubeKeywordSentiment, YoutubeVideo, DURATION_METRIC_COMMENT_RATE, DURATION_METRIC_LIKE_RATE, DURATION_METRIC_VIEWS};
 
 #[automock]
 #[async_trait]
 pub trait YoutubeVideoRepository: Send + Sync {
     async fn save_video_and_keywords(&self, youtube_video: YoutubeVideo, keywords: Vec<YoutubeKeyword>) -> Result<(), Error>;
     
-    async fn get_keyword_trends(&self, since: NaiveDate, limit: u32) -> Result<Vec<KeywordTrend>, Error>;
+    async fn get_keyword_trends(&self, since: NaiveDate, until: NaiveDate, limit: u32) -> Result<Vec<KeywordTrend>, Error>;
     
-    async fn save_keyword_rankings(&self, rankings: &[YoutubeKeywordRanking]) -> Result<(), Error>;
+    async fn replace_keyword_rankings(
+        &self,
+        ranking_date: NaiveDate,
+        region_code: &str,
+        strategy: &str,
+        rankings: &[YoutubeKeywordRanking]
+    ) -> Result<(), Error>;
+    
+    async fn get_keyword_rankings(&self, date: NaiveDate, region_code: &str, strategy: &str, limit: u32) -> Result<Vec<YoutubeKeywordRanking>, Error>;
+    
+    async fn find_latest_keyword_rankings(&self, region_code: &str, strategy: &str, limit: u32) -> Result<Vec<YoutubeKeywordRanking>, Error>;
+    
+    async fn find_latest_ranking_date_before(&self, date: NaiveDate, region_code: &str, strategy: &str) -> Result<Option<NaiveDate>, Error>;
+    
+    async fn today_ranking_exists(&self, date: NaiveDate, region_code: &str, strategy: &str) -> Result<bool, Error>;
+    
+    async fn find_ranking_dates_between(&self, from: NaiveDate, to: NaiveDate, region_code: &str, strategy: &str) -> Result<Vec<NaiveDate>, Error>;
+    
+    async fn find_tracked_video_ids(&self, published_since: DateTime<Utc>) -> Result<Vec<String>, Error>;
+    
+    async fn update_video_statistics(&self, statistics: &[VideoStatistics], refreshed_at: DateTime<Utc>) -> Result<u64, Error>;
+    
+    async fn mark_videos_unavailable(&self, video_ids: &[String], checked_at: DateTime<Utc>) -> Result<u64, Error>;
+    
+    async fn find_publish_hour_stats(&self, filter: &VideoAnalyticsFilter) -> Result<Vec<PublishHourStats>, Error>;
+    
+    async fn find_duration_percentiles(&self, filter: &VideoAnalyticsFilter, bucket_seconds: i32, last_bucket: i32) -> Result<Vec<DurationMetricPercentile>, Error>;
+    
+    async fn find_keyword_sentiment_samples(
+        &self,
+        ranking_date: NaiveDate,
+        region_code: &str,
+        strategy: &str,
+        videos_per_keyword: u32,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<KeywordSentimentSample>, Error>;
+    
+    async fn replace_keyword_sentiments(&self, sentiment_date: NaiveDate, sentiments: &[YoutubeKeywordSentiment]) -> Result<(), Error>;
     
-    async fn get_keyword_rankings(&self, date: NaiveDate, limit: u32) -> Result<Vec<YoutubeKeywordRanking>, Error>;
+    async fn find_keyword_sentiments(&self, sentiment_date: NaiveDate) -> Result<Vec<YoutubeKeywordSentiment>, Error>;
     
-    async fn today_ranking_exists(&self, date: NaiveDate) -> Result<bool, Error>;
+    async fn find_top_videos_by_keyword(&self, keyword_text: &str, since: NaiveDate, until: NaiveDate, limit: u32) -> Result<Vec<KeywordIdeaVideo>, Error>;
 }
 
 #[derive(Clone)]
@@ -50,21 +90,25 @@ impl YoutubeVideoRepository for YoutubeVideoSqlxRepository {
         let topics_json: Option<String> = youtube_video.topic_categories.as_ref()
                                                .and_then(|topics_vec| serde_json::to_string(topics_vec).ok());
         
-        // 영상 정보 저장
+        // 영상 정보 저장, 재처리로 들어온 예전 데이터가 더 최신 통계와 삭제/비공개 표시를 덮어쓰지 않도록 갱신 시각을 비교
+        // (MySQL 은 SET 을 왼쪽부터 적용하므로 updated_at 은 마지막에 갱신)
         let video_id = sqlx::query!(
             r#"
                 INSERT INTO youtube_videos (
                     video_id, published_at, channel_id, title, description, channel_title,
-                    tags, duration, view_count, like_count, comment_count, embed_html, topic_categories
-                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    tags, duration, view_count, like_count, comment_count, embed_html, topic_categories, category_id, updated_at
+                ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
                 ON DUPLICATE KEY UPDATE
-                    title = VALUES(title),
-                    description = VALUES(description),
-                    tags = VALUES(tags),
-                    view_count = VALUES(view_count),
-                    like_count = VALUES(like_count),
-                    comment_count = VALUES(comment_count),
-                    updated_at = CURRENT_TIMESTAMP,
+                    title = IF(VALUES(updated_at) >= updated_at, VALUES(title), title),
+                    category_id = IF(VALUES(updated_at) >= updated_at, VALUES(category_id), category_id),
+                    description = IF(VALUES(updated_at) >= updated_at, VALUES(description), description),
+                    tags = IF(VALUES(updated_at) >= updated_at, VALUES(tags), tags),
+                    view_count = IF(VALUES(updated_at) >= updated_at, VALUES(view_count), view_count),
+                    like_count = IF(VALUES(updated_at) >= updated_at, VALUES(like_count), like_count),
+                    comment_count = IF(VALUES(updated_at) >= updated_at, VALUES(comment_count), comment_count),
+                    is_available = IF(VALUES(updated_at) > COALESCE(unavailable_at, updated_at), TRUE, is_available),
+                    unavailable_at = IF(VALUES(updated_at) > COALESCE(unavailable_at, updated_at), NULL, unavailable_at),
+                    updated_at = GREATEST(updated_at, VALUES(updated_at)),
                     id = LAST_INSERT_ID(id)
             "#,
             youtube_video.video_id,
@@ -79,7 +123,9 @@ impl YoutubeVideoRepository for YoutubeVideoSqlxRepository {
             youtube_video.like_count,
             youtube_video.comment_count,
             youtube_video.embed_html,
-            topics_json
+            topics_json,
+            youtube_video.category_id,
+            youtube_video.updated_at
         )
             .execute(&mut *tx)
             .await?
@@ -152,6 +198,7 @@ impl YoutubeVideoRepository for YoutubeVideoSqlxRepository {
     async fn get_keyword_trends(
         &self,
         since: NaiveDate,
+        until: NaiveDate,
         limit: u32
     ) -> Result<Vec<KeywordTrend>, Error> {
         let trends = sqlx::query_as!(
@@ -162,12 +209,14 @@ impl YoutubeVideoRepository for YoutubeVideoSqlxRepository {
                 FROM youtube_videos AS yv
                 JOIN youtube_video_keywords AS yvk ON yv.id = yvk.video_id
                 JOIN youtube_keywords AS yk ON yvk.keyword_id = yk.id
-                WHERE yv.updated_at >= ?
+                WHERE yv.updated_at >= ? AND yv.updated_at < ?
+                  AND yv.is_available = TRUE
                 GROUP BY yk.keyword_text
                 ORDER BY total_views DESC
                 LIMIT ?;
             "#,
             since,
+            until,
             limit
         )
             .fetch_all(&self.db_pool)
@@ -176,22 +225,77 @@ impl YoutubeVideoRepository for YoutubeVideoSqlxRepository {
         Ok(trends)
     }
     
-    async fn save_keyword_rankings(&self, rankings: &[YoutubeKeywordRanking]) -> Result<(), Error> {
+    async fn replace_keyword_rankings(
+        &self,
+        ranking_date: NaiveDate,
+        region_code: &str,
+        strategy: &str,
+        rankings: &[YoutubeKeywordRanking]
+    ) -> Result<(), Error> {
+        let mut tx = self.db_pool.begin().await?;
+        
+        // 같은 날짜의 순위표를 동시에 교체하지 못하도록 잠금
+        sqlx::query!(
+            r#"
+                SELECT id
+                FROM youtube_keyword_rankings
+                WHERE ranking_date = ? AND region_code = ? AND strategy = ?
+                FOR UPDATE
+            "#,
+            ranking_date,
+            region_code,
+            strategy
+        )
+            .fetch_all(&mut *tx)
+            .await?;
+        
+        // 기존 순위표 아카이브
+        sqlx::query!(
+            r#"
+                INSERT INTO youtube_keyword_rankings_archive (
+                    original_id, ranking_date, region_code, strategy, ranking, keyword_id, keyword_text, score
+                )
+                SELECT id, ranking_date, region_code, strategy, ranking, keyword_id, keyword_text, score
+                FROM youtube_keyword_rankings
+                WHERE ranking_date = ? AND region_code = ? AND strategy = ?
+            "#,
+            ranking_date,
+            region_code,
+            strategy
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE
+                FROM youtube_keyword_rankings
+                WHERE ranking_date = ? AND region_code = ? AND strategy = ?
+            "#,
+            ranking_date,
+            region_code,
+            strategy
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        // 새 순위표 저장 (빈 순위표면 기존 순위표만 비움)
         if rankings.is_empty() {
+            tx.commit().await?;
             return Ok(());
         }
         
-        let mut tx = self.db_pool.begin().await?;
-        
         let mut query_builder = String::from(
-          "INSERT INTO youtube_keyword_rankings (ranking_date, ranking, keyword_id, keyword_text, score) VALUES "
+          "INSERT INTO youtube_keyword_rankings (ranking_date, region_code, strategy, ranking, keyword_id, keyword_text, score) VALUES "
         );
-        query_builder.push_str(&vec!["(?, ?, ?, ?, ?)"; rankings.len()].join(", "));
+        query_builder.push_str(&vec!["(?, ?, ?, ?, ?, ?, ?)"; rankings.len()].join(", "));
         
         let mut query = sqlx::query(&query_builder);
         for rank in rankings {
             query = query
-                .bind(rank.ranking_date)
+                .bind(ranking_date)
+                .bind(region_code)
+                .bind(strategy)
                 .bind(rank.ranking)
                 .bind(rank.keyword_id)
                 .bind(&rank.keyword_text)
@@ -204,17 +308,19 @@ impl YoutubeVideoRepository for YoutubeVideoSqlxRepository {
         Ok(())
     }
     
-    async fn get_keyword_rankings(&self, date: NaiveDate, limit: u32) -> Result<Vec<YoutubeKeywordRanking>, Error> {
+    async fn get_keyword_rankings(&self, date: NaiveDate, region_code: &str, strategy: &str, limit: u32) -> Result<Vec<YoutubeKeywordRanking>, Error> {
         let rankings = sqlx::query_as!(
             YoutubeKeywordRanking,
             r#"
-                SELECT id, ranking_date, ranking, keyword_id, keyword_text, score
+                SELECT id, ranking_date, region_code, strategy, ranking, keyword_id, keyword_text, score
                 FROM youtube_keyword_rankings
-                WHERE ranking_date = ?
+                WHERE ranking_date = ? AND region_code = ? AND strategy = ?
                 ORDER BY ranking
                 LIMIT ?;
             "#,
             date,
+            region_code,
+            strategy,
             limit
         )
             .fetch_all(&self.db_pool)
@@ -223,18 +329,785 @@ impl YoutubeVideoRepository for YoutubeVideoSqlxRepository {
         Ok(rankings)
     }
     
-    async fn today_ranking_exists(&self, date: NaiveDate) -> Result<bool, Error> {
+    async fn find_latest_keyword_rankings(&self, region_code: &str, strategy: &str, limit: u32) -> Result<Vec<YoutubeKeywordRanking>, Error> {
+        let rankings = sqlx::query_as!(
+            YoutubeKeywordRanking,
+            r#"
+                SELECT id, ranking_date, region_code, strategy, ranking, keyword_id, keyword_text, score
+                FROM youtube_keyword_rankings
+                WHERE region_code = ? AND strategy = ?
+                  AND ranking_date = (
+                      SELECT MAX(ranking_date)
+                      FROM youtube_keyword_rankings
+                      WHERE region_code = ? AND strategy = ?
+                  )
+                ORDER BY ranking
+                LIMIT ?
+            "#,
+            region_code,
+            strategy,
+            region_code,
+            strategy,
+            limit
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(rankings)
+    }
+    
+    async fn find_latest_ranking_date_before(&self, date: NaiveDate, region_code: &str, strategy: &str) -> Result<Option<NaiveDate>, Error> {
+        let ranking_date = sqlx::query_scalar!(
+            r#"
+                SELECT ranking_date
+                FROM youtube_keyword_rankings
+                WHERE ranking_date < ? AND region_code = ? AND strategy = ?
+                ORDER BY ranking_date DESC
+                LIMIT 1
+            "#,
+            date,
+            region_code,
+            strategy
+        )
+            .fetch_optional(&self.db_pool)
+            .await?;
+        
+        Ok(ranking_date)
+    }
+    
+    async fn today_ranking_exists(&self, date: NaiveDate, region_code: &str, strategy: &str) -> Result<bool, Error> {
         let result = sqlx::query!(
             r#"
-                SELECT *
+                SELECT id
                 FROM youtube_keyword_rankings
-                WHERE ranking_date = ?
+                WHERE ranking_date = ? AND region_code = ? AND strategy = ?
+                LIMIT 1
             "#,
-            date
+            date,
+            region_code,
+            strategy
         )
             .fetch_optional(&self.db_pool)
             .await?;
         
         Ok(result.is_some())
     }
+    
+    async fn find_ranking_dates_between(&self, from: NaiveDate, to: NaiveDate, region_code: &str, strategy: &str) -> Result<Vec<NaiveDate>, Error> {
+        let ranking_dates = sqlx::query_scalar!(
+            r#"
+                SELECT DISTINCT ranking_date
+                FROM youtube_keyword_rankings
+                WHERE ranking_date BETWEEN ? AND ? AND region_code = ? AND strategy = ?
+                ORDER BY ranking_date
+            "#,
+            from,
+            to,
+            region_code,
+            strategy
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(ranking_dates)
+    }
+    
+    async fn find_tracked_video_ids(&self, published_since: DateTime<Utc>) -> Result<Vec<String>, Error> {
+        let video_ids = sqlx::query_scalar!(
+            r#"
+                SELECT video_id
+                FROM youtube_videos
+                WHERE published_at >= ? AND is_available = TRUE
+                ORDER BY id
+            "#,
+            published_since
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(video_ids)
+    }
+    
+    // 수집 시각(updated_at)은 순위 기간 계산에 쓰이므로 그대로 두고 통계만 갱신
+    // 통계 값이 실제로 바뀐 영상 수 반환 (갱신 시각만 바뀐 영상은 세지 않음)
+    // MySQL 드라이버는 일치한 행 수를 돌려주므로 값이 같은 행은 조건에서 제외
+    async fn update_video_statistics(&self, statistics: &[VideoStatistics], refreshed_at: DateTime<Utc>) -> Result<u64, Error> {
+        if statistics.is_empty() {
+            return Ok(0);
+        }
+        
+        let mut tx = self.db_pool.begin().await?;
+        let mut changed_count = 0;
+        
+        for video_statistics in statistics {
+            changed_count += sqlx::query!(
+                r#"
+                    UPDATE youtube_videos
+                    SET view_count = ?,
+                        like_count = ?,
+                        comment_count = ?,
+                        updated_at = updated_at
+                    WHERE video_id = ?
+                      AND (view_count <> ? OR like_count <> ? OR comment_count <> ?)
+                "#,
+                video_statistics.view_count,
+                video_statistics.like_count,
+                video_statistics.comment_count,
+                video_statistics.video_id,
+                video_statistics.view_count,
+                video_statistics.like_count,
+                video_statistics.comment_count
+            )
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+        }
+        
+        let query_str = format!(
+            "UPDATE youtube_videos SET stats_refreshed_at = ?, updated_at = updated_at WHERE video_id IN ({})",
+            vec!["?"; statistics.len()].join(", ")
+        );
+        let mut query = sqlx::query(&query_str).bind(refreshed_at);
+        for video_statistics in statistics {
+            query = query.bind(&video_statistics.video_id);
+        }
+        query.execute(&mut *tx).await?;
+        
+        tx.commit().await?;
+        
+        Ok(changed_count)
+    }
+    
+    async fn mark_videos_unavailable(&self, video_ids: &[String], checked_at: DateTime<Utc>) -> Result<u64, Error> {
+        if video_ids.is_empty() {
+            return Ok(0);
+        }
+        
+        let query_str = format!(
+            "UPDATE youtube_videos SET is_available = FALSE, unavailable_at = ?, updated_at = updated_at WHERE video_id IN ({})",
+            vec!["?"; video_ids.len()].join(", ")
+        );
+        let mut query = sqlx::query(&query_str).bind(checked_at);
+        for video_id in video_ids {
+            query = query.bind(video_id);
+        }
+        
+        let result = query.execute(&self.db_pool).await?;
+        
+        Ok(result.rows_affected())
+    }
+    
+    // 시간대 변환은 서비스에서 하도록 UTC 날짜, 시각, 15분 구간 단위로만 합산 (커넥션 time_zone 은 UTC)
+    async fn find_publish_hour_stats(&self, filter: &VideoAnalyticsFilter) -> Result<Vec<PublishHourStats>, Error> {
+        let query_str = format!(
+            "SELECT DATE(yv.published_at) AS published_date, \
+                    CAST(HOUR(yv.published_at) AS SIGNED) AS published_hour, \
+                    CAST(FLOOR(MINUTE(yv.published_at) / 15) * 15 AS SIGNED) AS published_minute, \
+                    COUNT(*) AS video_count, \
+                    CAST(SUM(yv.view_count) AS SIGNED) AS view_count, \
+                    CAST(SUM(yv.like_count) AS SIGNED) AS like_count, \
+                    CAST(SUM(yv.comment_count) AS SIGNED) AS comment_count \
+             FROM youtube_videos AS yv \
+             WHERE {} \
+             GROUP BY published_date, published_hour, published_minute",
+            video_analytics_clause(filter)
+        );
+        
+        let stats = bind_video_analytics_filter(sqlx::query_as::<_, PublishHourStats>(&query_str), filter)
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(stats)
+    }
+    
+    // 길이 구간(bucket_seconds 단위, last_bucket 이상은 마지막 구간)과 지표별 중앙값, p90 을 SQL 에서 계산
+    // 구간 안에서 값 순서를 매긴 뒤 statistics::percentile 과 같은 선형 보간으로 두 순위 사이 값을 구함
+    // 조회수 0 인 영상은 좋아요율, 댓글율 계산에서 제외
+    async fn find_duration_percentiles(&self, filter: &VideoAnalyticsFilter, bucket_seconds: i32, last_bucket: i32) -> Result<Vec<DurationMetricPercentile>, Error> {
+        let query_str = format!(
+            "WITH base AS ( \
+                 SELECT LEAST(FLOOR(GREATEST(yv.duration, 0) / ?), ?) AS bucket, yv.view_count, yv.like_count, yv.comment_count \
+                 FROM youtube_videos AS yv \
+                 WHERE {} \
+             ), \
+             metric_values AS ( \
+                 SELECT '{}' AS metric, bucket, CAST(view_count AS DOUBLE) AS value FROM base \
+                 UNION ALL \
+                 SELECT '{}', bucket, CAST(like_count AS DOUBLE) / view_count FROM base WHERE view_count > 0 \
+                 UNION ALL \
+                 SELECT '{}', bucket, CAST(comment_count AS DOUBLE) / view_count FROM base WHERE view_count > 0 \
+             ), \
+             ranked AS ( \
+                 SELECT metric, bucket, value, \
+                        CAST(ROW_NUMBER() OVER (PARTITION BY metric, bucket ORDER BY value) AS SIGNED) - 1 AS value_index, \
+                        COUNT(*) OVER (PARTITION BY metric, bucket) AS value_count \
+                 FROM metric_values \
+             ), \
+             positions AS ( \
+                 SELECT r.metric, r.bucket, r.value, r.value_index, r.value_count, p.percentile, \
+                        p.percentile * (r.value_count - 1) AS position \
+                 FROM ranked AS r \
+                 CROSS JOIN (SELECT 0.5 AS percentile UNION ALL SELECT 0.9) AS p \
+             ) \
+             SELECT metric, CAST(bucket AS SIGNED) AS bucket, value_count, CAST(percentile AS DOUBLE) AS percentile, \
+                    CAST(SUM(CASE \
+                        WHEN value_index = FLOOR(position) THEN value * (1 - (position - FLOOR(position))) \
+                        WHEN value_index = FLOOR(position) + 1 THEN value * (position - FLOOR(position)) \
+                        ELSE 0 \
+                    END) AS DOUBLE) AS value \
+             FROM positions \
+             GROUP BY metric, bucket, value_count, percentile",
+            video_analytics_clause(filter),
+            DURATION_METRIC_VIEWS,
+            DURATION_METRIC_LIKE_RATE,
+            DURATION_METRIC_COMMENT_RATE
+        );
+        
+        let query = sqlx::query_as::<_, DurationMetricPercentile>(&query_str)
+            .bind(bucket_seconds)
+            .bind(last_bucket);
+        let percentiles = bind_video_analytics_filter(query, filter)
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(percentiles)
+    }
+    
+    // 순위표 키워드마다 조회수 상위 영상의 제목과 저장된 댓글
+    async fn find_keyword_sentiment_samples(
+        &self,
+        ranking_date: NaiveDate,
+        region_code: &str,
+        strategy: &str,
+        videos_per_keyword: u32,
+        since: NaiveDate,
+        until: NaiveDate,
+    ) -> Result<Vec<KeywordSentimentSample>, Error> {
+        let samples = sqlx::query_as!(
+            KeywordSentimentSample,
+            r#"
+                WITH top_videos AS (
+                    SELECT ranked.keyword_text, ranked.video_id, ranked.title
+                    FROM (
+                        SELECT ykr.keyword_text, yv.video_id, yv.title,
+                               ROW_NUMBER() OVER (PARTITION BY ykr.keyword_id ORDER BY yv.view_count DESC) AS video_rank
+                        FROM youtube_keyword_rankings AS ykr
+                        JOIN youtube_video_keywords AS yvk ON yvk.keyword_id = ykr.keyword_id
+                        JOIN youtube_videos AS yv ON yv.id = yvk.video_id
+                        WHERE ykr.ranking_date = ? AND ykr.region_code = ? AND ykr.strategy = ?
+                          AND yv.updated_at >= ? AND yv.updated_at < ?
+                          AND yv.is_available = TRUE
+                    ) AS ranked
+                    WHERE ranked.video_rank <= ?
+                )
+                SELECT tv.keyword_text, tv.title AS text
+                FROM top_videos AS tv
+                UNION ALL
+                SELECT tv.keyword_text, yvc.text_display AS text
+                FROM top_videos AS tv
+                JOIN youtube_video_comments AS yvc ON yvc.video_id = tv.video_id
+            "#,
+            ranking_date,
+            region_code,
+            strategy,
+            since,
+            until,
+            videos_per_keyword
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(samples)
+    }
+    
+    async fn replace_keyword_sentiments(&self, sentiment_date: NaiveDate, sentiments: &[YoutubeKeywordSentiment]) -> Result<(), Error> {
+        if sentiments.is_empty() {
+            return Ok(());
+        }
+        
+        let mut tx = self.db_pool.begin().await?;
+        
+        sqlx::query!(
+            r#"
+                DELETE
+                FROM youtube_keyword_sentiments
+                WHERE sentiment_date = ?
+            "#,
+            sentiment_date
+        )
+            .execute(&mut *tx)
+            .await?;
+        
+        let mut query_builder = String::from(
+            "INSERT INTO youtube_keyword_sentiments \
+             (sentiment_date, keyword_text, positive_ratio, neutral_ratio, negative_ratio, sample_count) VALUES "
+        );
+        query_builder.push_str(&vec!["(?, ?, ?, ?, ?, ?)"; sentiments.len()].join(", "));
+        
+        let mut query = sqlx::query(&query_builder);
+        for sentiment in sentiments {
+            query = query
+                .bind(sentiment_date)
+                .bind(&sentiment.keyword_text)
+                .bind(sentiment.positive_ratio)
+                .bind(sentiment.neutral_ratio)
+                .bind(sentiment.negative_ratio)
+                .bind(sentiment.sample_count);
+        }
+        
+        query.execute(&mut *tx).await?;
+        tx.commit().await?;
+        
+        Ok(())
+    }
+    
+    async fn find_keyword_sentiments(&self, sentiment_date: NaiveDate) -> Result<Vec<YoutubeKeywordSentiment>, Error> {
+        let sentiments = sqlx::query_as!(
+            YoutubeKeywordSentiment,
+            r#"
+                SELECT id, sentiment_date, keyword_text, positive_ratio, neutral_ratio, negative_ratio, sample_count
+                FROM youtube_keyword_sentiments
+                WHERE sentiment_date = ?
+            "#,
+            sentiment_date
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(sentiments)
+    }
+    
+    async fn find_top_videos_by_keyword(&self, keyword_text: &str, since: NaiveDate, until: NaiveDate, limit: u32) -> Result<Vec<KeywordIdeaVideo>, Error> {
+        let videos = sqlx::query_as!(
+            KeywordIdeaVideo,
+            r#"
+                SELECT yv.title, yv.description, yv.view_count
+                FROM youtube_keywords AS yk
+                JOIN youtube_video_keywords AS yvk ON yvk.keyword_id = yk.id
+                JOIN youtube_videos AS yv ON yv.id = yvk.video_id
+                WHERE yk.keyword_text = ?
+                  AND yv.updated_at >= ? AND yv.updated_at < ?
+                  AND yv.is_available = TRUE
+                ORDER BY yv.view_count DESC
+                LIMIT ?
+            "#,
+            keyword_text,
+            since,
+            until,
+            limit
+        )
+            .fetch_all(&self.db_pool)
+            .await?;
+        
+        Ok(videos)
+    }
+}
+
+// 영상 분석 API 들이 같은 조건을 쓰도록 WHERE 절과 바인딩을 한 곳에서 관리
+fn video_analytics_clause(filter: &VideoAnalyticsFilter) -> String {
+    let mut conditions = vec![
+        "yv.is_available = TRUE",
+        "yv.published_at >= ?",
+        "yv.published_at < ?",
+    ];
+    if filter.keyword.is_some() {
+        conditions.push(
+            "EXISTS (SELECT 1 FROM youtube_video_keywords AS yvk \
+                     JOIN youtube_keywords AS yk ON yk.id = yvk.keyword_id \
+                     WHERE yvk.video_id = yv.id AND yk.keyword_text = ?)"
+        );
+    }
+    if filter.category_id.is_some() {
+        conditions.push("yv.category_id = ?");
+    }
+    
+    conditions.join(" AND ")
+}
+
+fn bind_video_analytics_filter<'q, O>(
+    query: QueryAs<'q, MySql, O, MySqlArguments>,
+    filter: &VideoAnalyticsFilter,
+) -> QueryAs<'q, MySql, O, MySqlArguments> {
+    let mut query = query
+        .bind(filter.published_from)
+        .bind(filter.published_to);
+    if let Some(keyword) = &filter.keyword {
+        query = query.bind(keyword.clone());
+    }
+    if let Some(category_id) = &filter.category_id {
+        query = query.bind(category_id.clone());
+    }
+    
+    query
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeDelta;
+    use crate::common::test_support::test_keyword_ranking;
+    use crate::youtube::youtube_video::youtube_video_model::{RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM};
+
+    async fn init_schema(pool: &MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_keyword_rankings (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                ranking_date DATE NOT NULL,
+                region_code VARCHAR(8) NOT NULL DEFAULT 'KR',
+                strategy VARCHAR(32) NOT NULL DEFAULT 'weekly_view_sum',
+                ranking INT NOT NULL,
+                keyword_id BIGINT NOT NULL,
+                keyword_text VARCHAR(255) NOT NULL,
+                score BIGINT NOT NULL,
+                UNIQUE KEY uk_keyword_rankings_scope (ranking_date, region_code, strategy, ranking)
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+        
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_keyword_rankings_archive (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                original_id BIGINT NOT NULL,
+                ranking_date DATE NOT NULL,
+                region_code VARCHAR(8) NOT NULL,
+                strategy VARCHAR(32) NOT NULL,
+                ranking INT NOT NULL,
+                keyword_id BIGINT NOT NULL,
+                keyword_text VARCHAR(255) NOT NULL,
+                score BIGINT NOT NULL,
+                archived_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+            )
+        ")
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+    
+    #[sqlx::test]
+    async fn replace_keyword_rankings_replaces_chart_and_archives_previous(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_video_repository = YoutubeVideoSqlxRepository::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        
+        let first_run = vec![
+            test_keyword_ranking(date, 1, "first", 300),
+            test_keyword_ranking(date, 2, "second", 200),
+            test_keyword_ranking(date, 3, "third", 100),
+        ];
+        youtube_video_repository
+            .replace_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &first_run)
+            .await
+            .unwrap();
+        
+        let second_run = vec![test_keyword_ranking(date, 1, "second", 500), test_keyword_ranking(date, 2, "first", 400)];
+        let result = youtube_video_repository
+            .replace_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &second_run)
+            .await;
+        assert!(result.is_ok());
+        
+        let rankings = youtube_video_repository
+            .get_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, 100)
+            .await
+            .unwrap();
+        assert_eq!(rankings.len(), 2);
+        assert_eq!(rankings[0].keyword_text, "second");
+        assert_eq!(rankings[1].keyword_text, "first");
+        
+        let (archived,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM youtube_keyword_rankings_archive WHERE ranking_date = ?")
+            .bind(date)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(archived, 3);
+    }
+    
+    #[sqlx::test]
+    async fn replace_keyword_rankings_with_empty_chart_clears_previous(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_video_repository = YoutubeVideoSqlxRepository::new(pool.clone());
+        let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        
+        youtube_video_repository
+            .replace_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &[test_keyword_ranking(date, 1, "first", 100)])
+            .await
+            .unwrap();
+        youtube_video_repository
+            .replace_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &[])
+            .await
+            .unwrap();
+        
+        let rankings = youtube_video_repository
+            .get_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, 100)
+            .await
+            .unwrap();
+        assert!(rankings.is_empty());
+        
+        let (archived,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM youtube_keyword_rankings_archive WHERE ranking_date = ?")
+            .bind(date)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(archived, 1);
+    }
+    
+    #[sqlx::test]
+    async fn replace_keyword_rankings_keeps_other_scopes(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_video_repository = YoutubeVideoSqlxRepository::new(pool);
+        let date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        let previous_date = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap();
+        
+        youtube_video_repository
+            .replace_keyword_rankings(previous_date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &[test_keyword_ranking(previous_date, 1, "yesterday", 100)])
+            .await
+            .unwrap();
+        youtube_video_repository
+            .replace_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &[test_keyword_ranking(date, 1, "today", 100)])
+            .await
+            .unwrap();
+        
+        let previous_rankings = youtube_video_repository
+            .get_keyword_rankings(previous_date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, 100)
+            .await
+            .unwrap();
+        assert_eq!(previous_rankings.len(), 1);
+        assert_eq!(previous_rankings[0].keyword_text, "yesterday");
+    }
+    
+    #[sqlx::test]
+    async fn find_latest_ranking_date_before_skips_missing_dates(pool: MySqlPool) {
+        init_schema(&pool).await;
+        let youtube_video_repository = YoutubeVideoSqlxRepository::new(pool);
+        let date = NaiveDate::from_ymd_opt(2025, 7, 3).unwrap();
+        let last_date = NaiveDate::from_ymd_opt(2025, 7, 1).unwrap();
+        
+        let before_first_chart = youtube_video_repository
+            .find_latest_ranking_date_before(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM)
+            .await
+            .unwrap();
+        assert_eq!(before_first_chart, None);
+        
+        youtube_video_repository
+            .replace_keyword_rankings(last_date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &[test_keyword_ranking(last_date, 1, "first", 100)])
+            .await
+            .unwrap();
+        youtube_video_repository
+            .replace_keyword_rankings(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &[test_keyword_ranking(date, 1, "today", 100)])
+            .await
+            .unwrap();
+        
+        let previous_date = youtube_video_repository
+            .find_latest_ranking_date_before(date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM)
+            .await
+            .unwrap();
+        assert_eq!(previous_date, Some(last_date));
+    }
+    
+    #[sqlx::test]
+    async fn refresh_statistics_counts_changes_and_marks_missing_videos_unavailable(pool: MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_videos (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                video_id VARCHAR(255) NOT NULL UNIQUE,
+                view_count BIGINT NOT NULL DEFAULT 0,
+                like_count BIGINT NOT NULL DEFAULT 0,
+                comment_count BIGINT NOT NULL DEFAULT 0,
+                is_available BOOLEAN NOT NULL DEFAULT TRUE,
+                unavailable_at TIMESTAMP NULL DEFAULT NULL,
+                stats_refreshed_at TIMESTAMP NULL DEFAULT NULL,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+            )
+        ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO youtube_videos (video_id, view_count) VALUES ('same', 100), ('changed', 100), ('missing', 100)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let youtube_video_repository = YoutubeVideoSqlxRepository::new(pool.clone());
+        let refreshed_at = DateTime::from_timestamp(1_751_328_060, 0).unwrap();
+        let statistics = |video_id: &str, view_count: i64| VideoStatistics {
+            video_id: video_id.to_string(),
+            view_count,
+            like_count: 0,
+            comment_count: 0,
+        };
+        
+        let changed_count = youtube_video_repository
+            .update_video_statistics(&[statistics("same", 100), statistics("changed", 150)], refreshed_at)
+            .await
+            .unwrap();
+        let unavailable_count = youtube_video_repository
+            .mark_videos_unavailable(&["missing".to_string()], refreshed_at)
+            .await
+            .unwrap();
+        
+        assert_eq!(changed_count, 1);
+        assert_eq!(unavailable_count, 1);
+        let unavailable: Vec<String> = sqlx::query_scalar("SELECT video_id FROM youtube_videos WHERE is_available = FALSE")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(unavailable, vec!["missing"]);
+        let (refreshed,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM youtube_videos WHERE stats_refreshed_at IS NOT NULL")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(refreshed, 2);
+    }
+    
+    #[sqlx::test]
+    async fn save_video_and_keywords_keeps_newer_statistics_and_availability(pool: MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_videos (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                video_id VARCHAR(255) NOT NULL UNIQUE,
+                published_at TIMESTAMP NOT NULL,
+                channel_id VARCHAR(255) NOT NULL,
+                title VARCHAR(255) NOT NULL,
+                description TEXT NOT NULL,
+                channel_title VARCHAR(255) NOT NULL,
+                tags JSON NULL,
+                duration INT NOT NULL DEFAULT 0,
+                view_count BIGINT NOT NULL DEFAULT 0,
+                like_count BIGINT NOT NULL DEFAULT 0,
+                comment_count BIGINT NOT NULL DEFAULT 0,
+                embed_html TEXT NOT NULL,
+                topic_categories JSON NULL,
+                category_id VARCHAR(16) NULL,
+                is_available BOOLEAN NOT NULL DEFAULT TRUE,
+                unavailable_at TIMESTAMP NULL DEFAULT NULL,
+                created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+                updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP
+            )
+        ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_keywords (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                keyword_text VARCHAR(255) NOT NULL UNIQUE
+            )
+        ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_video_keywords (
+                video_id BIGINT NOT NULL,
+                keyword_id BIGINT NOT NULL,
+                PRIMARY KEY (video_id, keyword_id)
+            )
+        ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let youtube_video_repository = YoutubeVideoSqlxRepository::new(pool.clone());
+        let older = DateTime::from_timestamp(1_751_328_000, 0).unwrap();
+        let newer = DateTime::from_timestamp(1_751_414_400, 0).unwrap();
+        let video = |view_count: i64, updated_at: DateTime<Utc>| YoutubeVideo {
+            id: 0,
+            video_id: "video".to_string(),
+            published_at: older,
+            channel_id: "channel".to_string(),
+            title: format!("title {}", view_count),
+            description: String::new(),
+            channel_title: "channel".to_string(),
+            tags: None,
+            duration: 30,
+            view_count,
+            like_count: view_count / 10,
+            comment_count: view_count / 100,
+            embed_html: String::new(),
+            topic_categories: None,
+            category_id: None,
+            created_at: updated_at,
+            updated_at,
+        };
+        let keywords = || vec![YoutubeKeyword { id: 0, keyword_text: "먹방".to_string() }];
+        
+        youtube_video_repository.save_video_and_keywords(video(2000, newer), keywords()).await.unwrap();
+        youtube_video_repository
+            .mark_videos_unavailable(&["video".to_string()], newer + TimeDelta::hours(1))
+            .await
+            .unwrap();
+        youtube_video_repository.save_video_and_keywords(video(1000, older), keywords()).await.unwrap();
+        
+        let (view_count, like_count, comment_count, title, is_available, updated_at): (i64, i64, i64, String, bool, DateTime<Utc>) =
+            sqlx::query_as("SELECT view_count, like_count, comment_count, title, is_available, updated_at FROM youtube_videos WHERE video_id = 'video'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!((view_count, like_count, comment_count), (2000, 200, 20));
+        assert_eq!(title, "title 2000");
+        assert!(!is_available);
+        assert_eq!(updated_at, newer);
+        
+        // 표시 이후에 다시 수집된 영상은 다시 사용 가능으로 돌아옴
+        youtube_video_repository
+            .save_video_and_keywords(video(3000, newer + TimeDelta::hours(2)), keywords())
+            .await
+            .unwrap();
+        let (view_count, is_available): (i64, bool) = sqlx::query_as("SELECT view_count, is_available FROM youtube_videos WHERE video_id = 'video'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(view_count, 3000);
+        assert!(is_available);
+    }
+    
+    #[sqlx::test]
+    async fn find_duration_percentiles_interpolates_per_bucket(pool: MySqlPool) {
+        sqlx::query("
+            CREATE TABLE IF NOT EXISTS youtube_videos (
+                id BIGINT AUTO_INCREMENT PRIMARY KEY,
+                video_id VARCHAR(255) NOT NULL UNIQUE,
+                published_at TIMESTAMP NOT NULL,
+                duration INT NOT NULL DEFAULT 0,
+                view_count BIGINT NOT NULL DEFAULT 0,
+                like_count BIGINT NOT NULL DEFAULT 0,
+                comment_count BIGINT NOT NULL DEFAULT 0,
+                category_id VARCHAR(16) NULL,
+                is_available BOOLEAN NOT NULL DEFAULT TRUE
+            )
+        ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("
+            INSERT INTO youtube_videos (video_id, published_at, duration, view_count, like_count) VALUES
+                ('a', '2025-07-01 00:00:00', 12, 1000, 50),
+                ('b', '2025-07-01 00:00:00', 15, 3000, 150),
+                ('c', '2025-07-01 00:00:00', 18, 0, 0),
+                ('d', '2025-07-01 00:00:00', 170, 700, 7)
+        ")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let youtube_video_repository = YoutubeVideoSqlxRepository::new(pool);
+        let filter = VideoAnalyticsFilter {
+            published_from: DateTime::from_timestamp(1_751_241_600, 0).unwrap(),
+            published_to: DateTime::from_timestamp(1_751_414_400, 0).unwrap(),
+            keyword: None,
+            category_id: None,
+        };
+        
+        let percentiles = youtube_video_repository.find_duration_percentiles(&filter, 10, 6).await.unwrap();
+        let value = |metric: &str, bucket: i64, percentile: f64| percentiles
+            .iter()
+            .find(|row| row.metric == metric && row.bucket == bucket && (row.percentile - percentile).abs() < 1e-9)
+            .map(|row| (row.value_count, row.value));
+        
+        // 10-20초 구간 조회수 [0, 1000, 3000] 의 중앙값과 p90 (2 순위 사이 0.6 지점)
+        assert_eq!(value(DURATION_METRIC_VIEWS, 1, 0.5), Some((3, 1000.0)));
+        assert_eq!(value(DURATION_METRIC_VIEWS, 1, 0.9), Some((3, 2600.0)));
+        // 조회수 0 인 영상은 좋아요율에서 제외
+        assert_eq!(value(DURATION_METRIC_LIKE_RATE, 1, 0.5), Some((2, 0.05)));
+        assert_eq!(value(DURATION_METRIC_VIEWS, 6, 0.5), Some((1, 700.0)));
+        assert_eq!(value(DURATION_METRIC_VIEWS, 2, 0.5), None);
+    }
 }
\ No newline at end of file
@@ -1,57 +1,141 @@
-use std::time::Duration;
-use chrono_tz::Tz;
-use clokwerk::{AsyncScheduler, Job, TimeUnits};
+use std::future::Future;
+use chrono::{DateTime, Utc};
+use chrono_tz::Asia::Seoul;
 use crate::app_state::AppState;
+use crate::pipeline::pipeline_model::{JOB_CHANNEL_CLEANUP, JOB_CHANNEL_STATS_REFRESH, JOB_COMMENT_COLLECTION, JOB_KEYWORD_SENTIMENT, JOB_RAW_VIDEO_ARCHIVE, JOB_VIDEO_COLLECTION, JOB_VIDEO_STATS_REFRESH};
 
 pub fn init_scheduler(app_state: AppState) {
-    tokio::spawn(async move {
-        let asia_seoul: Tz = "Asia/Seoul".parse().expect("Invalid timezone specified");
-        let mut scheduler = AsyncScheduler::with_tz(asia_seoul);
+    spawn_job(app_state.clone(), JOB_VIDEO_COLLECTION, |app_state, scheduled_at| async move {
+        let youtube_video_service = &app_state.youtube_video_service;
         
-        let app_state_for_daily_task = app_state.clone();
-        scheduler
-            .every(1.day())
-            .at("09:01")
-            .run(move || {
-                let app_state_clone = app_state_for_daily_task.clone();
-                async move {
-                    match app_state_clone
-                        .youtube_video_service
-                        .run_video_collection_pipeline()
-                        .await
-                    {
-                        Ok(_) => {
-                            println!("[스케줄러] 데이터 수집 완료");
-                        }
-                        Err(e) => {
-                            eprintln!("[스케줄러] 데이터 수집 실패 {:?}", e);
-                        }
-                    }
-                }
-            });
+        // 인기 영상은 현재 목록만 조회할 수 있으므로 지난 날짜의 실행은 오늘 데이터로 대신 채우지 않음
+        let scheduled_date = scheduled_at.with_timezone(&Seoul).date_naive();
+        if scheduled_date < Utc::now().with_timezone(&Seoul).date_naive() {
+            println!("[스케줄러] {} 수집은 지난 날짜라 보충할 수 없습니다.", scheduled_date);
+            return Ok(());
+        }
         
-        let app_state_for_cleanup = app_state.clone();
-        scheduler
-            .every(10.minutes())
-            .run(move || {
-               let app_state_clone = app_state_for_cleanup.clone();
-                async move {
-                    match app_state_clone
-                        .youtube_channel_service
-                        .cleanup_stale_channels()
-                        .await
-                    { 
-                        Ok(_) => {},
-                        Err(e) => {
-                            eprintln!("[스케줄러] 클린 업 실패 {}", e);
-                        }
-                    }
-                }
-            });
+        // 다른 인스턴스가 이미 오늘 수집을 마쳤으면 건너뜀
+        if youtube_video_service.is_today_collected().await.map_err(|e| e.to_string())? {
+            println!("[스케줄러] 오늘 데이터는 이미 수집되었습니다.");
+            return Ok(());
+        }
+        
+        youtube_video_service
+            .run_video_collection_pipeline()
+            .await
+            .map_err(|e| e.to_string())?;
+        println!("[스케줄러] 데이터 수집 완료");
+        Ok(())
+    });
+    
+    spawn_job(app_state.clone(), JOB_CHANNEL_CLEANUP, |app_state, _| async move {
+        app_state.youtube_channel_service
+            .cleanup_stale_channels()
+            .await
+            .map_err(|e| e.to_string())
+    });
+    
+    spawn_job(app_state.clone(), JOB_VIDEO_STATS_REFRESH, |app_state, _| async move {
+        let summary = app_state.youtube_video_service
+            .refresh_tracked_video_statistics()
+            .await
+            .map_err(|e| e.to_string())?;
+        println!(
+            "[스케줄러] 통계 갱신 완료: {}/{}건 갱신, {}건 비공개 처리",
+            summary.refreshed_video_count,
+            summary.tracked_video_count,
+            summary.unavailable_video_count
+        );
+        Ok(())
+    });
+    
+    spawn_job(app_state.clone(), JOB_CHANNEL_STATS_REFRESH, |app_state, _| async move {
+        let summary = app_state.youtube_channel_service
+            .refresh_channel_statistics()
+            .await
+            .map_err(|e| e.to_string())?;
+        println!(
+            "[스케줄러] 채널 통계 갱신 완료: {}/{}개 채널",
+            summary.refreshed_channel_count,
+            summary.channel_count
+        );
+        Ok(())
+    });
+    
+    spawn_job(app_state.clone(), JOB_COMMENT_COLLECTION, |app_state, _| async move {
+        let summary = app_state.youtube_video_comment_service
+            .collect_trending_video_comments()
+            .await
+            .map_err(|e| e.to_string())?;
+        println!(
+            "[스케줄러] 댓글 수집 완료: {}/{}개 영상, 댓글 {}건",
+            summary.collected_video_count,
+            summary.video_count,
+            summary.comment_count
+        );
+        Ok(())
+    });
+    
+    // 감성 분석이 실패해도 댓글 수집은 성공으로 남도록 별도 작업으로 실행
+    spawn_job(app_state.clone(), JOB_KEYWORD_SENTIMENT, |app_state, scheduled_at| async move {
+        // 보충 실행이면 놓친 날짜의 순위표를 분석
+        let scheduled_date = scheduled_at.with_timezone(&Seoul).date_naive();
+        let sentiment_count = app_state.youtube_video_service
+            .calculate_and_save_keyword_sentiments(scheduled_date)
+            .await
+            .map_err(|e| e.to_string())?;
+        println!("[스케줄러] {} 키워드 감성 분석 완료: {}개 키워드", scheduled_date, sentiment_count);
+        Ok(())
+    });
+    
+    spawn_job(app_state, JOB_RAW_VIDEO_ARCHIVE, |app_state, _| async move {
+        app_state.raw_video_archive_service
+            .archive_expired_raw_videos()
+            .await
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    });
+}
+
+// 작업에는 예정된 실행 시각을 넘겨 보충 실행이 어느 실행을 대신하는지 알 수 있게 함
+fn spawn_job<F, Fut>(app_state: AppState, job_name: &'static str, job: F)
+where
+    F: Fn(AppState, DateTime<Utc>) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<(), String>> + Send + 'static,
+{
+    tokio::spawn(async move {
+        // 서버가 내려가 있던 동안 놓친 실행이 있으면 시작 시 한 번 보충
+        match app_state.pipeline_service.find_missed_run(job_name).await {
+            Ok(Some(missed_run_at)) => {
+                println!("[스케줄러] '{}' 놓친 실행({})을 보충합니다.", job_name, missed_run_at.with_timezone(&Seoul));
+                run_job(&app_state, job_name, missed_run_at, &job).await;
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("[스케줄러] '{}' 실행 기록 조회 실패 {}", job_name, e),
+        }
         
         loop {
-            scheduler.run_pending().await;
-            tokio::time::sleep(Duration::from_secs(60)).await;
+            let Some(next_run_at) = app_state.pipeline_service.next_run_at(job_name, Utc::now()) else {
+                eprintln!("[스케줄러] '{}' 다음 실행 시각이 없어 종료합니다.", job_name);
+                break;
+            };
+            let wait = (next_run_at - Utc::now()).to_std().unwrap_or_default();
+            tokio::time::sleep(wait).await;
+            
+            run_job(&app_state, job_name, next_run_at, &job).await;
         }
     });
+}
+
+async fn run_job<F, Fut>(app_state: &AppState, job_name: &'static str, scheduled_at: DateTime<Utc>, job: &F)
+where
+    F: Fn(AppState, DateTime<Utc>) -> Fut,
+    Fut: Future<Output = Result<(), String>>,
+{
+    match app_state.pipeline_service.run_exclusive(job_name, || job(app_state.clone(), scheduled_at)).await {
+        Ok(Some(_)) => {}
+        Ok(None) => println!("[스케줄러] 다른 인스턴스에서 '{}' 실행 중입니다.", job_name),
+        Err(e) => eprintln!("[스케줄러] '{}' 실패 {}", job_name, e),
+    }
 }
\ No newline at end of file
@@ -1,21 +1,37 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::error::Error;
-use chrono::{TimeDelta, Utc};
+use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
 use chrono_tz::Asia::Seoul;
+use crate::common::statistics::round4;
+use crate::errors::AppError;
+use crate::gemini::gemini_api_model::{GeminiPromptSentimentData, GeminiSentimentResponse};
 use crate::gemini::gemini_api_util::GeminiAPIClient;
-use crate::youtube::youtube_data_api::youtube_data_api_model::VideoItem;
+use crate::pipeline::pipeline_error::PipelineError;
+use crate::pipeline::pipeline_model::JOB_VIDEO_COLLECTION;
+use crate::pipeline::pipeline_service::PipelineService;
+use crate::youtube::youtube_data_api::youtube_data_api_model::{VideoItem, YoutubeQuotaUsage};
 use crate::youtube::youtube_data_api::youtube_data_api_util::YoutubeDataAPIClient;
+use crate::youtube::youtube_video::youtube_keyword_alert::evaluate_watchlist_alerts;
+use crate::youtube::youtube_video::youtube_keyword_watchlist_repository::YoutubeKeywordWatchlistRepository;
 use crate::youtube::youtube_video::youtube_raw_video_repository::YoutubeRawVideoRepository;
-use crate::youtube::youtube_video::youtube_video_model::{KeywordRankingResponse, RankChange, YoutubeKeyword, YoutubeKeywordRanking, YoutubeRawVideo, YoutubeVideo};
+use crate::youtube::youtube_video::youtube_video_analytics::{analytics_window, best_publish_time_slots, build_duration_buckets, build_publish_time_slots, parse_timezone, DURATION_BUCKET_SECONDS, DURATION_LAST_BUCKET};
+use crate::youtube::youtube_video::youtube_video_model::{DurationAnalyticsQuery, DurationAnalyticsResponse, KeywordRankingResponse, KeywordSentimentResponse, KeywordSentimentSample, YoutubeKeywordSentiment, PublishTimeAnalyticsQuery, PublishTimeAnalyticsResponse, VideoAnalyticsFilter, RankChange, RawVideoReprocessSummary, VideoStatistics, VideoStatsRefreshSummary, YoutubeKeyword, YoutubeKeywordRanking, YoutubeRawVideo, YoutubeVideo, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM};
 use crate::youtube::youtube_video::youtube_video_repository::YoutubeVideoRepository;
 
+// 감성 분석은 키워드당 조회수 상위 영상 3개의 제목과 수집된 댓글 기준
+const SENTIMENT_VIDEOS_PER_KEYWORD: u32 = 3;
+const SENTIMENT_BATCH_SIZE: usize = 5;
+const SENTIMENT_TEXT_MAX_CHARS: usize = 200;
+
 #[derive(Clone)]
 pub struct YoutubeVideoService {
     youtube_data_api_client: Arc<YoutubeDataAPIClient>,
     youtube_raw_video_repository: Arc<dyn YoutubeRawVideoRepository>,
     youtube_video_repository: Arc<dyn YoutubeVideoRepository>,
     gemini_api_client: Arc<GeminiAPIClient>,
+    youtube_keyword_watchlist_repository: Arc<dyn YoutubeKeywordWatchlistRepository>,
+    pipeline_service: PipelineService,
 }
 
 impl YoutubeVideoService {
@@ -24,29 +40,138 @@ impl YoutubeVideoService {
         youtube_raw_video_repository: Arc<dyn YoutubeRawVideoRepository>,
         youtube_video_repository: Arc<dyn YoutubeVideoRepository>,
         gemini_api_client: Arc<GeminiAPIClient>,
+        youtube_keyword_watchlist_repository: Arc<dyn YoutubeKeywordWatchlistRepository>,
+        pipeline_service: PipelineService,
     ) -> Self {
         Self {
             youtube_data_api_client,
             youtube_raw_video_repository,
             youtube_video_repository,
             gemini_api_client,
+            youtube_keyword_watchlist_repository,
+            pipeline_service,
         }
     }
     
     pub async fn run_video_collection_pipeline(&self) -> Result<(), Box<dyn Error>> {
+        let collected_at = Utc::now();
         let raw_video_items = self.fetch_video_items_from_data_api().await?;
         
         let video_items = self.filter_raw_video_data(raw_video_items).await?;
         
         self.save_raw_video_data(&video_items).await?;
         
-        self.transform_and_save_video_data(&video_items).await?;
+        self.transform_and_save_video_data(&video_items, collected_at).await?;
+        
+        let today = collected_at.with_timezone(&Seoul).date_naive();
+        let rankings = self.calculate_and_save_daily_rankings(today).await?;
         
-        self.calculate_and_save_daily_rankings().await?;
+        // 관심 키워드 알림은 일일 수집에서만 평가 (재처리, 재계산으로 지난 날짜 알림이 만들어지지 않도록)
+        // 알림 평가가 실패해도 저장된 순위표는 그대로 유지
+        if !rankings.is_empty() {
+            if let Err(e) = self.evaluate_keyword_alerts(today, &rankings).await {
+                eprintln!("[Alerts] {} 관심 키워드 알림 평가 실패: {}", today, e);
+            }
+        }
 
         Ok(())
     }
     
+    pub async fn request_reprocess_raw_videos(&self, from: NaiveDate, to: NaiveDate, force: bool) -> Result<serde_json::Value, AppError> {
+        const MAX_REPROCESS_DAYS: i64 = 31;
+        
+        if from > to || (to - from).num_days() >= MAX_REPROCESS_DAYS {
+            return Err(PipelineError::InvalidDateRange(from, to))?;
+        }
+        self.ensure_rankings_replaceable(from, to, force).await?;
+        
+        // 일일 수집, 순위 재계산과 같은 순위표를 쓰므로 같은 락에서 실행
+        let service_clone = self.clone();
+        let started = self.pipeline_service
+            .spawn_locked(JOB_VIDEO_COLLECTION, move || async move {
+                let summary = service_clone
+                    .reprocess_raw_videos(from, to, force)
+                    .await
+                    .map_err(|e| format!("원본 데이터 재처리 실패 ({} ~ {}): {}", from, to, e))?;
+                println!("[Background Job] 원본 데이터 재처리 성공 ({} ~ {}): {:?}", from, to, summary);
+                Ok(())
+            })
+            .await?;
+        if !started {
+            return Err(PipelineError::JobAlreadyRunning(JOB_VIDEO_COLLECTION.to_string()))?;
+        }
+        
+        Ok(serde_json::json!({
+            "message": "원본 데이터 재처리 요청이 접수되었습니다. 처리에는 몇 분 정도 소요될 수 있습니다."
+        }))
+    }
+    
+    // YouTube Data API 호출 없이 저장된 원본 메타데이터로 영상, 키워드, 순위를 다시 계산
+    // 원본 테이블은 영상당 마지막 수집본 한 행만 남기므로, 이후에 다시 수집된 영상은 과거 날짜에서 빠진다
+    // 그래서 재계산한 순위표는 원래 순위표보다 영상 수가 적을 수 있고, 키워드 추출도 Gemini 로 다시 한다
+    // 이미 있는 순위표는 force 일 때만 덮어쓴다
+    pub async fn reprocess_raw_videos(&self, from: NaiveDate, to: NaiveDate, force: bool) -> Result<RawVideoReprocessSummary, Box<dyn Error>> {
+        self.ensure_rankings_replaceable(from, to, force).await?;
+        let mut summary = RawVideoReprocessSummary::default();
+        
+        for date in from.iter_days().take_while(|date| *date <= to) {
+            let start = date
+                .and_hms_opt(0, 0, 0)
+                .and_then(|time| time.and_local_timezone(Seoul).single())
+                .ok_or("재처리 기준 시각 계산 실패")?
+                .with_timezone(&Utc);
+            let end = start + TimeDelta::days(1);
+            
+            let raw_videos = self.youtube_raw_video_repository
+                .find_by_updated_at_between(start, end)
+                .await?;
+            summary.raw_video_count += raw_videos.len();
+            
+            let Some(collected_at) = raw_videos.iter().map(|raw_video| raw_video.updated_at).max() else {
+                continue;
+            };
+            
+            let mut video_items = Vec::with_capacity(raw_videos.len());
+            for raw_video in &raw_videos {
+                match VideoItem::try_from(raw_video) {
+                    Ok(item) => video_items.push(item),
+                    Err(e) => {
+                        summary.invalid_raw_video_count += 1;
+                        eprintln!("[Reprocess] 원본 데이터 변환 실패 '{}': {}", raw_video.video_id, e);
+                    }
+                }
+            }
+            
+            let video_items = self.filter_raw_video_data(video_items).await?;
+            summary.reprocessed_video_count += video_items.len();
+            
+            self.transform_and_save_video_data(&video_items, collected_at).await?;
+        }
+        
+        for date in from.iter_days().take_while(|date| *date <= to) {
+            self.calculate_and_save_daily_rankings(date).await?;
+            summary.ranking_dates.push(date);
+        }
+        
+        Ok(summary)
+    }
+    
+    async fn ensure_rankings_replaceable(&self, from: NaiveDate, to: NaiveDate, force: bool) -> Result<(), PipelineError> {
+        if force {
+            return Ok(());
+        }
+        
+        let existing_dates = self.youtube_video_repository
+            .find_ranking_dates_between(from, to, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM)
+            .await?;
+        if !existing_dates.is_empty() {
+            let dates = existing_dates.iter().map(|date| date.to_string()).collect::<Vec<_>>().join(", ");
+            return Err(PipelineError::RankingsAlreadyExist(dates));
+        }
+        
+        Ok(())
+    }
+    
     async fn fetch_video_items_from_data_api(&self) -> Result<Vec<VideoItem>, Box<dyn Error>> {
         let search_tags = vec!["#shorts", "#쇼츠"];
         let search_query = search_tags.join("|");
@@ -104,11 +229,12 @@ impl YoutubeVideoService {
         Ok(final_shorts)
     }
     
-    async fn transform_and_save_video_data(&self, videos: &[VideoItem]) -> Result<(), Box<dyn Error>> {
+    async fn transform_and_save_video_data(&self, videos: &[VideoItem], collected_at: DateTime<Utc>) -> Result<(), Box<dyn Error>> {
         for video_chunk in videos.chunks(40) {
             let videos_to_save: Vec<YoutubeVideo> = video_chunk
                 .iter()
                 .map(YoutubeVideo::from)
+                .map(|video| YoutubeVideo { updated_at: collected_at, ..video })
                 .collect();
             
             let keyword_map = self.gemini_api_client.extract_keywords_with_gemini(&videos_to_save).await?;
@@ -149,11 +275,11 @@ impl YoutubeVideoService {
         Ok(())
     }
     
-    async fn calculate_and_save_daily_rankings(&self) -> Result<(), Box<dyn Error>> {
-        let today = Utc::now().with_timezone(&Seoul).date_naive();
-        let one_week_ago = Utc::now().with_timezone(&Seoul).date_naive() - TimeDelta::days(7);
+    pub async fn calculate_and_save_daily_rankings(&self, today: NaiveDate) -> Result<Vec<YoutubeKeywordRanking>, Box<dyn Error>> {
+        let one_week_ago = today - TimeDelta::days(7);
+        let tomorrow = today + TimeDelta::days(1);
         
-        let trends = self.youtube_video_repository.get_keyword_trends(one_week_ago, 100).await?;
+        let trends = self.youtube_video_repository.get_keyword_trends(one_week_ago, tomorrow, 100).await?;
         
         let rankings_to_save: Vec<YoutubeKeywordRanking> = trends
             .into_iter()
@@ -162,6 +288,8 @@ impl YoutubeVideoService {
                 YoutubeKeywordRanking {
                     id: 0,
                     ranking_date: today,
+                    region_code: RANKING_REGION_CODE.to_string(),
+                    strategy: RANKING_STRATEGY_WEEKLY_VIEW_SUM.to_string(),
                     ranking: (index + 1) as i32,
                     keyword_id: trend.id,
                     keyword_text: trend.keyword_text,
@@ -170,26 +298,242 @@ impl YoutubeVideoService {
             })
             .collect();
         println!("{:?}", rankings_to_save);
-        self.youtube_video_repository.save_keyword_rankings(&rankings_to_save).await?;
+        self.youtube_video_repository
+            .replace_keyword_rankings(today, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, &rankings_to_save)
+            .await?;
         
-        Ok(())
+        Ok(rankings_to_save)
+    }
+    
+    // 직전 순위표와 비교해 관심 키워드 알림 조건을 평가하고, 그날 알림을 새 평가 결과로 교체
+    // 전날 순위표가 없으면 그 이전의 가장 최근 순위표와 비교하고, 비교할 순위표가 없으면 평가하지 않음
+    async fn evaluate_keyword_alerts(&self, today: NaiveDate, today_rankings: &[YoutubeKeywordRanking]) -> Result<usize, Box<dyn Error>> {
+        let Some(previous_date) = self.youtube_video_repository
+            .find_latest_ranking_date_before(today, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM)
+            .await? else {
+            println!("[Alerts] {} 이전 순위표가 없어 관심 키워드 알림을 평가하지 않습니다.", today);
+            return Ok(0);
+        };
+        let previous_rankings = self.youtube_video_repository
+            .get_keyword_rankings(previous_date, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, 100)
+            .await?;
+        
+        let keyword_texts: Vec<String> = today_rankings
+            .iter()
+            .chain(&previous_rankings)
+            .map(|ranking| ranking.keyword_text.clone())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+        let watchlists = self.youtube_keyword_watchlist_repository
+            .find_watchlists_by_keywords(&keyword_texts)
+            .await?;
+        
+        let alerts = evaluate_watchlist_alerts(&watchlists, today_rankings, &previous_rankings);
+        self.youtube_keyword_watchlist_repository
+            .replace_keyword_alerts(today, &alerts)
+            .await?;
+        println!("[Alerts] {} 관심 키워드 알림 {}건 저장 ({} 순위표와 비교)", today, alerts.len(), previous_date);
+        
+        Ok(alerts.len())
+    }
+    
+    // 순위표 키워드별 제목, 댓글을 Gemini 로 분류해 날짜별로 저장
+    pub async fn calculate_and_save_keyword_sentiments(&self, date: NaiveDate) -> Result<usize, Box<dyn Error>> {
+        let samples = self.youtube_video_repository
+            .find_keyword_sentiment_samples(
+                date,
+                RANKING_REGION_CODE,
+                RANKING_STRATEGY_WEEKLY_VIEW_SUM,
+                SENTIMENT_VIDEOS_PER_KEYWORD,
+                date - TimeDelta::days(7),
+                date + TimeDelta::days(1),
+            )
+            .await?;
+        let keyword_texts = group_sentiment_samples(&samples);
+        
+        let mut sentiments = Vec::with_capacity(keyword_texts.len());
+        let batch_count = keyword_texts.len().div_ceil(SENTIMENT_BATCH_SIZE);
+        let mut failed_batch_count = 0;
+        for chunk in keyword_texts.chunks(SENTIMENT_BATCH_SIZE) {
+            let prompt_data: Vec<GeminiPromptSentimentData> = chunk
+                .iter()
+                .map(|(keyword_text, texts)| GeminiPromptSentimentData {
+                    keyword_text,
+                    texts: texts.clone(),
+                })
+                .collect();
+            
+            // 한 배치가 실패해도 나머지 키워드는 분석하고, 실패한 배치의 키워드만 결과에서 빠짐
+            let results = match self.gemini_api_client.classify_sentiments_with_gemini(&prompt_data).await {
+                Ok(results) => results,
+                Err(e) => {
+                    failed_batch_count += 1;
+                    let keyword_texts: Vec<&str> = chunk.iter().map(|(keyword_text, _)| *keyword_text).collect();
+                    eprintln!("[Sentiment] {:?} 감성 분석 실패: {}", keyword_texts, e);
+                    continue;
+                }
+            };
+            sentiments.extend(results.into_iter().filter_map(|result| {
+                // 요청하지 않은 키워드나 보낸 텍스트 수와 합이 맞지 않는 분류 결과는 저장하지 않음
+                let text_count = chunk
+                    .iter()
+                    .find(|(keyword_text, _)| *keyword_text == result.keyword_text)
+                    .map(|(_, texts)| texts.len())?;
+                let sentiment = keyword_sentiment(date, &result, text_count);
+                if sentiment.is_none() {
+                    eprintln!(
+                        "[Sentiment] '{}' 분류 개수 불일치 (보낸 텍스트 {}건, 긍정 {} 중립 {} 부정 {})",
+                        result.keyword_text, text_count, result.positive, result.neutral, result.negative
+                    );
+                }
+                sentiment
+            }));
+        }
+        
+        if batch_count > 0 && failed_batch_count == batch_count {
+            return Err(format!("{} 키워드 감성 분석 배치 {}개가 모두 실패했습니다.", date, batch_count).into());
+        }
+        
+        self.youtube_video_repository.replace_keyword_sentiments(date, &sentiments).await?;
+        
+        Ok(sentiments.len())
     }
 
+    // search 대신 videos.list(50개당 1 unit)로 순위 기간 내 영상의 통계만 갱신
+    pub async fn refresh_tracked_video_statistics(&self) -> Result<VideoStatsRefreshSummary, Box<dyn Error>> {
+        let today = Utc::now().with_timezone(&Seoul).date_naive();
+        let published_since = (today - TimeDelta::days(7))
+            .and_hms_opt(0, 0, 0)
+            .and_then(|time| time.and_local_timezone(Seoul).single())
+            .ok_or("통계 갱신 기준 시각 계산 실패")?
+            .with_timezone(&Utc);
+        
+        let tracked_video_ids = self.youtube_video_repository
+            .find_tracked_video_ids(published_since)
+            .await?;
+        let mut summary = VideoStatsRefreshSummary {
+            tracked_video_count: tracked_video_ids.len(),
+            ..Default::default()
+        };
+        let mut changed_video_count = 0;
+        
+        for chunk in tracked_video_ids.chunks(50) {
+            let refreshed_at = Utc::now();
+            let details = self.youtube_data_api_client.get_videos_details(chunk).await?;
+            let statistics: Vec<VideoStatistics> = details.iter().map(VideoStatistics::from).collect();
+            let missing_ids = find_missing_video_ids(chunk, &statistics);
+            
+            changed_video_count += self.youtube_video_repository.update_video_statistics(&statistics, refreshed_at).await?;
+            summary.refreshed_video_count += statistics.len();
+            summary.unavailable_video_count += self.youtube_video_repository
+                .mark_videos_unavailable(&missing_ids, refreshed_at)
+                .await?;
+        }
+        
+        // 통계가 바뀌었고 오늘 순위가 이미 있을 때만 재계산 (없으면 일일 수집에서 계산)
+        let stats_changed = changed_video_count > 0 || summary.unavailable_video_count > 0;
+        // 수집, 재처리와 같은 순위표를 교체하므로 같은 락에서 실행하고, 실행 중이면 그 작업이 순위를 계산하므로 건너뜀
+        if stats_changed && self.youtube_video_repository.today_ranking_exists(today, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM).await? {
+            let recomputed = self.pipeline_service
+                .run_locked(JOB_VIDEO_COLLECTION, || async {
+                    self.calculate_and_save_daily_rankings(today)
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                })
+                .await?;
+            if recomputed.is_none() {
+                println!("[Stats] 다른 인스턴스에서 데이터 수집 중이라 {} 순위 재계산을 건너뜁니다.", today);
+            }
+        }
+        
+        Ok(summary)
+    }
+    
+    pub async fn is_today_collected(&self) -> Result<bool, Box<dyn Error>> {
+        let today = Utc::now().with_timezone(&Seoul).date_naive();
+        Ok(self.youtube_video_repository.today_ranking_exists(today, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM).await?)
+    }
+    
+    pub async fn get_youtube_quota_usage(&self) -> Result<YoutubeQuotaUsage, anyhow::Error> {
+        self.youtube_data_api_client.get_quota_usage().await
+    }
+    
+    pub async fn get_publish_time_analytics(&self, query: PublishTimeAnalyticsQuery) -> Result<PublishTimeAnalyticsResponse, AppError> {
+        let timezone = parse_timezone(query.timezone.as_deref())?;
+        let (from, to, published_from, published_to) = analytics_window(timezone, query.from, query.to, Utc::now())?;
+        let filter = VideoAnalyticsFilter {
+            published_from,
+            published_to,
+            keyword: query.keyword.filter(|keyword| !keyword.trim().is_empty()),
+            category_id: query.category_id.filter(|category_id| !category_id.trim().is_empty()),
+        };
+        
+        let stats = self.youtube_video_repository.find_publish_hour_stats(&filter).await?;
+        let slots = build_publish_time_slots(timezone, &stats);
+        
+        Ok(PublishTimeAnalyticsResponse {
+            timezone: timezone.name().to_string(),
+            from,
+            to,
+            video_count: slots.iter().map(|slot| slot.video_count).sum(),
+            best_slots: best_publish_time_slots(&slots),
+            slots,
+            keyword: filter.keyword,
+            category_id: filter.category_id,
+        })
+    }
+    
+    pub async fn get_duration_analytics(&self, query: DurationAnalyticsQuery) -> Result<DurationAnalyticsResponse, AppError> {
+        let timezone = parse_timezone(query.timezone.as_deref())?;
+        let (from, to, published_from, published_to) = analytics_window(timezone, query.from, query.to, Utc::now())?;
+        let filter = VideoAnalyticsFilter {
+            published_from,
+            published_to,
+            keyword: query.keyword.filter(|keyword| !keyword.trim().is_empty()),
+            category_id: None,
+        };
+        
+        let percentiles = self.youtube_video_repository
+            .find_duration_percentiles(&filter, DURATION_BUCKET_SECONDS, DURATION_LAST_BUCKET)
+            .await?;
+        let buckets = build_duration_buckets(&percentiles);
+        
+        Ok(DurationAnalyticsResponse {
+            from,
+            to,
+            keyword: filter.keyword,
+            video_count: buckets.iter().map(|bucket| bucket.video_count).sum(),
+            buckets,
+        })
+    }
+    
     pub async fn get_daily_rankings(&self) -> Result<Vec<KeywordRankingResponse>, Box<dyn Error>> {
         let mut today = Utc::now().with_timezone(&Seoul).date_naive();
-        if !self.youtube_video_repository.today_ranking_exists(today).await? {
+        if !self.youtube_video_repository.today_ranking_exists(today, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM).await? {
             today = today - TimeDelta::days(1);
         }
         let yesterday = today - TimeDelta::days(1);
         const RANKING_LIMIT: u32 = 100;
         
-        let today_rankings = self.youtube_video_repository.get_keyword_rankings(today, RANKING_LIMIT).await?;
-        let yesterday_rankings = self. youtube_video_repository.get_keyword_rankings(yesterday, RANKING_LIMIT).await?;
+        let today_rankings = self.youtube_video_repository
+            .get_keyword_rankings(today, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, RANKING_LIMIT)
+            .await?;
+        let yesterday_rankings = self.youtube_video_repository
+            .get_keyword_rankings(yesterday, RANKING_REGION_CODE, RANKING_STRATEGY_WEEKLY_VIEW_SUM, RANKING_LIMIT)
+            .await?;
         
         let yesterday_rankings_map: HashMap<String, i32> = yesterday_rankings
             .into_iter()
             .map(|rank| (rank.keyword_text, rank.ranking))
             .collect();
+        let sentiments_map: HashMap<String, YoutubeKeywordSentiment> = self.youtube_video_repository
+            .find_keyword_sentiments(today)
+            .await?
+            .into_iter()
+            .map(|sentiment| (sentiment.keyword_text.clone(), sentiment))
+            .collect();
         
         let rankings = today_rankings
             .into_iter()
@@ -208,10 +552,228 @@ impl YoutubeVideoService {
                     None => RankChange::New
                 };
                 
-                KeywordRankingResponse::from((today_rank, rank_change))
+                let sentiment = sentiments_map.get(&today_rank.keyword_text).map(KeywordSentimentResponse::from);
+                KeywordRankingResponse {
+                    sentiment,
+                    ..KeywordRankingResponse::from((today_rank, rank_change))
+                }
             })
             .collect();
         
         Ok(rankings)
     }
+}
+
+// 응답에서 빠진 영상은 삭제 또는 비공개 처리된 것으로 간주
+fn find_missing_video_ids(requested_ids: &[String], statistics: &[VideoStatistics]) -> Vec<String> {
+    let returned_ids: HashSet<&str> = statistics.iter().map(|stats| stats.video_id.as_str()).collect();
+    
+    requested_ids
+        .iter()
+        .filter(|video_id| !returned_ids.contains(video_id.as_str()))
+        .cloned()
+        .collect()
+}
+
+// 키워드 순서를 유지하며 샘플 문장을 묶고, 너무 긴 문장은 잘라서 전달
+fn group_sentiment_samples(samples: &[KeywordSentimentSample]) -> Vec<(&str, Vec<&str>)> {
+    let mut grouped: Vec<(&str, Vec<&str>)> = Vec::new();
+    for sample in samples {
+        let text = sample.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let text = match text.char_indices().nth(SENTIMENT_TEXT_MAX_CHARS) {
+            Some((index, _)) => &text[..index],
+            None => text,
+        };
+        
+        match grouped.iter_mut().find(|(keyword_text, _)| *keyword_text == sample.keyword_text) {
+            Some((_, texts)) => texts.push(text),
+            None => grouped.push((&sample.keyword_text, vec![text])),
+        }
+    }
+    
+    grouped
+}
+
+// Gemini 가 보고한 개수는 보낸 텍스트 수와 합이 같을 때만 믿고 비율로 변환
+fn keyword_sentiment(date: NaiveDate, result: &GeminiSentimentResponse, text_count: usize) -> Option<YoutubeKeywordSentiment> {
+    let (positive, neutral, negative) = (result.positive, result.neutral, result.negative);
+    if text_count == 0 || positive < 0 || neutral < 0 || negative < 0 || positive + neutral + negative != text_count as i64 {
+        return None;
+    }
+    let total = text_count as f64;
+    
+    Some(YoutubeKeywordSentiment {
+        id: 0,
+        sentiment_date: date,
+        keyword_text: result.keyword_text.clone(),
+        positive_ratio: round4(positive as f64 / total),
+        neutral_ratio: round4(neutral as f64 / total),
+        negative_ratio: round4(negative as f64 / total),
+        sample_count: text_count as i32,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::test_support::{test_gemini_api_client, test_keyword_ranking, test_redis_repository, test_youtube_data_api_client};
+    use crate::pipeline::pipeline_repository::MockPipelineRepository;
+    use crate::youtube::youtube_video::youtube_keyword_watchlist_repository::MockYoutubeKeywordWatchlistRepository;
+    use crate::youtube::youtube_video::youtube_raw_video_repository::MockYoutubeRawVideoRepository;
+    use crate::youtube::youtube_video::youtube_video_model::{KeywordAlertType, YoutubeKeywordWatchlist};
+    use crate::youtube::youtube_video::youtube_video_repository::MockYoutubeVideoRepository;
+    
+    fn service(
+        youtube_raw_video_repository: MockYoutubeRawVideoRepository,
+        youtube_video_repository: MockYoutubeVideoRepository,
+        youtube_keyword_watchlist_repository: MockYoutubeKeywordWatchlistRepository,
+    ) -> YoutubeVideoService {
+        YoutubeVideoService::new(
+            test_youtube_data_api_client(),
+            Arc::new(youtube_raw_video_repository),
+            Arc::new(youtube_video_repository),
+            test_gemini_api_client(),
+            Arc::new(youtube_keyword_watchlist_repository),
+            PipelineService::new(test_redis_repository(), Arc::new(MockPipelineRepository::new()), vec![]).unwrap(),
+        )
+    }
+    
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, day).unwrap()
+    }
+    
+    #[tokio::test]
+    async fn reprocess_refuses_to_overwrite_existing_rankings() {
+        let mut youtube_raw_video_repository = MockYoutubeRawVideoRepository::new();
+        youtube_raw_video_repository.expect_find_by_updated_at_between().never();
+        let mut youtube_video_repository = MockYoutubeVideoRepository::new();
+        youtube_video_repository.expect_find_ranking_dates_between().returning(|_, _, _, _| Ok(vec![date(6)]));
+        youtube_video_repository.expect_replace_keyword_rankings().never();
+        
+        let service = service(youtube_raw_video_repository, youtube_video_repository, MockYoutubeKeywordWatchlistRepository::new());
+        
+        let result = service.reprocess_raw_videos(date(5), date(7), false).await;
+        assert!(result.unwrap_err().to_string().contains("2026-01-06"));
+        
+        let result = service.request_reprocess_raw_videos(date(5), date(7), false).await;
+        assert!(matches!(result, Err(AppError::Pipeline(PipelineError::RankingsAlreadyExist(_)))));
+    }
+    
+    #[tokio::test]
+    async fn reprocess_with_force_recomputes_every_date() {
+        let mut youtube_raw_video_repository = MockYoutubeRawVideoRepository::new();
+        youtube_raw_video_repository.expect_find_by_updated_at_between().times(2).returning(|_, _| Ok(Vec::new()));
+        let mut youtube_video_repository = MockYoutubeVideoRepository::new();
+        youtube_video_repository.expect_find_ranking_dates_between().never();
+        youtube_video_repository.expect_get_keyword_trends().times(2).returning(|_, _, _| Ok(Vec::new()));
+        youtube_video_repository.expect_replace_keyword_rankings().times(2).returning(|_, _, _, _| Ok(()));
+        
+        let service = service(youtube_raw_video_repository, youtube_video_repository, MockYoutubeKeywordWatchlistRepository::new());
+        
+        let summary = service.reprocess_raw_videos(date(5), date(6), true).await.unwrap();
+        assert_eq!(summary.ranking_dates, vec![date(5), date(6)]);
+    }
+    
+    #[tokio::test]
+    async fn keyword_alerts_compare_against_latest_previous_chart() {
+        let mut youtube_video_repository = MockYoutubeVideoRepository::new();
+        youtube_video_repository.expect_find_latest_ranking_date_before()
+            .withf(|ranking_date, _, _| *ranking_date == date(7))
+            .returning(|_, _, _| Ok(Some(date(5))));
+        youtube_video_repository.expect_get_keyword_rankings()
+            .withf(|ranking_date, _, _, _| *ranking_date == date(5))
+            .times(1)
+            .returning(|ranking_date, _, _, _| Ok(vec![test_keyword_ranking(ranking_date, 15, "먹방", 0)]));
+        let mut youtube_keyword_watchlist_repository = MockYoutubeKeywordWatchlistRepository::new();
+        youtube_keyword_watchlist_repository.expect_find_watchlists_by_keywords().returning(|_| Ok(vec![YoutubeKeywordWatchlist {
+            id: 1,
+            user_public_id: "user".to_string(),
+            keyword_text: "먹방".to_string(),
+            enter_top_n: Some(10),
+            climb_threshold: None,
+            alert_on_drop_off: false,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }]));
+        youtube_keyword_watchlist_repository.expect_replace_keyword_alerts()
+            .withf(|ranking_date, alerts| {
+                *ranking_date == date(7)
+                    && alerts.len() == 1
+                    && alerts[0].alert_type == KeywordAlertType::EnterTopN
+                    && alerts[0].previous_ranking == Some(15)
+            })
+            .times(1)
+            .returning(|_, _| Ok(()));
+        
+        let service = service(MockYoutubeRawVideoRepository::new(), youtube_video_repository, youtube_keyword_watchlist_repository);
+        
+        let alert_count = service.evaluate_keyword_alerts(date(7), &[test_keyword_ranking(date(7), 2, "먹방", 0)]).await.unwrap();
+        assert_eq!(alert_count, 1);
+    }
+    
+    #[tokio::test]
+    async fn keyword_alerts_are_skipped_without_previous_chart() {
+        let mut youtube_video_repository = MockYoutubeVideoRepository::new();
+        youtube_video_repository.expect_find_latest_ranking_date_before().returning(|_, _, _| Ok(None));
+        youtube_video_repository.expect_get_keyword_rankings().never();
+        let mut youtube_keyword_watchlist_repository = MockYoutubeKeywordWatchlistRepository::new();
+        youtube_keyword_watchlist_repository.expect_find_watchlists_by_keywords().never();
+        youtube_keyword_watchlist_repository.expect_replace_keyword_alerts().never();
+        
+        let service = service(MockYoutubeRawVideoRepository::new(), youtube_video_repository, youtube_keyword_watchlist_repository);
+        
+        let alert_count = service.evaluate_keyword_alerts(date(7), &[test_keyword_ranking(date(7), 1, "먹방", 0)]).await.unwrap();
+        assert_eq!(alert_count, 0);
+    }
+    
+    #[test]
+    fn find_missing_video_ids_returns_ids_absent_from_response() {
+        let requested_ids = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let statistics = vec![VideoStatistics {
+            video_id: "b".to_string(),
+            view_count: 10,
+            like_count: 1,
+            comment_count: 0,
+        }];
+        
+        assert_eq!(find_missing_video_ids(&requested_ids, &statistics), vec!["a", "c"]);
+        assert!(find_missing_video_ids(&requested_ids[1..2], &statistics).is_empty());
+    }
+    
+    #[test]
+    fn keyword_sentiment_converts_counts_to_ratios() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        
+        let result = |positive, neutral, negative| GeminiSentimentResponse {
+            keyword_text: "먹방".to_string(),
+            positive,
+            neutral,
+            negative,
+        };
+        
+        let sentiment = keyword_sentiment(date, &result(6, 3, 1), 10).unwrap();
+        
+        assert_eq!((sentiment.positive_ratio, sentiment.neutral_ratio, sentiment.negative_ratio), (0.6, 0.3, 0.1));
+        assert_eq!(sentiment.sample_count, 10);
+        assert_eq!(KeywordSentimentResponse::from(&sentiment).label, "positive");
+        assert!(keyword_sentiment(date, &result(0, 0, 0), 0).is_none());
+    }
+    
+    #[test]
+    fn keyword_sentiment_rejects_counts_not_matching_sent_texts() {
+        let date = NaiveDate::from_ymd_opt(2026, 1, 5).unwrap();
+        let result = |positive, neutral, negative| GeminiSentimentResponse {
+            keyword_text: "먹방".to_string(),
+            positive,
+            neutral,
+            negative,
+        };
+        
+        assert!(keyword_sentiment(date, &result(60, 30, 10), 10).is_none());
+        assert!(keyword_sentiment(date, &result(6, 3, 0), 10).is_none());
+        assert!(keyword_sentiment(date, &result(12, -1, -1), 10).is_none());
+    }
 }
\ No newline at end of file
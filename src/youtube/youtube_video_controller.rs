@@ -1,20 +1,40 @@
 use std::error::Error;
-use actix_web::{get, post, web, HttpResponse};
+use actix_web::{delete, get, post, web, HttpResponse};
+use actix_web::http::header;
 use utoipa::OpenApi;
 use crate::app_state::AppState;
 use crate::auth::auth_model::AuthenticatedUser;
 use crate::common::pagination::{Page, PaginationQuery};
 use crate::errors::{AppError, ErrorResponse};
-use crate::youtube::youtube_channel::youtube_channel_model::{AnalyzeChannelRequestQuery, ChannelKeywordResponse, ChannelRequestQuery, ChannelResponse};
-use crate::youtube::youtube_video::youtube_video_model::KeywordRankingResponse;
+use crate::youtube::youtube_channel::youtube_channel_comparison::comparison_to_csv;
+use crate::youtube::youtube_channel::youtube_channel_model::{AnalyzeChannelRequestQuery, ChannelBenchmarkResponse, ChannelKeywordGapResponse, ChannelCompareQuery, ChannelDetailResponse, ChannelListQuery, ChannelComparisonResponse, ExportFormat, ChannelGrowthQuery, ChannelGrowthResponse, ChannelKeywordDiffResponse, ChannelKeywordResponse, ChannelKeywordTimelineResponse, ChannelKeywordVideosQuery, ChannelKeywordVideosResponse, ChannelRequestQuery, ChannelResponse, MyChannelAnalysisResponse};
+use crate::youtube::youtube_video::youtube_video_model::{DurationAnalyticsQuery, DurationAnalyticsResponse, KeywordAlertResponse, KeywordCommentsQuery, KeywordCommentsResponse, KeywordIdeasResponse, KeywordRankingResponse, KeywordWatchlistRequest, KeywordWatchlistResponse, PublishTimeAnalyticsQuery, PublishTimeAnalyticsResponse};
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
         get_daily_keyword_rankings,
+        get_publish_time_analytics,
+        get_duration_analytics,
+        get_keyword_comments,
+        get_keyword_ideas,
+        get_keyword_watchlists,
+        save_keyword_watchlist,
+        delete_keyword_watchlist,
+        get_keyword_alerts,
         get_channels,
         get_channels_keyword,
-        request_analyze_channels_keyword
+        request_analyze_channels_keyword,
+        get_channel_growth,
+        get_channel_keyword_diff,
+        get_channel_keyword_timeline,
+        get_channel_keyword_videos,
+        get_channel_benchmark,
+        get_channel_keyword_gap,
+        compare_channels,
+        get_my_channel_analyses,
+        get_channel,
+        delete_channel
     ),
     components(),
     tags(
@@ -25,9 +45,28 @@ pub struct YoutubeApi;
 
 pub fn youtube_api(config: &mut web::ServiceConfig) {
     config.service(get_daily_keyword_rankings)
+        .service(get_publish_time_analytics)
+        .service(get_duration_analytics)
+        .service(get_keyword_comments)
+        .service(get_keyword_ideas)
+        .service(get_keyword_watchlists)
+        .service(save_keyword_watchlist)
+        .service(delete_keyword_watchlist)
+        .service(get_keyword_alerts)
         .service(get_channels)
         .service(get_channels_keyword)
-        .service(request_analyze_channels_keyword);
+        .service(request_analyze_channels_keyword)
+        .service(get_channel_growth)
+        .service(get_channel_keyword_diff)
+        .service(get_channel_keyword_timeline)
+        .service(get_channel_keyword_videos)
+        .service(get_channel_benchmark)
+        .service(get_channel_keyword_gap)
+        .service(compare_channels)
+        .service(get_my_channel_analyses)
+        // /channel/{handle} 은 고정 경로 뒤에 등록
+        .service(get_channel)
+        .service(delete_channel);
 }
 
 #[utoipa::path(
@@ -62,13 +101,286 @@ pub async fn get_daily_keyword_rankings(
 
 #[utoipa::path(
     get,
-    path = "/channel",
+    path = "/keyword/comments",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        KeywordCommentsQuery
+    ),
+    responses(
+        (
+            status = 200,
+            body = KeywordCommentsResponse,
+            description = "get keywords viewers mention in comments under the keyword's top videos",
+            content_type = "application/json"
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/keyword/comments")]
+pub async fn get_keyword_comments(
+    state: web::Data<AppState>,
+    query: web::Query<KeywordCommentsQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_video_comment_service.get_keyword_comments(query.into_inner().keyword_text).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/keyword/{keyword}/ideas",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("keyword" = String, Path, description = "trending keyword text")
+    ),
+    responses(
+        (
+            status = 200,
+            body = KeywordIdeasResponse,
+            description = "get short-form content ideas generated from the keyword's top videos (cached per day)",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "no recently collected videos for the keyword",
+        ),
+        (
+            status = 409,
+            body = ErrorResponse,
+            description = "ideas for the keyword are being generated by another request",
+        ),
+        (
+            status = 429,
+            body = ErrorResponse,
+            description = "daily idea generation limit exceeded",
+        ),
+        (
+            status = 502,
+            body = ErrorResponse,
+            description = "idea generation failed",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/keyword/{keyword}/ideas")]
+pub async fn get_keyword_ideas(
+    state: web::Data<AppState>,
+    keyword: web::Path<String>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.keyword_idea_service.get_keyword_ideas(&auth_user.public_id, keyword.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/keyword/watchlist",
+    security(
+        ("bearerAuth" = [])
+    ),
+    responses(
+        (
+            status = 200,
+            body = Vec<KeywordWatchlistResponse>,
+            description = "get the current user's watched keywords and their alert rules",
+            content_type = "application/json"
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/keyword/watchlist")]
+pub async fn get_keyword_watchlists(
+    state: web::Data<AppState>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.keyword_watchlist_service.get_watchlists(&auth_user.public_id).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    post,
+    path = "/keyword/watchlist",
+    security(
+        ("bearerAuth" = [])
+    ),
+    request_body = KeywordWatchlistRequest,
+    responses(
+        (
+            status = 200,
+            body = KeywordWatchlistResponse,
+            description = "watch a keyword, or update its alert rules if already watched",
+            content_type = "application/json"
+        ),
+        (
+            status = 400,
+            body = ErrorResponse,
+            description = "invalid alert rules",
+        ),
+        (
+            status = 409,
+            body = ErrorResponse,
+            description = "watchlist limit exceeded",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[post("/keyword/watchlist")]
+pub async fn save_keyword_watchlist(
+    state: web::Data<AppState>,
+    form: web::Json<KeywordWatchlistRequest>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.keyword_watchlist_service
+        .save_watchlist(&auth_user.public_id, form.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/keyword/watchlist/{id}",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("id" = i64, Path, description = "watchlist id")
+    ),
+    responses(
+        (
+            status = 204,
+            description = "stop watching the keyword",
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "watchlist not found",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[delete("/keyword/watchlist/{id}")]
+pub async fn delete_keyword_watchlist(
+    state: web::Data<AppState>,
+    path: web::Path<i64>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    state.keyword_watchlist_service.delete_watchlist(&auth_user.public_id, path.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[utoipa::path(
+    get,
+    path = "/keyword/alerts",
     security(
         ("bearerAuth" = [])
     ),
     params(
         PaginationQuery
     ),
+    responses(
+        (
+            status = 200,
+            body = Page<KeywordAlertResponse>,
+            description = "get rank alerts triggered for the current user's watched keywords, newest first",
+            content_type = "application/json"
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/keyword/alerts")]
+pub async fn get_keyword_alerts(
+    state: web::Data<AppState>,
+    query: web::Query<PaginationQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.keyword_watchlist_service
+        .get_alerts(&auth_user.public_id, query.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/analytics/publish-time",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        PublishTimeAnalyticsQuery
+    ),
+    responses(
+        (
+            status = 200,
+            body = PublishTimeAnalyticsResponse,
+            description = "get views and engagement of collected videos by weekday and hour",
+            content_type = "application/json"
+        ),
+        (
+            status = 400,
+            body = ErrorResponse,
+            description = "invalid timezone or date range",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/analytics/publish-time")]
+pub async fn get_publish_time_analytics(
+    state: web::Data<AppState>,
+    query: web::Query<PublishTimeAnalyticsQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_video_service.get_publish_time_analytics(query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/analytics/duration",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        DurationAnalyticsQuery
+    ),
+    responses(
+        (
+            status = 200,
+            body = DurationAnalyticsResponse,
+            description = "get view, like-rate and comment-rate distributions by video duration bucket",
+            content_type = "application/json"
+        ),
+        (
+            status = 400,
+            body = ErrorResponse,
+            description = "invalid timezone or date range",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/analytics/duration")]
+pub async fn get_duration_analytics(
+    state: web::Data<AppState>,
+    query: web::Query<DurationAnalyticsQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_video_service.get_duration_analytics(query.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ChannelListQuery
+    ),
     responses(
         (
             status = 200,
@@ -87,7 +399,7 @@ pub async fn get_daily_keyword_rankings(
 #[get("/channel")]
 pub async fn get_channels(
     state: web::Data<AppState>,
-    query: web::Query<PaginationQuery>,
+    query: web::Query<ChannelListQuery>,
     auth_user: AuthenticatedUser
 ) -> Result<HttpResponse, Box<dyn Error>> {
     let response = state.youtube_channel_service.get_youtube_channels(query.into_inner()).await?;
@@ -136,7 +448,7 @@ pub async fn get_channels_keyword(
         ("bearerAuth" = [])
     ),
     params(
-        ("channel_handle" = String, Query, description = "channel's handle")
+        AnalyzeChannelRequestQuery
     ),
     responses(
         (
@@ -147,7 +459,17 @@ pub async fn get_channels_keyword(
         (
             status = 400,
             body = ErrorResponse,
-            description = "failed to get data",
+            description = "failed to get data, or analysis options differ from the ones stored for an already analyzed channel",
+        ),
+        (
+            status = 409,
+            body = ErrorResponse,
+            description = "channel analysis already in progress",
+        ),
+        (
+            status = 429,
+            body = ErrorResponse,
+            description = "monthly analysis quota exceeded",
         )
     ),
     tags = ["Youtube Data"]
@@ -159,6 +481,378 @@ pub async fn request_analyze_channels_keyword(
     auth_user: AuthenticatedUser
 ) -> Result<HttpResponse, AppError> {
     let channel_handle = query.channel_handle.clone();
-    let response = state.youtube_channel_service.request_analyze_youtube_channel_keywords(channel_handle).await?;
+    let response = state.youtube_channel_service
+        .request_analyze_youtube_channel_keywords(&auth_user.public_id, channel_handle, query.analysis_options())
+        .await?;
     Ok(HttpResponse::Accepted().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/growth",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ChannelGrowthQuery
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelGrowthResponse,
+            description = "get youtube channel's subscriber and view growth",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/growth")]
+pub async fn get_channel_growth(
+    state: web::Data<AppState>,
+    query: web::Query<ChannelGrowthQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service.get_youtube_channel_growth(&query.channel_handle, query.days).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/keyword/diff",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("channel_handle" = String, Query, description = "channel's handle")
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelKeywordDiffResponse,
+            description = "get keywords that entered or left the channel's top 100 since the previous analysis",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/keyword/diff")]
+pub async fn get_channel_keyword_diff(
+    state: web::Data<AppState>,
+    query: web::Query<ChannelRequestQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service.get_youtube_channel_keyword_diff(&query.channel_handle).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/keyword/timeline",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("channel_handle" = String, Query, description = "channel's handle")
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelKeywordTimelineResponse,
+            description = "get channel's top keywords by upload month, requires_full_reanalysis is true for channels analyzed before monthly keywords were stored",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/keyword/timeline")]
+pub async fn get_channel_keyword_timeline(
+    state: web::Data<AppState>,
+    query: web::Query<ChannelRequestQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service.get_youtube_channel_keyword_timeline(&query.channel_handle).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/keyword/videos",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ChannelKeywordVideosQuery
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelKeywordVideosResponse,
+            description = "get channel videos behind a keyword, ordered by view count",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/keyword/videos")]
+pub async fn get_channel_keyword_videos(
+    state: web::Data<AppState>,
+    query: web::Query<ChannelKeywordVideosQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service
+        .get_youtube_channel_keyword_videos(&query.channel_handle, &query.keyword_text)
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/benchmark",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("channel_handle" = String, Query, description = "channel's handle")
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelBenchmarkResponse,
+            description = "get channel's upload performance benchmarks",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        ),
+        (
+            status = 422,
+            body = ErrorResponse,
+            description = "channel has no stored videos (analyzed before videos were stored), run a full re-analysis first",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/benchmark")]
+pub async fn get_channel_benchmark(
+    state: web::Data<AppState>,
+    query: web::Query<ChannelRequestQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service.get_youtube_channel_benchmark(&query.channel_handle).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/keyword/gap",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("channel_handle" = String, Query, description = "channel's handle")
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelKeywordGapResponse,
+            description = "get trending keywords the channel has not covered and covered keywords that are trending now, ranked by opportunity score",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        ),
+        (
+            status = 409,
+            body = ErrorResponse,
+            description = "channel analysis is in progress",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/keyword/gap")]
+pub async fn get_channel_keyword_gap(
+    state: web::Data<AppState>,
+    query: web::Query<ChannelRequestQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service.get_youtube_channel_keyword_gap(&query.channel_handle).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/compare",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ChannelCompareQuery
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelComparisonResponse,
+            description = "compare keywords of 2-5 analyzed channels (format=csv returns text/csv)",
+            content_type = "application/json"
+        ),
+        (
+            status = 400,
+            body = ErrorResponse,
+            description = "invalid channel handles",
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/compare")]
+pub async fn compare_channels(
+    state: web::Data<AppState>,
+    query: web::Query<ChannelCompareQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service.compare_youtube_channels(&query.channel_handles).await?;
+    
+    match query.format {
+        ExportFormat::Json => Ok(HttpResponse::Ok().json(response)),
+        ExportFormat::Csv => Ok(HttpResponse::Ok()
+            .content_type("text/csv; charset=utf-8")
+            .insert_header((header::CONTENT_DISPOSITION, "attachment; filename=\"channel_comparison.csv\""))
+            .body(comparison_to_csv(&response)?)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/me",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        PaginationQuery
+    ),
+    responses(
+        (
+            status = 200,
+            body = Page<MyChannelAnalysisResponse>,
+            description = "get channels analyzed by the current user",
+            content_type = "application/json"
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/me")]
+pub async fn get_my_channel_analyses(
+    state: web::Data<AppState>,
+    query: web::Query<PaginationQuery>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service
+        .get_my_channel_analyses(&auth_user.public_id, query.into_inner())
+        .await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    get,
+    path = "/channel/{handle}",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("handle" = String, Path, description = "channel handle")
+    ),
+    responses(
+        (
+            status = 200,
+            body = ChannelDetailResponse,
+            description = "get youtube channel with analysis metadata",
+            content_type = "application/json"
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[get("/channel/{handle}")]
+pub async fn get_channel(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    let response = state.youtube_channel_service.get_youtube_channel(path.into_inner()).await?;
+    Ok(HttpResponse::Ok().json(response))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/channel/{handle}",
+    security(
+        ("bearerAuth" = [])
+    ),
+    params(
+        ("handle" = String, Path, description = "channel handle")
+    ),
+    responses(
+        (
+            status = 204,
+            description = "delete youtube channel and its keywords",
+        ),
+        (
+            status = 403,
+            body = ErrorResponse,
+            description = "admin only",
+        ),
+        (
+            status = 404,
+            body = ErrorResponse,
+            description = "channel not found",
+        ),
+        (
+            status = 409,
+            body = ErrorResponse,
+            description = "channel analysis in progress",
+        )
+    ),
+    tags = ["Youtube Data"]
+)]
+#[delete("/channel/{handle}")]
+pub async fn delete_channel(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    auth_user: AuthenticatedUser
+) -> Result<HttpResponse, AppError> {
+    state.auth_service.authorize_admin(&auth_user.public_id).await?;
+    state.youtube_channel_service.delete_youtube_channel(path.into_inner()).await?;
+    Ok(HttpResponse::NoContent().finish())
 }
\ No newline at end of file